@@ -41,7 +41,7 @@ fn run() -> Result<(), Box<StdError>> {
             println!("document not found, but index exists");
         }
         // No index
-        Err(Error::Api(ApiError::IndexNotFound { .. })) => {
+        Err(Error::Api { error: ApiError::IndexNotFound { .. }, .. }) => {
             println!("index not found");
         }
         // Some other error
@@ -75,7 +75,7 @@ fn ensure_indexed(client: &SyncClient, doc: MyType) -> Result<(), Error> {
             put_doc(client, doc)?;
         }
         // No index: create it, then map and index
-        Err(Error::Api(ApiError::IndexNotFound { .. })) => {
+        Err(Error::Api { error: ApiError::IndexNotFound { .. }, .. }) => {
             println!("creating index and doc");
 
             put_index(client)?;
@@ -90,7 +90,7 @@ fn ensure_indexed(client: AsyncClient, doc: MyType) -> Box<Future<Item = (), Err
                 put_doc(client, doc)
             }
             // No index: create it, then map and index
-            Err(Error::Api(ApiError::IndexNotFound { .. })) => {
+            Err(Error::Api { error: ApiError::IndexNotFound { .. }, .. }) => {
                 println!("creating index and doc");
 
                 let put_doc = put_index(client.clone()).and_then(|_| put_doc(client, doc));
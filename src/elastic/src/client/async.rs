@@ -246,7 +246,7 @@ impl AsyncClientBuilder {
     # use elastic::prelude::*;
     let builder = SyncClientBuilder::new()
         .params(|p| {
-            p.url_param("pretty", true)
+            p.pretty(true)
         });
     ```
 
@@ -80,7 +80,7 @@ match response {
             println!("{:?}", hit);
         }
     },
-    Err(Error::Api(e)) => {
+    Err(Error::Api { error, .. }) => {
         // handle a REST API error
     },
     Err(e) => {
@@ -274,7 +274,7 @@ let request_builder = client.request(req);
 
 // Set additional url parameters
 let request_builder = request_builder.params(|p| p
-    .url_param("pretty", true)
+    .pretty(true)
     .url_param("refresh", true)
 );
 
@@ -318,7 +318,7 @@ match response {
             println!("{:?}", hit);
         }
     },
-    Err(Error::Api(e)) => {
+    Err(Error::Api { error, .. }) => {
         // handle a REST API error
     },
     Err(e) => {
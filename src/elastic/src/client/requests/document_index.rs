@@ -120,7 +120,7 @@ where
     TDocument: Serialize,
 {
     fn into_request(self) -> Result<IndexRequest<'static, Vec<u8>>> {
-        let body = serde_json::to_vec(&self.doc).map_err(error::request)?;
+        let body = serde_json::to_vec(&self.doc).map_err(error::serialize)?;
 
         Ok(IndexRequest::for_index_ty_id(
             self.index,
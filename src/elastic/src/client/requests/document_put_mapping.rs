@@ -109,7 +109,7 @@ where
     TDocument: DocumentType,
 {
     fn into_request(self) -> Result<IndicesPutMappingRequest<'static, Vec<u8>>> {
-        let body = serde_json::to_vec(&TDocument::index_mapping()).map_err(error::request)?;
+        let body = serde_json::to_vec(&TDocument::index_mapping()).map_err(error::serialize)?;
 
         Ok(IndicesPutMappingRequest::for_index_ty(
             self.index,
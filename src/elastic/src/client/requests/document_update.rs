@@ -210,7 +210,7 @@ where
     TBody: Serialize,
 {
     fn into_request(self) -> Result<UpdateRequest<'static, Vec<u8>>, Error> {
-        let body = serde_json::to_vec(&self.body).map_err(error::request)?;
+        let body = serde_json::to_vec(&self.body).map_err(error::serialize)?;
 
         Ok(UpdateRequest::for_index_ty_id(
             self.index,
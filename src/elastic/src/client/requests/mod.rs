@@ -8,7 +8,7 @@ use futures_cpupool::CpuPool;
 
 use client::{AsyncSender, Client, RequestParams, Sender};
 
-pub use elastic_reqwest::{AsyncBody, SyncBody};
+pub use elastic_reqwest::{AsyncBody, BufferedBody, SyncBody};
 pub use elastic_reqwest::req::{empty_body, DefaultBody, HttpMethod, HttpRequest, Url};
 pub use elastic_reqwest::req::params;
 pub use elastic_reqwest::req::endpoints;
@@ -223,7 +223,7 @@ mod tests {
             .unwrap();
 
         let req = RequestBuilder::new(client.clone(), None, PingRequest::new())
-            .params(|p| p.url_param("pretty", true))
+            .params(|p| p.pretty(true))
             .params(|p| p.url_param("refresh", true));
 
         let params = &req.params.unwrap();
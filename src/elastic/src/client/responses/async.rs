@@ -1,8 +1,8 @@
-use std::mem;
 use futures::{Future, Poll, Stream};
 use futures_cpupool::CpuPool;
 use serde::de::DeserializeOwned;
-use reqwest::unstable::async::{Decoder, Response as RawResponse};
+use elastic_reqwest::AsyncResponse as RawResponse;
+use elastic_reqwest::async::AsyncResponseBody;
 
 use error::{self, Error};
 use http::AsyncChunk;
@@ -38,7 +38,10 @@ impl AsyncResponseBuilder {
     Convert the builder into a raw HTTP response that implements `Read`.
     */
     pub fn into_raw(self) -> AsyncHttpResponse {
-        AsyncHttpResponse(self.inner)
+        AsyncHttpResponse {
+            status: self.status(),
+            body: self.inner.into_body(),
+        }
     }
 
     /**
@@ -108,17 +111,23 @@ impl AsyncResponseBuilder {
 
     [response-types]: parse/trait.IsOk.html#implementors
     */
-    pub fn into_response<T>(mut self) -> IntoResponse<T>
+    pub fn into_response<T>(self) -> IntoResponse<T>
     where
         T: IsOk + DeserializeOwned + Send + 'static,
     {
         let status = self.status();
-        let body = mem::replace(self.inner.body_mut(), Decoder::empty());
+        let capture_limit = self.inner.capture_body_on_error_limit();
+        let body = self.inner.into_body();
 
         let de_fn = move |body: AsyncChunk| {
-            parse()
+            let parse = match capture_limit {
+                Some(limit) => parse::<T>().capture_body_on_error_limit(limit),
+                None => parse::<T>(),
+            };
+
+            parse
                 .from_slice(status, body.as_ref())
-                .map_err(move |e| error::response(status, e))
+                .map_err(|e| error::buffered_response(status, body.as_ref(), e))
         };
 
         let body_future = body.concat2().map_err(move |e| error::response(status, e));
@@ -160,24 +169,25 @@ where
 }
 
 /** A raw HTTP response that can be buffered using `Read`. */
-pub struct AsyncHttpResponse(RawResponse);
+pub struct AsyncHttpResponse {
+    status: u16,
+    body: AsyncResponseBody,
+}
 
 impl Stream for AsyncHttpResponse {
     type Item = AsyncChunk;
     type Error = Error;
 
     fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
-        self.0.body_mut().poll().map_err(|e| {
-            let status = self.status();
+        let status = self.status;
 
-            error::response(status, e)
-        })
+        self.body.poll().map_err(|e| error::response(status, e))
     }
 }
 
 impl AsyncHttpResponse {
     /** Get the HTTP status for the response. */
     pub fn status(&self) -> u16 {
-        self.0.status().into()
+        self.status
     }
 }
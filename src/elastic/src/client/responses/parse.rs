@@ -69,7 +69,7 @@ match response {
     Ok(response) => {
         println!("took: {}", response.took);
     },
-    Err(Error::Api(e)) => {
+    Err(Error::Api { error, .. }) => {
         // handle a REST API error
     },
     Err(e) => {
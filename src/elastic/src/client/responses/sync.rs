@@ -1,6 +1,6 @@
 use std::io::{Read, Result as IoResult};
 use serde::de::DeserializeOwned;
-use reqwest::Response as RawResponse;
+use elastic_reqwest::SyncResponse as RawResponse;
 
 use error::{self, Result};
 use elastic_reqwest::SyncFromResponse;
@@ -94,9 +94,25 @@ impl SyncResponseBuilder {
         T: IsOk + DeserializeOwned,
     {
         let status = self.status();
-        parse()
-            .from_response(self.0)
-            .map_err(|e| error::response(status, e))
+
+        match status {
+            200...299 => parse()
+                .from_response(self.0)
+                .map_err(|e| error::response(status, e)),
+            // Buffer error responses so the details of an API error can be read from the body
+            _ => {
+                let parse = match self.0.capture_body_on_error_limit() {
+                    Some(limit) => parse::<T>().capture_body_on_error_limit(limit),
+                    None => parse::<T>(),
+                };
+
+                let body = self.0.into_bytes().map_err(|e| error::response(status, e))?;
+
+                parse
+                    .from_slice(status, &body)
+                    .map_err(|e| error::buffered_response(status, &body, e))
+            }
+        }
     }
 }
 
@@ -25,7 +25,7 @@ match response {
     Ok(response) => {
         // do something with the response
     },
-    Err(Error::Api(e)) => {
+    Err(Error::Api { error, status, .. }) => {
         // handle a REST API error
     },
     Err(e) => {
@@ -42,9 +42,9 @@ use std::fmt;
 use std::error::Error as StdError;
 
 use serde_json;
-use reqwest::Error as ReqwestError;
+use reqwest::{Error as ReqwestError, StatusCode};
 use elastic_reqwest::Error as ElasticReqwestError;
-use elastic_reqwest::res::error::ResponseError;
+use elastic_reqwest::res::error::{ParseResponseError, ResponseError};
 
 pub use elastic_reqwest::res::error::ApiError;
 
@@ -60,23 +60,54 @@ can be formatted, but not destructured.
 If the `RUST_BACKTRACE` environment variable is `1` then client errors will
 also contain a backtrace.
 */
-#[derive(Debug)]
 pub enum Error {
-    /** An API error from Elasticsearch. */ Api(ApiError),
+    /** An API error from Elasticsearch. */
+    Api {
+        /** The error returned by Elasticsearch. */ error: ApiError,
+        /** The status code of the response the error was returned in. */ status: StatusCode,
+        /** The type, reason and root causes of the error, if they could be read from the response body. */
+        details: Option<ApiErrorDetails>,
+    },
     /** Any other kind of error. */ Client(ClientError),
 }
 
+/** The maximum length of an API error in debug output. */
+const MAX_API_ERROR_DEBUG_LEN: usize = 512;
+
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            // API errors can contain an entire error response body, so keep them short enough for logs
+            Error::Api { error: ref e, .. } => {
+                let e = format!("{:?}", e);
+
+                if e.len() > MAX_API_ERROR_DEBUG_LEN {
+                    let end = (0..MAX_API_ERROR_DEBUG_LEN + 1)
+                        .rev()
+                        .find(|&i| e.is_char_boundary(i))
+                        .unwrap_or(0);
+
+                    write!(f, "Api({}...(truncated))", &e[..end])
+                } else {
+                    write!(f, "Api({})", e)
+                }
+            }
+            Error::Client(ref e) => f.debug_tuple("Client").field(e).finish(),
+        }
+    }
+}
+
 impl StdError for Error {
     fn description(&self) -> &str {
         match *self {
-            Error::Api(_) => "API error returned from Elasticsearch",
+            Error::Api { .. } => "API error returned from Elasticsearch",
             Error::Client(_) => "error sending a request or receiving a response",
         }
     }
 
     fn cause(&self) -> Option<&StdError> {
         match *self {
-            Error::Api(ref e) => Some(e),
+            Error::Api { error: ref e, .. } => Some(e),
             Error::Client(ref e) => Some(e),
         }
     }
@@ -85,7 +116,7 @@ impl StdError for Error {
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            Error::Api(ref e) => write!(f, "API error returned from Elasticsearch. Caused by: {}", e),
+            Error::Api { error: ref e, .. } => write!(f, "API error returned from Elasticsearch. Caused by: {}", e),
             Error::Client(ref e) => write!(
                 f,
                 "error sending a request or receiving a response. Caused by: {}",
@@ -95,6 +126,264 @@ impl fmt::Display for Error {
     }
 }
 
+impl Error {
+    /**
+    Whether the error is likely to be temporary.
+
+    Transient errors are safe to retry, possibly after a delay.
+    They include failures to send a request, like connection errors and timeouts, and responses with a `408`, `429`, `502`, `503` or `504` status code.
+    Elasticsearch rejecting a request because it's overloaded is also transient.
+    Other API errors and errors building a client or serializing a request body aren't transient, because retrying them will fail the same way.
+
+    Elasticsearch responds with a `503` while the cluster doesn't have an elected master.
+    These API errors are recognised as transient:
+
+    - `master_not_discovered_exception`: no master node could be found to handle the request. See [`MasterNotDiscoveredException`][master-not-discovered].
+    - `cluster_block_exception` with a `SERVICE_UNAVAILABLE` block, like `no master` or `state not recovered / initialized`. See [`ClusterBlockException`][cluster-block], [`DiscoverySettings`][no-master-block] and [`GatewayService`][state-not-recovered-block].
+
+    Other cluster blocks, like an index being made read-only, aren't transient.
+
+    [master-not-discovered]: https://github.com/elastic/elasticsearch/blob/6.x/server/src/main/java/org/elasticsearch/discovery/MasterNotDiscoveredException.java
+    [cluster-block]: https://github.com/elastic/elasticsearch/blob/6.x/server/src/main/java/org/elasticsearch/cluster/block/ClusterBlockException.java
+    [no-master-block]: https://github.com/elastic/elasticsearch/blob/6.x/server/src/main/java/org/elasticsearch/discovery/DiscoverySettings.java
+    [state-not-recovered-block]: https://github.com/elastic/elasticsearch/blob/6.x/server/src/main/java/org/elasticsearch/gateway/GatewayService.java
+    */
+    pub fn is_transient(&self) -> bool {
+        match *self {
+            Error::Api {
+                error: ApiError::Other(ref err),
+                ..
+            } => match err.get("type").and_then(|ty| ty.as_str()) {
+                Some("es_rejected_execution_exception")
+                | Some("circuit_breaking_exception")
+                | Some("process_cluster_event_timeout_exception")
+                | Some("unavailable_shards_exception")
+                | Some("master_not_discovered_exception") => true,
+                // Retryable cluster blocks, like having no master, are the ones that respond with a `503`
+                Some("cluster_block_exception") => err.get("reason")
+                    .and_then(|reason| reason.as_str())
+                    .map(|reason| reason.contains("SERVICE_UNAVAILABLE/"))
+                    .unwrap_or(false),
+                _ => false,
+            },
+            Error::Api { .. } => false,
+            Error::Client(ref e) => match *e.inner.kind() {
+                inner::ErrorKind::Request => true,
+                inner::ErrorKind::Response(status) => is_transient_status(status),
+                _ => false,
+            },
+        }
+    }
+
+    /**
+    The status code of the response that caused the error, if there was one.
+
+    This is `Some` for API errors, and for client errors that happened while receiving a response, like a response body that couldn't be parsed.
+    Errors that happened before a response was received, like failing to connect, return `None`.
+    */
+    pub fn status_code(&self) -> Option<StatusCode> {
+        match *self {
+            Error::Api { status, .. } => Some(status),
+            Error::Client(ref e) => match *e.inner.kind() {
+                inner::ErrorKind::Response(status) => StatusCode::try_from(status).ok(),
+                _ => None,
+            },
+        }
+    }
+
+    /**
+    Convert the error into the details of an API error returned by Elasticsearch.
+
+    The details include the type, reason and root causes of the error, so they can be inspected the same way for any kind of `ApiError`.
+    Returns `None` if the error is a client error, or if the response body didn't contain an `error` object.
+    */
+    pub fn into_api_error(self) -> Option<ApiErrorDetails> {
+        match self {
+            Error::Api { details, .. } => details,
+            Error::Client(_) => None,
+        }
+    }
+
+    /**
+    Details about a response body that couldn't be deserialised into the response type, if that's what caused the error.
+
+    The raw body is only included if it's no larger than the limit set by `RequestParams::capture_body_on_error_limit`.
+    */
+    pub fn body_parse_error(&self) -> Option<BodyParseError> {
+        let cause = match *self {
+            Error::Client(ref e) => match *e.inner.kind() {
+                inner::ErrorKind::Response(_) => e.inner.1.next_error.as_ref(),
+                _ => None,
+            },
+            Error::Api { .. } => None,
+        };
+
+        let cause = match cause {
+            Some(cause) => cause,
+            None => return None,
+        };
+
+        let err = match (cause.downcast_ref::<ResponseError>(), cause.downcast_ref::<ElasticReqwestError>()) {
+            (Some(&ResponseError::Parse(ref err)), _) => err,
+            (_, Some(&ElasticReqwestError::Response(ResponseError::Parse(ref err)))) => err,
+            _ => return None,
+        };
+
+        match *err {
+            ParseResponseError::Body {
+                line,
+                column,
+                ref raw_body,
+                ..
+            } => Some(BodyParseError {
+                line: line,
+                column: column,
+                raw_body: raw_body.as_ref().map(String::as_str),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/** A response body that couldn't be deserialised into the response type. */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BodyParseError<'a> {
+    line: usize,
+    column: usize,
+    raw_body: Option<&'a str>,
+}
+
+impl<'a> BodyParseError<'a> {
+    /** The line in the body where deserialisation failed. */
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /** The column in the body where deserialisation failed. */
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /** The raw body, if it was captured. */
+    pub fn raw_body(&self) -> Option<&'a str> {
+        self.raw_body
+    }
+}
+
+/**
+The details of an API error returned by Elasticsearch.
+
+These are read from the `error` object in the response body:
+
+```text
+{
+    "error": {
+        "type": "index_not_found_exception",
+        "reason": "no such index",
+        "root_cause": [ ... ]
+    },
+    "status": 404
+}
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiErrorDetails {
+    status: StatusCode,
+    error_type: String,
+    reason: String,
+    root_cause: Vec<RootCause>,
+}
+
+impl ApiErrorDetails {
+    fn from_body(status: StatusCode, body: &[u8]) -> Option<Self> {
+        #[derive(Deserialize)]
+        struct ApiErrorBody {
+            error: ApiErrorObject,
+        }
+
+        #[derive(Deserialize)]
+        struct ApiErrorObject {
+            #[serde(rename = "type")]
+            error_type: String,
+            reason: Option<String>,
+            #[serde(default)]
+            root_cause: Vec<RootCauseObject>,
+        }
+
+        #[derive(Deserialize)]
+        struct RootCauseObject {
+            #[serde(rename = "type")]
+            error_type: String,
+            reason: Option<String>,
+        }
+
+        let body: ApiErrorBody = match serde_json::from_slice(body) {
+            Ok(body) => body,
+            Err(_) => return None,
+        };
+
+        Some(ApiErrorDetails {
+            status: status,
+            error_type: body.error.error_type,
+            reason: body.error.reason.unwrap_or_default(),
+            root_cause: body.error
+                .root_cause
+                .into_iter()
+                .map(|cause| RootCause {
+                    error_type: cause.error_type,
+                    reason: cause.reason.unwrap_or_default(),
+                })
+                .collect(),
+        })
+    }
+
+    /** The status code of the response the error was returned in. */
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /** The type of the error, like `index_not_found_exception`. */
+    pub fn error_type(&self) -> &str {
+        &self.error_type
+    }
+
+    /** The reason for the error. */
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    /** The errors that caused this one. */
+    pub fn root_cause(&self) -> &[RootCause] {
+        &self.root_cause
+    }
+}
+
+/** An error that caused an API error. */
+#[derive(Debug, Clone, PartialEq)]
+pub struct RootCause {
+    error_type: String,
+    reason: String,
+}
+
+impl RootCause {
+    /** The type of the error, like `index_not_found_exception`. */
+    pub fn error_type(&self) -> &str {
+        &self.error_type
+    }
+
+    /** The reason for the error. */
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+fn is_transient_status(status: u16) -> bool {
+    match status {
+        408 | 429 | 502 | 503 | 504 => true,
+        _ => false,
+    }
+}
+
 /** An error building a client, sending a request or receiving a response. */
 #[derive(Debug)]
 pub struct ClientError {
@@ -126,6 +415,15 @@ where
     })
 }
 
+pub(crate) fn serialize<E>(err: E) -> Error
+where
+    E: StdError + Send + 'static,
+{
+    Error::Client(ClientError {
+        inner: inner::Error::with_chain(err, inner::ErrorKind::Serialize),
+    })
+}
+
 pub(crate) fn request<E>(err: E) -> Error
 where
     E: StdError + Send + 'static,
@@ -136,11 +434,34 @@ where
 }
 
 pub(crate) fn response<E>(status: u16, err: E) -> Error
+where
+    E: Into<MaybeApiError<E>> + StdError + Send + 'static,
+{
+    response_with_body(status, None, err)
+}
+
+/** Like `response`, but also reads the details of an API error from the buffered response body. */
+pub(crate) fn buffered_response<E>(status: u16, body: &[u8], err: E) -> Error
+where
+    E: Into<MaybeApiError<E>> + StdError + Send + 'static,
+{
+    response_with_body(status, Some(body), err)
+}
+
+fn response_with_body<E>(status: u16, body: Option<&[u8]>, err: E) -> Error
 where
     E: Into<MaybeApiError<E>> + StdError + Send + 'static,
 {
     match err.into() {
-        MaybeApiError::Api(err) => Error::Api(err),
+        MaybeApiError::Api(err) => {
+            let status = StatusCode::try_from(status).unwrap_or(StatusCode::Unregistered(status));
+
+            Error::Api {
+                error: err,
+                status: status,
+                details: body.and_then(|body| ApiErrorDetails::from_body(status, body)),
+            }
+        }
         MaybeApiError::Other(err) => Error::Client(ClientError {
             inner: inner::Error::with_chain(err, inner::ErrorKind::Response(status)),
         }),
@@ -206,6 +527,10 @@ mod inner {
                 description("error attempting to build a client")
                 display("error attempting to build a client")
             }
+            Serialize {
+                description("error serializing a request body")
+                display("error serializing a request body")
+            }
             Request {
                 description("error sending a request")
                 display("error sending a request")
@@ -227,4 +552,240 @@ mod tests {
     fn error_is_send_sync() {
         assert_send::<Error>();
     }
+
+    fn io_error() -> io::Error {
+        io::Error::new(io::ErrorKind::Other, "test error")
+    }
+
+    fn api(err: ApiError) -> Error {
+        response(400, ResponseError::Api(err))
+    }
+
+    fn api_error(ty: &str) -> Error {
+        api_error_with_reason(ty, "test error")
+    }
+
+    fn api_error_with_reason(ty: &str, reason: &str) -> Error {
+        let err = json!({
+            "type": ty,
+            "reason": reason
+        });
+
+        match err {
+            serde_json::Value::Object(err) => api(ApiError::Other(err)),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn api_error_debug_is_truncated() {
+        let reason: String = ::std::iter::repeat('a').take(1000).collect();
+        let err = api(ApiError::MapperParsing { reason: reason });
+
+        let debug = format!("{:?}", err);
+
+        let expected = format!("Api(MapperParsing {{ reason: \"{}...(truncated))", ::std::iter::repeat('a').take(487).collect::<String>());
+
+        assert_eq!(expected, debug);
+    }
+
+    #[test]
+    fn short_api_error_debug_is_not_truncated() {
+        let err = api(ApiError::IndexNotFound { index: "test".into() });
+
+        assert_eq!("Api(IndexNotFound { index: \"test\" })", format!("{:?}", err));
+    }
+
+    const INDEX_NOT_FOUND_BODY: &'static str = r#"{
+        "error": {
+            "root_cause": [
+                {
+                    "type": "index_not_found_exception",
+                    "reason": "no such index",
+                    "resource.type": "index_or_alias",
+                    "resource.id": "carrots",
+                    "index_uuid": "_na_",
+                    "index": "carrots"
+                }
+            ],
+            "type": "index_not_found_exception",
+            "reason": "no such index",
+            "resource.type": "index_or_alias",
+            "resource.id": "carrots",
+            "index_uuid": "_na_",
+            "index": "carrots"
+        },
+        "status": 404
+    }"#;
+
+    #[test]
+    fn into_api_error_for_api_error() {
+        let err: ApiError = serde_json::from_str(INDEX_NOT_FOUND_BODY).unwrap();
+
+        let details = buffered_response(404, INDEX_NOT_FOUND_BODY.as_bytes(), ResponseError::Api(err))
+            .into_api_error()
+            .unwrap();
+
+        assert_eq!(StatusCode::NotFound, details.status());
+        assert_eq!("index_not_found_exception", details.error_type());
+        assert_eq!("no such index", details.reason());
+        assert_eq!(1, details.root_cause().len());
+        assert_eq!("index_not_found_exception", details.root_cause()[0].error_type());
+        assert_eq!("no such index", details.root_cause()[0].reason());
+    }
+
+    #[test]
+    fn into_api_error_for_other_api_error() {
+        let body = r#"{
+            "error": {
+                "root_cause": [
+                    {
+                        "type": "parsing_exception",
+                        "reason": "no [query] registered for [qeury]",
+                        "line": 1,
+                        "col": 22
+                    }
+                ],
+                "type": "parsing_exception",
+                "reason": "no [query] registered for [qeury]",
+                "line": 1,
+                "col": 22
+            },
+            "status": 400
+        }"#;
+
+        let err: ApiError = serde_json::from_str(body).unwrap();
+
+        let details = buffered_response(400, body.as_bytes(), ResponseError::Api(err))
+            .into_api_error()
+            .unwrap();
+
+        assert_eq!(StatusCode::BadRequest, details.status());
+        assert_eq!("parsing_exception", details.error_type());
+        assert_eq!("no [query] registered for [qeury]", details.reason());
+        assert_eq!("parsing_exception", details.root_cause()[0].error_type());
+    }
+
+    #[test]
+    fn into_api_error_without_body() {
+        let err: ApiError = serde_json::from_str(INDEX_NOT_FOUND_BODY).unwrap();
+
+        assert_eq!(None, api(err).into_api_error());
+    }
+
+    #[test]
+    fn into_api_error_for_client_error() {
+        assert_eq!(None, request(io_error()).into_api_error());
+    }
+
+    #[test]
+    fn client_errors_are_transient() {
+        assert!(request(io_error()).is_transient());
+
+        for status in &[408, 429, 502, 503, 504] {
+            assert!(response(*status, io_error()).is_transient());
+        }
+    }
+
+    #[test]
+    fn client_errors_are_not_transient() {
+        assert!(!build(io_error()).is_transient());
+        assert!(!serialize(io_error()).is_transient());
+
+        for status in &[200, 400, 401, 403, 404, 409, 500] {
+            assert!(!response(*status, io_error()).is_transient());
+        }
+    }
+
+    #[test]
+    fn status_code_for_response_errors() {
+        assert_eq!(Some(StatusCode::ServiceUnavailable), response(503, io_error()).status_code());
+        assert_eq!(Some(StatusCode::Ok), response(200, io_error()).status_code());
+    }
+
+    fn body_parse_err(raw_body: Option<String>) -> ResponseError {
+        ResponseError::Parse(ParseResponseError::Body {
+            message: "invalid type".into(),
+            line: 2,
+            column: 10,
+            raw_body: raw_body,
+        })
+    }
+
+    #[test]
+    fn body_parse_error_for_response_errors() {
+        let err = response(200, body_parse_err(Some("{ \"took\": \"1\" }".into())));
+        let parse_err = err.body_parse_error().unwrap();
+
+        assert_eq!(2, parse_err.line());
+        assert_eq!(10, parse_err.column());
+        assert_eq!(Some("{ \"took\": \"1\" }"), parse_err.raw_body());
+
+        let err = response(200, ElasticReqwestError::Response(body_parse_err(None)));
+        let parse_err = err.body_parse_error().unwrap();
+
+        assert_eq!(2, parse_err.line());
+        assert_eq!(None, parse_err.raw_body());
+    }
+
+    #[test]
+    fn no_body_parse_error_for_other_errors() {
+        assert_eq!(None, response(200, io_error()).body_parse_error());
+        assert_eq!(None, request(io_error()).body_parse_error());
+        assert_eq!(None, api_error("es_rejected_execution_exception").body_parse_error());
+    }
+
+    #[test]
+    fn status_code_for_api_errors() {
+        let err = response(404, ResponseError::Api(ApiError::IndexNotFound { index: "test".into() }));
+        assert_eq!(Some(StatusCode::NotFound), err.status_code());
+
+        match err {
+            Error::Api { status, .. } => assert_eq!(StatusCode::NotFound, status),
+            _ => panic!("expected an API error"),
+        }
+
+        assert_eq!(Some(StatusCode::BadRequest), api_error("es_rejected_execution_exception").status_code());
+        assert_eq!(Some(StatusCode::BadRequest), api(ApiError::IndexNotFound { index: "test".into() }).status_code());
+
+        let err = response(999, ResponseError::Api(ApiError::IndexNotFound { index: "test".into() }));
+        assert_eq!(Some(StatusCode::Unregistered(999)), err.status_code());
+    }
+
+    #[test]
+    fn no_status_code_for_other_errors() {
+        assert_eq!(None, build(io_error()).status_code());
+        assert_eq!(None, serialize(io_error()).status_code());
+        assert_eq!(None, request(io_error()).status_code());
+    }
+
+    #[test]
+    fn api_errors_are_transient() {
+        assert!(api_error("es_rejected_execution_exception").is_transient());
+        assert!(api_error("circuit_breaking_exception").is_transient());
+        assert!(api_error("process_cluster_event_timeout_exception").is_transient());
+        assert!(api_error("unavailable_shards_exception").is_transient());
+        assert!(api_error("master_not_discovered_exception").is_transient());
+        assert!(api_error_with_reason("cluster_block_exception", "blocked by: [SERVICE_UNAVAILABLE/2/no master];").is_transient());
+        assert!(api_error_with_reason("cluster_block_exception", "blocked by: [SERVICE_UNAVAILABLE/1/state not recovered / initialized];").is_transient());
+    }
+
+    #[test]
+    fn api_errors_are_not_transient() {
+        let errors = vec![
+            api(ApiError::IndexNotFound { index: "test".into() }),
+            api(ApiError::DocumentMissing { index: "test".into() }),
+            api(ApiError::IndexAlreadyExists { index: "test".into() }),
+            api(ApiError::Parsing { line: 1, col: 1, reason: "test".into() }),
+            api(ApiError::MapperParsing { reason: "test".into() }),
+            api(ApiError::ActionRequestValidation { reason: "test".into() }),
+            api_error("search_phase_execution_exception"),
+            api_error("cluster_block_exception"),
+            api_error_with_reason("cluster_block_exception", "blocked by: [FORBIDDEN/12/index read-only / allow delete (api)];"),
+        ];
+
+        for err in errors {
+            assert!(!err.is_transient());
+        }
+    }
 }
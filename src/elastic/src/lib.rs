@@ -64,7 +64,7 @@ use elastic::http::header::Authorization;
 let builder = SyncClientBuilder::new()
     .base_url("http://es_host:9200")
     .params(|p| p
-        .url_param("pretty", true)
+        .pretty(true)
         .header(Authorization("let me in".to_owned())));
 
 let client = builder.build()?;
@@ -84,7 +84,7 @@ Individual requests can override these parameter values:
 let client = SyncClientBuilder::new().build()?;
 
 let response = client.search::<Value>()
-                     .params(|p| p.url_param("pretty", false))
+                     .params(|p| p.pretty(false))
                      .send()?;
 # Ok(())
 # }
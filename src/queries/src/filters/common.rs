@@ -1,6 +1,7 @@
 use serde;
-use serde::de::Visitor;
+use serde::de::{MapAccess, Visitor};
 use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde_json::{self, Value};
 use std::fmt;
 use super::super::Values;
 
@@ -23,6 +24,7 @@ pub struct Field {
 pub struct FieldAndValue {
     pub field: String,
     pub value: Values,
+    pub name: Option<String>,
 }
 
 impl<'de> serde::Deserialize<'de> for FieldAndValue {
@@ -39,9 +41,7 @@ impl Serialize for FieldAndValue {
     where
         S: Serializer,
     {
-        let mut map = serializer.serialize_map(Some(1))?;
-        map.serialize_entry(&self.field, &self.value)?;
-        map.end()
+        serialize_field_and_value(serializer, &self.field, "value", &self.value, self.name.as_ref())
     }
 }
 
@@ -54,15 +54,99 @@ impl<'de> Visitor<'de> for FieldAndValueVisitor {
         formatter.write_str("a key value pair")
     }
 
-    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
     where
         A: serde::de::MapAccess<'de>,
     {
-        use serde::de::Error;
-        let field = map.next_key()?.ok_or(A::Error::custom("expected field"))?;
-        let value: Values = map.next_value()?;
+        let (field, value, name) = deserialize_field_and_value(map, "value")?;
 
-        Ok(FieldAndValue { field, value })
+        Ok(FieldAndValue { field, value, name })
+    }
+}
+
+/// The value of a field, in the long form `{ "value": .., "_name": .. }` if the query is named.
+struct FieldValue<'a> {
+    value_key: &'static str,
+    value: &'a Values,
+    name: Option<&'a String>,
+}
+
+impl<'a> Serialize for FieldValue<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.name {
+            Some(name) => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry(self.value_key, self.value)?;
+                map.serialize_entry("_name", name)?;
+                map.end()
+            }
+            None => self.value.serialize(serializer),
+        }
+    }
+}
+
+/// Serialise a single field and its value.
+///
+/// The `value_key` is the key of the value in the long form, which is used if the query is named.
+pub(crate) fn serialize_field_and_value<S>(
+    serializer: S,
+    field: &str,
+    value_key: &'static str,
+    value: &Values,
+    name: Option<&String>,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let value = FieldValue {
+        value_key: value_key,
+        value: value,
+        name: name,
+    };
+
+    let mut map = serializer.serialize_map(Some(1))?;
+    map.serialize_entry(field, &value)?;
+    map.end()
+}
+
+/// Deserialise a single field and its value, from either the short or the long form.
+pub(crate) fn deserialize_field_and_value<'de, A>(
+    mut map: A,
+    value_key: &'static str,
+) -> Result<(String, Values, Option<String>), A::Error>
+where
+    A: MapAccess<'de>,
+{
+    use serde::de::Error;
+    let field = map.next_key()?.ok_or(A::Error::custom("expected field"))?;
+
+    match map.next_value()? {
+        Value::Object(mut params) => {
+            let value = params
+                .remove(value_key)
+                .ok_or_else(|| A::Error::custom(format!("expected {}", value_key)))?;
+            let value = serde_json::from_value(value).map_err(A::Error::custom)?;
+
+            let name = match params.remove("_name") {
+                Some(name) => Some(serde_json::from_value(name).map_err(A::Error::custom)?),
+                None => None,
+            };
+
+            // Other options, like `boost`, aren't supported, so reject them instead of dropping them
+            if let Some(key) = params.keys().next() {
+                return Err(A::Error::custom(format!("unknown field `{}`", key)));
+            }
+
+            Ok((field, value, name))
+        }
+        value => {
+            let value = serde_json::from_value(value).map_err(A::Error::custom)?;
+
+            Ok((field, value, None))
+        }
     }
 }
 
@@ -83,4 +167,22 @@ mod tests {
         let j = serde_json::to_string(&s).unwrap();
         assert_eq!(o, j);
     }
+
+    #[test]
+    fn named_kv_parse_and_serialise() {
+        let o = r#"{"title":{"value":"Search","_name":"title_query"}}"#;
+        let s: FieldAndValue = serde_json::from_str(o).unwrap();
+        assert_eq!(Some("title_query".to_owned()), s.name);
+
+        let j = serde_json::to_string(&s).unwrap();
+        assert_eq!(o, j);
+    }
+
+    #[test]
+    fn kv_parse_rejects_unknown_field() {
+        let o = r#"{"title":{"value":"Search","boost":2.0}}"#;
+        let err = serde_json::from_str::<FieldAndValue>(o).unwrap_err();
+
+        assert!(err.to_string().contains("unknown field `boost`"));
+    }
 }
@@ -4,9 +4,19 @@ pub struct ExistsFilter {
     pub exists: ExistsField,
 }
 
+impl ExistsFilter {
+    /// Set a name for the query, which is listed in the `matched_queries` of hits that match it.
+    pub fn named(mut self, name: &str) -> ExistsFilter {
+        self.exists.name = Some(name.to_owned());
+        self
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
 pub struct ExistsField {
     pub field: String,
+    #[serde(rename = "_name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 }
 
 #[cfg(test)]
@@ -20,4 +30,15 @@ mod tests {
         let j = r#"{ "exists": { "field": "sourceAddress" } }"#;
         let _s: ExistsFilter = serde_json::from_str(j).unwrap();
     }
+
+    #[test]
+    fn exists_filter_named() {
+        let e: ExistsFilter = serde_json::from_str(r#"{"exists":{"field":"sourceAddress"}}"#).unwrap();
+
+        let o = r#"{"exists":{"field":"sourceAddress"}}"#;
+        assert_eq!(o, serde_json::to_string(&e).unwrap());
+
+        let o = r#"{"exists":{"field":"sourceAddress","_name":"has_source"}}"#;
+        assert_eq!(o, serde_json::to_string(&e.named("has_source")).unwrap());
+    }
 }
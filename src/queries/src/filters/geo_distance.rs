@@ -0,0 +1,263 @@
+use serde;
+use serde::de::Visitor;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use std::fmt;
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct GeoDistanceFilter {
+    pub geo_distance: GeoDistanceField,
+}
+
+impl GeoDistanceFilter {
+    /// Match documents with a `geo_point` within `distance` of `lat`, `lon`.
+    ///
+    /// The distance includes its unit, like `"200km"`.
+    pub fn new(field: &str, distance: &str, lat: f64, lon: f64) -> GeoDistanceFilter {
+        GeoDistanceFilter {
+            geo_distance: GeoDistanceField {
+                field: field.to_string(),
+                point: format!("{},{}", lat, lon),
+                distance: distance.to_string(),
+                distance_type: DistanceType::default(),
+                validation_method: None,
+                boost: None,
+                name: None,
+            },
+        }
+    }
+
+    /// Set how the distance is calculated.
+    pub fn distance_type(mut self, distance_type: DistanceType) -> GeoDistanceFilter {
+        self.geo_distance.distance_type = distance_type;
+        self
+    }
+
+    /// Set how invalid points are handled.
+    pub fn validation_method(mut self, validation_method: ValidationMethod) -> GeoDistanceFilter {
+        self.geo_distance.validation_method = Some(validation_method);
+        self
+    }
+
+    /// Set the boost for the query.
+    pub fn boost(mut self, boost: &str) -> GeoDistanceFilter {
+        self.geo_distance.boost = Some(boost.to_owned());
+        self
+    }
+
+    /// Set a name for the query, which is listed in the `matched_queries` of hits that match it.
+    pub fn named(mut self, name: &str) -> GeoDistanceFilter {
+        self.geo_distance.name = Some(name.to_owned());
+        self
+    }
+}
+
+/// How the distance between two points is calculated.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub enum DistanceType {
+    /// Treat the earth as a sphere. This is the default.
+    #[serde(rename = "arc")] Arc,
+    /// Treat the earth as flat. This is faster but less accurate over long distances and near the poles.
+    #[serde(rename = "plane")] Plane,
+}
+
+impl Default for DistanceType {
+    fn default() -> DistanceType {
+        DistanceType::Arc
+    }
+}
+
+/// How points with invalid latitudes or longitudes are handled.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub enum ValidationMethod {
+    /// Fail the query. This is the default.
+    #[serde(rename = "STRICT")] Strict,
+    /// Accept invalid points.
+    #[serde(rename = "IGNORE_MALFORMED")] IgnoreMalformed,
+    /// Accept invalid points and try to correct them.
+    #[serde(rename = "COERCE")] Coerce,
+}
+
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
+pub struct GeoDistanceField {
+    pub field: String,
+    pub point: String,
+    pub distance: String,
+    pub distance_type: DistanceType,
+    pub validation_method: Option<ValidationMethod>,
+    pub boost: Option<String>,
+    pub name: Option<String>,
+}
+
+impl Serialize for GeoDistanceField {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let len = 3 + self.validation_method.iter().count() + self.boost.iter().count() + self.name.iter().count();
+
+        let mut map = serializer.serialize_map(Some(len))?;
+        map.serialize_entry("distance", &self.distance)?;
+        map.serialize_entry("distance_type", &self.distance_type)?;
+
+        if let Some(ref validation_method) = self.validation_method {
+            map.serialize_entry("validation_method", validation_method)?;
+        }
+
+        if let Some(ref boost) = self.boost {
+            map.serialize_entry("boost", boost)?;
+        }
+
+        if let Some(ref name) = self.name {
+            map.serialize_entry("_name", name)?;
+        }
+
+        map.serialize_entry(&self.field, &self.point)?;
+        map.end()
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for GeoDistanceField {
+    fn deserialize<D>(deserializer: D) -> Result<GeoDistanceField, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(GeoDistanceFilterVisitor)
+    }
+}
+
+pub(crate) struct GeoDistanceFilterVisitor;
+
+impl<'de> Visitor<'de> for GeoDistanceFilterVisitor {
+    type Value = GeoDistanceField;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a geo distance filter structure")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        use serde::de::Error;
+
+        fn set<T, E: Error>(slot: &mut Option<T>, key: &'static str, value: T) -> Result<(), E> {
+            if slot.is_some() {
+                return Err(E::duplicate_field(key));
+            }
+
+            *slot = Some(value);
+            Ok(())
+        }
+
+        let mut distance = None;
+        let mut distance_type = None;
+        let mut validation_method = None;
+        let mut boost = None;
+        let mut name = None;
+        let mut field: Option<(String, String)> = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_ref() {
+                "distance" => set(&mut distance, "distance", map.next_value()?)?,
+                "distance_type" => set(&mut distance_type, "distance_type", map.next_value()?)?,
+                "validation_method" => set(&mut validation_method, "validation_method", map.next_value()?)?,
+                "boost" => set(&mut boost, "boost", map.next_value()?)?,
+                "_name" => set(&mut name, "_name", map.next_value()?)?,
+                _ => {
+                    // Any other key is the point field, and there can only be one of them
+                    if let Some((ref field, _)) = field {
+                        return Err(A::Error::custom(format!(
+                            "expected a single field, found `{}` and `{}`",
+                            field, key
+                        )));
+                    }
+
+                    field = Some((key, map.next_value()?));
+                }
+            }
+        }
+
+        let distance = distance.ok_or(A::Error::custom("expected distance"))?;
+        let (field, point) = field.ok_or(A::Error::custom("expected field"))?;
+
+        Ok(GeoDistanceField {
+            field: field,
+            point: point,
+            distance: distance,
+            distance_type: distance_type.unwrap_or_default(),
+            validation_method: validation_method,
+            boost: boost,
+            name: name,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn geo_distance_filter_new() {
+        let g = GeoDistanceFilter::new("pin.location", "200km", 40.0, -70.5);
+
+        assert!(g.geo_distance.field == "pin.location");
+        assert!(g.geo_distance.point == "40,-70.5");
+        assert!(g.geo_distance.distance_type == DistanceType::Arc);
+    }
+
+    #[test]
+    fn geo_distance_filter_distance_type() {
+        let arc = GeoDistanceFilter::new("pin.location", "200km", 40.0, -70.0);
+        let plane = arc.clone().distance_type(DistanceType::Plane);
+
+        let o = r#"{"geo_distance":{"distance":"200km","distance_type":"arc","pin.location":"40,-70"}}"#;
+        assert_eq!(o, serde_json::to_string(&arc).unwrap());
+
+        let o = r#"{"geo_distance":{"distance":"200km","distance_type":"plane","pin.location":"40,-70"}}"#;
+        assert_eq!(o, serde_json::to_string(&plane).unwrap());
+    }
+
+    #[test]
+    fn geo_distance_filter() {
+        let o = r#"{"geo_distance":{"distance":"12km","distance_type":"plane","pin.location":"40,-70"}}"#;
+        let s: GeoDistanceFilter = serde_json::from_str(o).unwrap();
+        let j = serde_json::to_string(&s).unwrap();
+        assert_eq!(o, j);
+
+        let o = r#"{"geo_distance":{"pin.location":"40,-70","distance":"12km"}}"#;
+        let s: GeoDistanceFilter = serde_json::from_str(o).unwrap();
+        assert_eq!(DistanceType::Arc, s.geo_distance.distance_type);
+    }
+
+    #[test]
+    fn geo_distance_filter_options() {
+        let g = GeoDistanceFilter::new("pin.location", "12km", 40.0, -70.0)
+            .validation_method(ValidationMethod::IgnoreMalformed)
+            .boost("2")
+            .named("nearby");
+
+        let o = r#"{"geo_distance":{"distance":"12km","distance_type":"arc","validation_method":"IGNORE_MALFORMED","boost":"2","_name":"nearby","pin.location":"40,-70"}}"#;
+        assert_eq!(o, serde_json::to_string(&g).unwrap());
+
+        let s: GeoDistanceFilter = serde_json::from_str(o).unwrap();
+        assert_eq!(g, s);
+
+        let o = r#"{"geo_distance":{"_name":"nearby","pin.location":"40,-70","boost":"2","distance":"12km"}}"#;
+        let s: GeoDistanceFilter = serde_json::from_str(o).unwrap();
+        assert_eq!("pin.location", s.geo_distance.field);
+        assert_eq!(Some("nearby".to_owned()), s.geo_distance.name);
+        assert_eq!(Some("2".to_owned()), s.geo_distance.boost);
+    }
+
+    #[test]
+    fn geo_distance_filter_multiple_fields() {
+        let o = r#"{"geo_distance":{"distance":"12km","pin.location":"40,-70","other.location":"41,-71"}}"#;
+        let s: Result<GeoDistanceFilter, _> = serde_json::from_str(o);
+        assert!(s.is_err());
+
+        let o = r#"{"geo_distance":{"distance":"12km","distance":"10km","pin.location":"40,-70"}}"#;
+        let s: Result<GeoDistanceFilter, _> = serde_json::from_str(o);
+        assert!(s.is_err());
+    }
+}
@@ -1,8 +1,65 @@
+use serde;
+use serde::de::Visitor;
+use serde::ser::{Serialize, Serializer};
+use std::fmt;
+use super::super::Values;
 use super::common::*;
 
 #[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
 pub struct MatchFilter {
-    #[serde(rename = "match")] pub match_: FieldAndValue,
+    #[serde(rename = "match")] pub match_: MatchField,
+}
+
+impl MatchFilter {
+    /// Set a name for the query, which is listed in the `matched_queries` of hits that match it.
+    pub fn named(mut self, name: &str) -> MatchFilter {
+        self.match_.name = Some(name.to_owned());
+        self
+    }
+}
+
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
+pub struct MatchField {
+    pub field: String,
+    pub query: Values,
+    pub name: Option<String>,
+}
+
+impl Serialize for MatchField {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_field_and_value(serializer, &self.field, "query", &self.query, self.name.as_ref())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for MatchField {
+    fn deserialize<D>(deserializer: D) -> Result<MatchField, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(MatchFilterVisitor)
+    }
+}
+
+pub(crate) struct MatchFilterVisitor;
+
+impl<'de> Visitor<'de> for MatchFilterVisitor {
+    type Value = MatchField;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a match filter structure")
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let (field, query, name) = deserialize_field_and_value(map, "query")?;
+
+        Ok(MatchField { field, query, name })
+    }
 }
 
 #[cfg(test)]
@@ -18,4 +75,18 @@ mod tests {
         let j = r#"{ "match": { "content": "Elasticsearch" }}"#;
         let _s: MatchFilter = serde_json::from_str(j).unwrap();
     }
+
+    #[test]
+    fn match_filter_named() {
+        let m: MatchFilter = serde_json::from_str(r#"{"match":{"title":"Search"}}"#).unwrap();
+
+        let o = r#"{"match":{"title":"Search"}}"#;
+        assert_eq!(o, serde_json::to_string(&m).unwrap());
+
+        let o = r#"{"match":{"title":{"query":"Search","_name":"by_title"}}}"#;
+        assert_eq!(o, serde_json::to_string(&m.named("by_title")).unwrap());
+
+        let s: MatchFilter = serde_json::from_str(o).unwrap();
+        assert_eq!(Some("by_title".to_owned()), s.match_.name);
+    }
 }
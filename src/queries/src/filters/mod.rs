@@ -1,11 +1,13 @@
 pub(crate) mod common;
 pub(crate) mod exists;
+pub(crate) mod geo_distance;
 pub(crate) mod matchfilter;
 pub(crate) mod range;
 pub(crate) mod term;
 pub(crate) mod wildcard;
 
 pub(crate) use self::exists::ExistsFilter;
+pub(crate) use self::geo_distance::GeoDistanceFilter;
 pub(crate) use self::matchfilter::MatchFilter;
 pub(crate) use self::range::RangeFilter;
 pub(crate) use self::term::TermFilter;
@@ -20,6 +22,7 @@ pub enum Filters {
     exists(ExistsFilter),
     #[serde(rename = "match")] match_(MatchFilter),
     wildcard(WildcardFilter),
+    geo_distance(GeoDistanceFilter),
 }
 
 impl From<RangeFilter> for Filters {
@@ -51,3 +54,9 @@ impl From<WildcardFilter> for Filters {
         Filters::wildcard(w)
     }
 }
+
+impl From<GeoDistanceFilter> for Filters {
+    fn from(g: GeoDistanceFilter) -> Self {
+        Filters::geo_distance(g)
+    }
+}
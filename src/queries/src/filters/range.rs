@@ -20,6 +20,29 @@ impl RangeFilter {
             },
         }
     }
+
+    /// Set how the range is matched against range fields.
+    pub fn relation(mut self, relation: RangeRelation) -> RangeFilter {
+        self.range.params.relation = Some(relation);
+        self
+    }
+
+    /// Set a name for the query, which is listed in the `matched_queries` of hits that match it.
+    pub fn named(mut self, name: &str) -> RangeFilter {
+        self.range.params.name = Some(name.to_owned());
+        self
+    }
+}
+
+/// How a range matches the values of a range field, like `integer_range` or `date_range`.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub enum RangeRelation {
+    /// Match documents whose range overlaps the query range.
+    #[serde(rename = "INTERSECTS")] Intersects,
+    /// Match documents whose range contains the query range.
+    #[serde(rename = "CONTAINS")] Contains,
+    /// Match documents whose range is within the query range.
+    #[serde(rename = "WITHIN")] Within,
 }
 
 
@@ -46,6 +69,12 @@ pub struct RangeParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default = "None")]
     boost: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default = "None")]
+    relation: Option<RangeRelation>,
+    #[serde(rename = "_name", skip_serializing_if = "Option::is_none")]
+    #[builder(default = "None")]
+    name: Option<String>,
 }
 
 #[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
@@ -130,4 +159,46 @@ mod tests {
         let j = serde_json::to_string(&s).unwrap();
         assert_eq!(o, j);
     }
+
+    #[test]
+    fn range_filter_named() {
+        let p = RangeParamsBuilder::default()
+            .gte(Some(0.into()))
+            .build()
+            .unwrap();
+
+        let r = RangeFilter::new("age", p).named("adults");
+
+        let o = r#"{"range":{"age":{"gte":0,"_name":"adults"}}}"#;
+        let j = serde_json::to_string(&r).unwrap();
+        assert_eq!(o, j);
+
+        let s: RangeFilter = serde_json::from_str(o).unwrap();
+        assert_eq!(r, s);
+    }
+
+    #[test]
+    fn range_filter_relation() {
+        let p = RangeParamsBuilder::default()
+            .gte(Some(0.into()))
+            .build()
+            .unwrap();
+
+        let relations = vec![
+            (RangeRelation::Intersects, "INTERSECTS"),
+            (RangeRelation::Contains, "CONTAINS"),
+            (RangeRelation::Within, "WITHIN"),
+        ];
+
+        for (relation, expected) in relations {
+            let r = RangeFilter::new("age_range", p.clone()).relation(relation);
+
+            let o = format!(r#"{{"range":{{"age_range":{{"gte":0,"relation":"{}"}}}}}}"#, expected);
+            let j = serde_json::to_string(&r).unwrap();
+            assert_eq!(o, j);
+
+            let s: RangeFilter = serde_json::from_str(&o).unwrap();
+            assert_eq!(r, s);
+        }
+    }
 }
@@ -9,9 +9,19 @@ pub struct TermFilter {
 impl TermFilter {
     pub fn new(f: String, v: Values) -> TermFilter {
         TermFilter {
-            term: FieldAndValue { field: f, value: v },
+            term: FieldAndValue {
+                field: f,
+                value: v,
+                name: None,
+            },
         }
     }
+
+    /// Set a name for the query, which is listed in the `matched_queries` of hits that match it.
+    pub fn named(mut self, name: &str) -> TermFilter {
+        self.term.name = Some(name.to_owned());
+        self
+    }
 }
 
 #[cfg(test)]
@@ -33,4 +43,18 @@ mod tests {
         let j = r#"{ "term":  { "status": "published" }}"#;
         let _s: TermFilter = serde_json::from_str(j).unwrap();
     }
+
+    #[test]
+    fn term_filter_named() {
+        let t = TermFilter::new("user".to_owned(), Values::String("kimchy".to_owned()));
+
+        let o = r#"{"term":{"user":"kimchy"}}"#;
+        assert_eq!(o, serde_json::to_string(&t).unwrap());
+
+        let o = r#"{"term":{"user":{"value":"kimchy","_name":"by_user"}}}"#;
+        assert_eq!(o, serde_json::to_string(&t.named("by_user")).unwrap());
+
+        let s: TermFilter = serde_json::from_str(o).unwrap();
+        assert_eq!(Some("by_user".to_owned()), s.term.name);
+    }
 }
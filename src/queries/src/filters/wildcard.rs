@@ -5,6 +5,16 @@ pub struct WildcardFilter {
     pub wildcard: WildcardVariants,
 }
 
+impl WildcardFilter {
+    /// Set a name for the query, which is listed in the `matched_queries` of hits that match it.
+    pub fn named(mut self, name: &str) -> WildcardFilter {
+        match self.wildcard {
+            WildcardVariants::FieldAndValue(ref mut f) => f.name = Some(name.to_owned()),
+        }
+        self
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
 #[serde(untagged)]
 pub enum WildcardVariants {
@@ -27,4 +37,15 @@ mod tests {
         let j = r#"{ "wildcard" : { "user" : "ki*y" } }"#;
         let _s: WildcardFilter = serde_json::from_str(j).unwrap();
     }
+
+    #[test]
+    fn wildcard_filter_named() {
+        let w: WildcardFilter = serde_json::from_str(r#"{"wildcard":{"user":"ki*y"}}"#).unwrap();
+
+        let o = r#"{"wildcard":{"user":{"value":"ki*y","_name":"by_user"}}}"#;
+        assert_eq!(o, serde_json::to_string(&w.named("by_user")).unwrap());
+
+        let s: WildcardFilter = serde_json::from_str(o).unwrap();
+        assert_eq!(o, serde_json::to_string(&s).unwrap());
+    }
 }
@@ -56,9 +56,18 @@ pub struct Bool {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default = "None")]
     pub must_not: Option<Vec<Filters>>,
+    #[serde(rename = "_name", skip_serializing_if = "Option::is_none")]
+    #[builder(default = "None")]
+    pub name: Option<String>,
 }
 
 impl Bool {
+    /// Set a name for the query, which is listed in the `matched_queries` of hits that match it.
+    pub fn named(mut self, name: &str) -> Self {
+        self.name = Some(name.to_owned());
+        self
+    }
+
     fn add_filter(&mut self, section: BoolQuerySections, f: Filters) {
         use BoolQuerySections::*;
 
@@ -394,6 +403,20 @@ mod tests {
         assert_eq!(expected, j);
     }
 
+    #[test]
+    fn named_bool() {
+        let unnamed = BoolBuilder::default()
+            .build()
+            .expect("could not build bool");
+        let named = unnamed.clone().named("recent");
+
+        assert_eq!("{}", serde_json::to_string(&unnamed).unwrap());
+        assert_eq!(r#"{"_name":"recent"}"#, serde_json::to_string(&named).unwrap());
+
+        let parsed: Bool = serde_json::from_str(r#"{ "must": [], "_name": "recent" }"#).unwrap();
+        assert_eq!(Some("recent".to_owned()), parsed.name);
+    }
+
     #[test]
     fn filter() {
         let j = r#"
@@ -3,4 +3,5 @@ pub use BoolQuerySections;
 pub use Query;
 pub use QueryBuilder;
 pub use filters::common::EsDateFormat;
-pub use filters::range::{RangeFilter, RangeParamsBuilder};
+pub use filters::geo_distance::{DistanceType, GeoDistanceFilter, ValidationMethod};
+pub use filters::range::{RangeFilter, RangeParamsBuilder, RangeRelation};
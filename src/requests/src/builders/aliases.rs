@@ -0,0 +1,193 @@
+use serde_json::{Map, Value};
+
+/// A builder for the body of an [update aliases request][docs-aliases].
+///
+/// All of the actions are applied atomically.
+///
+/// # Examples
+///
+/// Point an alias at a new index and remove it from the old one:
+///
+/// ```
+/// # #[macro_use] extern crate serde_json;
+/// # extern crate elastic_requests;
+/// # use elastic_requests::*;
+/// # fn main() {
+/// let body = AliasActionsBuilder::new()
+///     .remove("logs-000001", "logs")
+///     .add(AddAliasBuilder::new("logs-000002", "logs").search_routing("1,2").index_routing("1"))
+///     .build();
+///
+/// let req = IndicesUpdateAliasesRequest::new(body);
+/// # }
+/// ```
+///
+/// [docs-aliases]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct AliasActionsBuilder {
+    actions: Vec<Value>,
+}
+
+impl AliasActionsBuilder {
+    /// Create a new, empty list of alias actions.
+    pub fn new() -> Self {
+        AliasActionsBuilder::default()
+    }
+
+    /// Add an alias to an index.
+    pub fn add<A: Into<Value>>(mut self, add: A) -> Self {
+        self.actions.push(json!({ "add": add.into() }));
+
+        self
+    }
+
+    /// Remove an alias from an index.
+    pub fn remove(mut self, index: &str, alias: &str) -> Self {
+        self.actions.push(json!({ "remove": { "index": index, "alias": alias } }));
+
+        self
+    }
+
+    /// Move an alias from one index to another.
+    ///
+    /// The alias is removed from the old index and added to the new one in the same request,
+    /// so there's no point where it doesn't point to either index.
+    pub fn swap(self, old_index: &str, new_index: &str, alias: &str) -> Self {
+        self.remove(old_index, alias).add(AddAliasBuilder::new(new_index, alias))
+    }
+
+    /// Build the update aliases body.
+    pub fn build(self) -> Value {
+        json!({ "actions": self.actions })
+    }
+}
+
+impl From<AliasActionsBuilder> for Value {
+    fn from(builder: AliasActionsBuilder) -> Value {
+        builder.build()
+    }
+}
+
+/// A builder for a single `add` action in an [update aliases request][docs-aliases].
+///
+/// [docs-aliases]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html
+#[derive(Debug, PartialEq, Clone)]
+pub struct AddAliasBuilder {
+    action: Map<String, Value>,
+}
+
+impl AddAliasBuilder {
+    /// Create an action that adds `alias` to `index`.
+    pub fn new(index: &str, alias: &str) -> Self {
+        let mut action = Map::new();
+        action.insert("index".to_owned(), Value::from(index));
+        action.insert("alias".to_owned(), Value::from(alias));
+
+        AddAliasBuilder { action: action }
+    }
+
+    /// Only search the shards that the given routing value maps to when searching through the alias.
+    ///
+    /// Multiple routing values can be given separated by commas.
+    pub fn search_routing(mut self, routing: &str) -> Self {
+        self.action.insert("search_routing".to_owned(), Value::from(routing));
+
+        self
+    }
+
+    /// Route documents indexed through the alias using the given value.
+    pub fn index_routing(mut self, routing: &str) -> Self {
+        self.action.insert("index_routing".to_owned(), Value::from(routing));
+
+        self
+    }
+
+    /// Only match documents that match the given query when searching through the alias.
+    pub fn filter(mut self, filter: Value) -> Self {
+        self.action.insert("filter".to_owned(), filter);
+
+        self
+    }
+
+    /// Build the add action.
+    pub fn build(self) -> Value {
+        Value::Object(self.action)
+    }
+}
+
+impl From<AddAliasBuilder> for Value {
+    fn from(builder: AddAliasBuilder) -> Value {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alias_actions_body() {
+        let body = AliasActionsBuilder::new()
+            .remove("logs-000001", "logs")
+            .add(AddAliasBuilder::new("logs-000002", "logs"))
+            .build();
+
+        let expected = json!({
+            "actions": [
+                { "remove": { "index": "logs-000001", "alias": "logs" } },
+                { "add": { "index": "logs-000002", "alias": "logs" } }
+            ]
+        });
+
+        assert_eq!(expected, body);
+    }
+
+    #[test]
+    fn add_alias_with_routing_and_filter() {
+        let body = AliasActionsBuilder::new()
+            .add(
+                AddAliasBuilder::new("users", "user_12")
+                    .search_routing("1,2")
+                    .index_routing("2")
+                    .filter(json!({ "term": { "user_id": 12 } })),
+            )
+            .build();
+
+        let expected = json!({
+            "actions": [
+                {
+                    "add": {
+                        "index": "users",
+                        "alias": "user_12",
+                        "search_routing": "1,2",
+                        "index_routing": "2",
+                        "filter": { "term": { "user_id": 12 } }
+                    }
+                }
+            ]
+        });
+
+        assert_eq!(expected, body);
+    }
+
+    #[test]
+    fn swap_alias_actions_body() {
+        let body = AliasActionsBuilder::new()
+            .swap("logs-000001", "logs-000002", "logs")
+            .build();
+
+        let expected = json!({
+            "actions": [
+                { "remove": { "index": "logs-000001", "alias": "logs" } },
+                { "add": { "index": "logs-000002", "alias": "logs" } }
+            ]
+        });
+
+        assert_eq!(expected, body);
+    }
+
+    #[test]
+    fn empty_alias_actions_body() {
+        assert_eq!(json!({ "actions": [] }), Value::from(AliasActionsBuilder::new()));
+    }
+}
@@ -0,0 +1,322 @@
+use std::error::Error;
+use std::fmt;
+
+use serde_json::{Map, Value};
+
+/// The metadata for a single action in a [bulk request][docs-bulk].
+///
+/// [docs-bulk]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-bulk.html
+#[derive(Debug, PartialEq, Clone)]
+pub struct BulkMeta {
+    index: String,
+    id: Option<String>,
+    routing: Option<String>,
+    version: Option<u64>,
+    pipeline: Option<String>,
+}
+
+impl BulkMeta {
+    /// Create metadata for an action on the given index.
+    pub fn new<IIndex>(index: IIndex) -> Self
+    where
+        IIndex: Into<String>,
+    {
+        BulkMeta {
+            index: index.into(),
+            id: None,
+            routing: None,
+            version: None,
+            pipeline: None,
+        }
+    }
+
+    /// Set the id of the document.
+    ///
+    /// If no id is set for an index or create action then Elasticsearch generates one.
+    pub fn id<IId>(mut self, id: IId) -> Self
+    where
+        IId: Into<String>,
+    {
+        self.id = Some(id.into());
+
+        self
+    }
+
+    /// Set the routing value used to pick the shard for the document.
+    pub fn routing<IRouting>(mut self, routing: IRouting) -> Self
+    where
+        IRouting: Into<String>,
+    {
+        self.routing = Some(routing.into());
+
+        self
+    }
+
+    /// Only run the action if the stored document has the given version.
+    pub fn version(mut self, version: u64) -> Self {
+        self.version = Some(version);
+
+        self
+    }
+
+    /// Set the ingest pipeline used to pre-process the document.
+    pub fn pipeline<IPipeline>(mut self, pipeline: IPipeline) -> Self
+    where
+        IPipeline: Into<String>,
+    {
+        self.pipeline = Some(pipeline.into());
+
+        self
+    }
+}
+
+impl From<BulkMeta> for Value {
+    fn from(meta: BulkMeta) -> Value {
+        let mut body = Map::new();
+
+        body.insert("_index".to_owned(), Value::String(meta.index));
+
+        if let Some(id) = meta.id {
+            body.insert("_id".to_owned(), Value::String(id));
+        }
+
+        if let Some(routing) = meta.routing {
+            body.insert("routing".to_owned(), Value::String(routing));
+        }
+
+        if let Some(version) = meta.version {
+            body.insert("version".to_owned(), Value::from(version));
+        }
+
+        if let Some(pipeline) = meta.pipeline {
+            body.insert("pipeline".to_owned(), Value::String(pipeline));
+        }
+
+        Value::Object(body)
+    }
+}
+
+/// A single action in a [bulk request][docs-bulk].
+///
+/// Each action is converted into an action line like `{"index":{"_index":"twitter"}}`.
+///
+/// [docs-bulk]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-bulk.html
+#[derive(Debug, PartialEq, Clone)]
+pub enum BulkAction {
+    /// Index a document, replacing it if it already exists.
+    Index(BulkMeta),
+    /// Index a document, failing if it already exists.
+    Create(BulkMeta),
+    /// Update part of a document.
+    Update(BulkMeta),
+    /// Delete a document.
+    Delete(BulkMeta),
+}
+
+impl From<BulkAction> for Value {
+    fn from(action: BulkAction) -> Value {
+        let (name, meta) = match action {
+            BulkAction::Index(meta) => ("index", meta),
+            BulkAction::Create(meta) => ("create", meta),
+            BulkAction::Update(meta) => ("update", meta),
+            BulkAction::Delete(meta) => ("delete", meta),
+        };
+
+        let mut body = Map::new();
+        body.insert(name.to_owned(), meta.into());
+
+        Value::Object(body)
+    }
+}
+
+/// A builder for the body of a [bulk request][docs-bulk].
+///
+/// The body is a newline-delimited list of action lines, each followed by a source line
+/// for index, create and update actions.
+///
+/// # Examples
+///
+/// Index one document and delete another:
+///
+/// ```
+/// # #[macro_use] extern crate serde_json;
+/// # extern crate elastic_requests;
+/// # use elastic_requests::*;
+/// # fn main() { run().unwrap() }
+/// # fn run() -> Result<(), Box<::std::error::Error>> {
+/// let body = BulkBodyBuilder::new()
+///     .action_with_source(BulkAction::Index(BulkMeta::new("twitter").id("1")), json!({ "user": "kimchy" }))?
+///     .action(BulkAction::Delete(BulkMeta::new("twitter").id("2")))?
+///     .build();
+///
+/// let req = BulkRequest::new(body);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [docs-bulk]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-bulk.html
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct BulkBodyBuilder {
+    lines: Vec<Value>,
+}
+
+impl BulkBodyBuilder {
+    /// Create a new, empty bulk body.
+    pub fn new() -> Self {
+        BulkBodyBuilder::default()
+    }
+
+    /// Add an action without a source document.
+    ///
+    /// Only delete actions don't take a source.
+    /// Index, create and update actions return an error, use `action_with_source` for them instead.
+    pub fn action(mut self, action: BulkAction) -> Result<Self, InvalidBulkAction> {
+        let name = match action {
+            BulkAction::Index(_) => Some("index"),
+            BulkAction::Create(_) => Some("create"),
+            BulkAction::Update(_) => Some("update"),
+            BulkAction::Delete(_) => None,
+        };
+
+        if let Some(name) = name {
+            return Err(InvalidBulkAction {
+                action: name,
+                source_required: true,
+            });
+        }
+
+        self.lines.push(action.into());
+
+        Ok(self)
+    }
+
+    /// Add an action followed by its source document.
+    ///
+    /// For update actions the source is the update body, like `{"doc":{...}}`.
+    /// Delete actions don't take a source, so they return an error.
+    pub fn action_with_source(mut self, action: BulkAction, source: Value) -> Result<Self, InvalidBulkAction> {
+        if let BulkAction::Delete(_) = action {
+            return Err(InvalidBulkAction {
+                action: "delete",
+                source_required: false,
+            });
+        }
+
+        self.lines.push(action.into());
+        self.lines.push(source);
+
+        Ok(self)
+    }
+
+    /// Build the bulk body.
+    ///
+    /// Every line, including the last one, ends with a newline.
+    pub fn build(self) -> String {
+        let mut body = String::new();
+
+        for line in self.lines {
+            body.push_str(&line.to_string());
+            body.push('\n');
+        }
+
+        body
+    }
+}
+
+/// A bulk action that was added to a `BulkBodyBuilder` with a source document it can't have, or without one it needs.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InvalidBulkAction {
+    action: &'static str,
+    source_required: bool,
+}
+
+impl fmt::Display for InvalidBulkAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.source_required {
+            write!(f, "invalid bulk action: '{}' actions must have a source document", self.action)
+        } else {
+            write!(f, "invalid bulk action: '{}' actions can't have a source document", self.action)
+        }
+    }
+}
+
+impl Error for InvalidBulkAction {
+    fn description(&self) -> &str {
+        "invalid bulk action"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bulk_meta_into_value() {
+        let meta = BulkMeta::new("twitter")
+            .id("1")
+            .routing("kimchy")
+            .version(2)
+            .pipeline("my-pipeline");
+
+        let expected = json!({
+            "_index": "twitter",
+            "_id": "1",
+            "routing": "kimchy",
+            "version": 2,
+            "pipeline": "my-pipeline"
+        });
+
+        assert_eq!(expected, Value::from(meta));
+    }
+
+    #[test]
+    fn bulk_actions_into_value() {
+        let meta = BulkMeta::new("twitter");
+
+        assert_eq!(json!({ "index": { "_index": "twitter" } }), Value::from(BulkAction::Index(meta.clone())));
+        assert_eq!(json!({ "create": { "_index": "twitter" } }), Value::from(BulkAction::Create(meta.clone())));
+        assert_eq!(json!({ "update": { "_index": "twitter" } }), Value::from(BulkAction::Update(meta.clone())));
+        assert_eq!(json!({ "delete": { "_index": "twitter" } }), Value::from(BulkAction::Delete(meta)));
+    }
+
+    #[test]
+    fn build_bulk_body() {
+        let body = BulkBodyBuilder::new()
+            .action_with_source(BulkAction::Index(BulkMeta::new("twitter").id("1")), json!({ "user": "kimchy" }))
+            .unwrap()
+            .action(BulkAction::Delete(BulkMeta::new("twitter").id("2")))
+            .unwrap()
+            .build();
+
+        let expected = "{\"index\":{\"_id\":\"1\",\"_index\":\"twitter\"}}\n{\"user\":\"kimchy\"}\n{\"delete\":{\"_id\":\"2\",\"_index\":\"twitter\"}}\n";
+
+        assert_eq!(expected, body);
+    }
+
+    #[test]
+    fn delete_with_source_is_err() {
+        let body = BulkBodyBuilder::new().action_with_source(BulkAction::Delete(BulkMeta::new("twitter").id("1")), json!({}));
+
+        assert!(body.is_err());
+    }
+
+    #[test]
+    fn action_without_source_is_err() {
+        let meta = BulkMeta::new("twitter").id("1");
+
+        for action in vec![BulkAction::Index(meta.clone()), BulkAction::Create(meta.clone()), BulkAction::Update(meta)] {
+            let body = BulkBodyBuilder::new().action(action.clone());
+
+            assert!(body.is_err(), "expected {:?} without a source to be an error", action);
+        }
+    }
+
+    #[test]
+    fn invalid_bulk_action_display() {
+        let missing = BulkBodyBuilder::new().action(BulkAction::Index(BulkMeta::new("twitter"))).unwrap_err();
+        let unexpected = BulkBodyBuilder::new().action_with_source(BulkAction::Delete(BulkMeta::new("twitter")), json!({})).unwrap_err();
+
+        assert_eq!("invalid bulk action: 'index' actions must have a source document", missing.to_string());
+        assert_eq!("invalid bulk action: 'delete' actions can't have a source document", unexpected.to_string());
+    }
+}
@@ -0,0 +1,27 @@
+use genned::endpoints::CountRequest;
+use super::push_url_param;
+
+impl<'a, B> CountRequest<'a, B> {
+    /// Count documents matching a simple query string.
+    ///
+    /// The query is sent as the `q` url parameter using the Lucene query string syntax.
+    /// A query in the request body takes precedence over the url query, so use an empty body.
+    pub fn with_url_query(mut self, q: &str) -> Self {
+        self.url = push_url_param(self.url, "q", q);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use genned::endpoints::CountRequest;
+    use genned::http::empty_body;
+
+    #[test]
+    fn count_with_url_query() {
+        let req = CountRequest::for_index_ty("idx", "ty", empty_body()).with_url_query("user:kimchy");
+
+        assert_eq!("/idx/ty/_count?q=user%3Akimchy", *req.url);
+    }
+}
@@ -0,0 +1,163 @@
+use std::error::Error;
+use std::fmt;
+
+use genned::endpoints::SearchRequest;
+use genned::params::Index;
+
+/// An index on a remote cluster for a [cross cluster search][docs-ccs].
+///
+/// The index is formatted as `cluster:index`.
+///
+/// # Examples
+///
+/// Search the same index on two remote clusters:
+///
+/// ```
+/// # #[macro_use] extern crate serde_json;
+/// # extern crate elastic_requests;
+/// # use elastic_requests::*;
+/// # fn main() { run().unwrap() }
+/// # fn run() -> Result<(), Box<::std::error::Error>> {
+/// let indices = [
+///     CrossClusterIndex::new("cluster_one", "logs-*")?,
+///     CrossClusterIndex::new("cluster_two", "logs-*")?,
+/// ];
+///
+/// let req = SearchRequest::for_cross_cluster_indices(&indices, json!({ "query": { "match_all": {} } }));
+///
+/// assert_eq!("/cluster_one:logs-*,cluster_two:logs-*/_search", *req.url);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [docs-ccs]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-cross-cluster-search.html
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CrossClusterIndex {
+    cluster: String,
+    index: String,
+}
+
+impl CrossClusterIndex {
+    /// Create an index on the given remote cluster.
+    ///
+    /// Neither the cluster nor the index can be empty or contain a `:` or `,`.
+    pub fn new(cluster: &str, index: &str) -> Result<Self, InvalidCrossClusterIndex> {
+        if !is_valid_name(cluster) {
+            return Err(InvalidCrossClusterIndex { name: cluster.to_owned() });
+        }
+
+        if !is_valid_name(index) {
+            return Err(InvalidCrossClusterIndex { name: index.to_owned() });
+        }
+
+        Ok(CrossClusterIndex {
+            cluster: cluster.to_owned(),
+            index: index.to_owned(),
+        })
+    }
+
+    /// The name of the remote cluster.
+    pub fn cluster(&self) -> &str {
+        &self.cluster
+    }
+
+    /// The name of the index on the remote cluster.
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+}
+
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty() && !name.contains(|c| c == ':' || c == ',')
+}
+
+impl fmt::Display for CrossClusterIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.cluster, self.index)
+    }
+}
+
+impl From<CrossClusterIndex> for String {
+    fn from(index: CrossClusterIndex) -> String {
+        index.to_string()
+    }
+}
+
+impl<'a> From<CrossClusterIndex> for Index<'a> {
+    fn from(index: CrossClusterIndex) -> Index<'a> {
+        Index::from(index.to_string())
+    }
+}
+
+/// A cluster or index name that can't be used in a `CrossClusterIndex`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InvalidCrossClusterIndex {
+    name: String,
+}
+
+impl fmt::Display for InvalidCrossClusterIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid cross cluster name '{}': names must be non-empty and can't contain ':' or ','", self.name)
+    }
+}
+
+impl Error for InvalidCrossClusterIndex {
+    fn description(&self) -> &str {
+        "invalid cross cluster name"
+    }
+}
+
+impl<'a, B> SearchRequest<'a, B> {
+    /// Request to: `/{index}/_search` for a list of indices on remote clusters.
+    ///
+    /// The indices are joined into a single comma-separated path segment.
+    pub fn for_cross_cluster_indices(indices: &[CrossClusterIndex], body: B) -> Self {
+        let indices: Vec<String> = indices.iter().map(|index| index.to_string()).collect();
+
+        SearchRequest::for_index(indices.join(","), body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use genned::endpoints::SimpleSearchRequest;
+    use genned::http::empty_body;
+
+    #[test]
+    fn cross_cluster_index_to_string() {
+        let index = CrossClusterIndex::new("cluster_one", "twitter").unwrap();
+
+        assert_eq!("cluster_one:twitter", index.to_string());
+        assert_eq!("cluster_one:twitter", String::from(index));
+    }
+
+    #[test]
+    fn cross_cluster_index_invalid() {
+        assert!(CrossClusterIndex::new("cluster:one", "twitter").is_err());
+        assert!(CrossClusterIndex::new("cluster_one", "a,b").is_err());
+        assert!(CrossClusterIndex::new("", "twitter").is_err());
+        assert!(CrossClusterIndex::new("cluster_one", "").is_err());
+    }
+
+    #[test]
+    fn search_for_cross_cluster_indices() {
+        let indices = [
+            CrossClusterIndex::new("cluster_one", "twitter").unwrap(),
+            CrossClusterIndex::new("cluster_two", "logs-*").unwrap(),
+        ];
+
+        let req = SearchRequest::for_cross_cluster_indices(&indices, empty_body());
+
+        assert_eq!("/cluster_one:twitter,cluster_two:logs-*/_search", *req.url);
+    }
+
+    #[test]
+    fn simple_search_for_cross_cluster_index_ty() {
+        let index = CrossClusterIndex::new("cluster_one", "twitter").unwrap();
+
+        let req = SimpleSearchRequest::for_index_ty(index, "tweet");
+
+        assert_eq!("/cluster_one:twitter/tweet/_search", *req.url);
+    }
+}
@@ -0,0 +1,124 @@
+use std::fmt;
+
+#[cfg(feature = "uuid")]
+use uuid::Uuid;
+
+use genned::params::Id;
+
+/// The id of a document.
+///
+/// Any type that can be converted into a `DocumentId` can be used as the id for requests like
+/// `GetRequest`, `IndexRequest` and `DeleteRequest`.
+/// Enabling the `uuid` feature adds a conversion from `uuid::Uuid`.
+///
+/// # Examples
+///
+/// Get a document with a numeric id:
+///
+/// ```
+/// # use elastic_requests::*;
+/// let req = GetRequest::for_index_ty_id("myindex", "mytype", DocumentId::from(42));
+///
+/// assert_eq!("/myindex/mytype/42", *req.url);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DocumentId(String);
+
+impl DocumentId {
+    /// Get the id as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for DocumentId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<u64> for DocumentId {
+    fn from(id: u64) -> DocumentId {
+        DocumentId(id.to_string())
+    }
+}
+
+impl<'b> From<&'b str> for DocumentId {
+    fn from(id: &'b str) -> DocumentId {
+        DocumentId(id.to_owned())
+    }
+}
+
+impl From<String> for DocumentId {
+    fn from(id: String) -> DocumentId {
+        DocumentId(id)
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl From<Uuid> for DocumentId {
+    fn from(id: Uuid) -> DocumentId {
+        DocumentId(id.hyphenated().to_string())
+    }
+}
+
+impl From<DocumentId> for String {
+    fn from(id: DocumentId) -> String {
+        id.0
+    }
+}
+
+impl<'a> From<DocumentId> for Id<'a> {
+    fn from(id: DocumentId) -> Id<'a> {
+        Id::from(id.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use genned::endpoints::{DeleteRequest, GetRequest, IndexRequest};
+    use genned::http::empty_body;
+
+    #[test]
+    fn document_id_from_u64() {
+        assert_eq!("42", DocumentId::from(42).as_str());
+    }
+
+    #[test]
+    fn document_id_from_str() {
+        assert_eq!(DocumentId::from("1"), DocumentId::from(String::from("1")));
+    }
+
+    #[test]
+    fn requests_for_document_id() {
+        let get = GetRequest::for_index_ty_id("idx", "ty", DocumentId::from(1));
+        let index = IndexRequest::for_index_ty_id("idx", "ty", DocumentId::from(2), empty_body());
+        let delete = DeleteRequest::for_index_ty_id("idx", "ty", DocumentId::from(3));
+
+        assert_eq!("/idx/ty/1", *get.url);
+        assert_eq!("/idx/ty/2", *index.url);
+        assert_eq!("/idx/ty/3", *delete.url);
+    }
+
+    #[test]
+    fn requests_for_u64_document_id() {
+        let id: u64 = 42;
+
+        let get = GetRequest::for_index_ty_id("idx", "ty", DocumentId::from(id));
+        let index = IndexRequest::for_index_ty_id("idx", "ty", DocumentId::from(id), empty_body());
+        let delete = DeleteRequest::for_index_ty_id("idx", "ty", DocumentId::from(id));
+
+        assert_eq!("/idx/ty/42", *get.url);
+        assert_eq!("/idx/ty/42", *index.url);
+        assert_eq!("/idx/ty/42", *delete.url);
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn document_id_from_uuid() {
+        let id = Uuid::parse_str("936da01f-9abd-4d9d-80c7-02af85c822a8").unwrap();
+
+        assert_eq!("936da01f-9abd-4d9d-80c7-02af85c822a8", DocumentId::from(id).as_str());
+    }
+}
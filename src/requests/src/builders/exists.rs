@@ -0,0 +1,90 @@
+use serde_json::Value;
+
+use genned::endpoints::MgetRequest;
+use genned::http::{HttpMethod, HttpRequest, Url};
+use genned::params::{Index, Type};
+use super::push_url_param;
+
+/// A request to check whether a set of documents exist.
+///
+/// This is sent as a [multi get request][docs-mget] that doesn't return any document sources.
+/// To check a single document, use an `ExistsRequest` instead.
+///
+/// # Examples
+///
+/// Check whether two tweets exist:
+///
+/// ```
+/// # #[macro_use] extern crate serde_json;
+/// # extern crate elastic_requests;
+/// # use elastic_requests::*;
+/// # fn main() {
+/// let req = BulkExistsRequest::for_index_ty_ids("twitter", "tweet", &["1", "2"]);
+///
+/// assert_eq!("/twitter/tweet/_mget?_source=false", *req.url);
+/// assert_eq!(json!({ "ids": ["1", "2"] }), req.body);
+/// # }
+/// ```
+///
+/// [docs-mget]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-multi-get.html
+#[derive(Debug, PartialEq, Clone)]
+pub struct BulkExistsRequest<'a> {
+    pub url: Url<'a>,
+    pub body: Value,
+}
+
+impl<'a> BulkExistsRequest<'a> {
+    /// Request to: `/{index}/{type}/_mget` for a list of document ids.
+    pub fn for_index_ty_ids<IIndex, IType>(index: IIndex, ty: IType, ids: &[&str]) -> Self
+    where
+        IIndex: Into<Index<'a>>,
+        IType: Into<Type<'a>>,
+    {
+        let req = MgetRequest::for_index_ty(index, ty, json!({ "ids": ids }));
+
+        BulkExistsRequest {
+            url: push_url_param(req.url, "_source", "false"),
+            body: req.body,
+        }
+    }
+}
+
+impl<'a> Into<HttpRequest<'a, Value>> for BulkExistsRequest<'a> {
+    fn into(self) -> HttpRequest<'a, Value> {
+        HttpRequest {
+            url: self.url,
+            method: HttpMethod::Post,
+            body: Some(self.body),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use genned::endpoints::ExistsRequest;
+
+    #[test]
+    fn bulk_exists_for_index_ty_ids() {
+        let req = BulkExistsRequest::for_index_ty_ids("idx", "ty", &["1", "2"]);
+
+        assert_eq!("/idx/ty/_mget?_source=false", *req.url);
+        assert_eq!(json!({ "ids": ["1", "2"] }), req.body);
+    }
+
+    #[test]
+    fn bulk_exists_into_http_request() {
+        let req: HttpRequest<Value> = BulkExistsRequest::for_index_ty_ids("idx", "ty", &[]).into();
+
+        assert_eq!(HttpMethod::Post, req.method);
+        assert_eq!(Some(json!({ "ids": [] })), req.body);
+    }
+
+    #[test]
+    fn exists_is_head_request() {
+        let req: HttpRequest<_> = ExistsRequest::for_index_ty_id("idx", "ty", "1").into();
+
+        assert_eq!(HttpMethod::Head, req.method);
+        assert_eq!("/idx/ty/1", *req.url);
+    }
+}
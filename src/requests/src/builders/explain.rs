@@ -0,0 +1,35 @@
+use genned::endpoints::ExplainRequest;
+use super::push_source_filter_params;
+
+impl<'a, B> ExplainRequest<'a, B> {
+    /// Filter the fields of the document source that are returned with the explanation.
+    ///
+    /// The `includes` and `excludes` lists are sent as the `_source_include` and
+    /// `_source_exclude` url parameters.
+    /// Empty lists are ignored.
+    pub fn with_source_filtering(mut self, includes: &[&str], excludes: &[&str]) -> Self {
+        self.url = push_source_filter_params(self.url, includes, excludes);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use genned::endpoints::ExplainRequest;
+    use genned::http::empty_body;
+
+    #[test]
+    fn explain_with_source_filtering() {
+        let req = ExplainRequest::for_index_ty_id("idx", "ty", 1, empty_body()).with_source_filtering(&["a", "b.c"], &["d"]);
+
+        assert_eq!("/idx/ty/1/_explain?_source_include=a%2Cb.c&_source_exclude=d", *req.url);
+    }
+
+    #[test]
+    fn explain_with_empty_source_filtering() {
+        let req = ExplainRequest::for_index_ty_id("idx", "ty", 1, empty_body()).with_source_filtering(&[], &[]);
+
+        assert_eq!("/idx/ty/1/_explain", *req.url);
+    }
+}
@@ -0,0 +1,41 @@
+use genned::endpoints::FieldCapsRequest;
+use super::{join_indices, push_url_list_param};
+
+impl<'a, B> FieldCapsRequest<'a, B> {
+    /// Request to: `/{index}/_field_caps` for a list of indices.
+    ///
+    /// The index names are joined into a single comma-separated path segment.
+    pub fn for_indices(indices: &[&str], body: B) -> Self {
+        FieldCapsRequest::for_index(join_indices(indices), body)
+    }
+
+    /// Restrict the capabilities returned to the given field names.
+    ///
+    /// Field names may contain wildcards, like `user.*`.
+    /// The list is sent as the `fields` url parameter and is ignored if it's empty.
+    pub fn with_fields(mut self, fields: &[&str]) -> Self {
+        self.url = push_url_list_param(self.url, "fields", fields);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use genned::endpoints::FieldCapsRequest;
+    use genned::http::empty_body;
+
+    #[test]
+    fn field_caps_for_index_with_fields() {
+        let req = FieldCapsRequest::for_index("idx", empty_body()).with_fields(&["title", "user.*"]);
+
+        assert_eq!("/idx/_field_caps?fields=title%2Cuser.*", *req.url);
+    }
+
+    #[test]
+    fn field_caps_for_indices() {
+        let req = FieldCapsRequest::for_indices(&["idx1", "idx2"], empty_body()).with_fields(&["title"]);
+
+        assert_eq!("/idx1,idx2/_field_caps?fields=title", *req.url);
+    }
+}
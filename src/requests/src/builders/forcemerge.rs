@@ -0,0 +1,52 @@
+use genned::endpoints::IndicesForcemergeRequest;
+use super::push_url_param;
+
+impl<'a, B> IndicesForcemergeRequest<'a, B> {
+    /// Set the number of segments to merge each shard down to.
+    ///
+    /// By default Elasticsearch checks whether a merge needs to happen at all.
+    pub fn max_num_segments(mut self, max_num_segments: u32) -> Self {
+        self.url = push_url_param(self.url, "max_num_segments", &max_num_segments.to_string());
+
+        self
+    }
+
+    /// Only merge away segments that contain deleted documents.
+    pub fn only_expunge_deletes(mut self, only_expunge_deletes: bool) -> Self {
+        self.url = push_url_param(self.url, "only_expunge_deletes", &only_expunge_deletes.to_string());
+
+        self
+    }
+
+    /// Whether to flush the index after merging.
+    ///
+    /// If this isn't set then Elasticsearch will flush the index, which is the same as passing `true`.
+    pub fn flush(mut self, flush: bool) -> Self {
+        self.url = push_url_param(self.url, "flush", &flush.to_string());
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use genned::endpoints::IndicesForcemergeRequest;
+    use genned::http::empty_body;
+
+    #[test]
+    fn forcemerge_for_index() {
+        let req = IndicesForcemergeRequest::for_index("idx", empty_body());
+
+        assert_eq!("/idx/_forcemerge", *req.url);
+    }
+
+    #[test]
+    fn forcemerge_with_params() {
+        let req = IndicesForcemergeRequest::new(empty_body())
+            .max_num_segments(1)
+            .only_expunge_deletes(false)
+            .flush(false);
+
+        assert_eq!("/_forcemerge?max_num_segments=1&only_expunge_deletes=false&flush=false", *req.url);
+    }
+}
@@ -0,0 +1,40 @@
+use genned::endpoints::GetSourceRequest;
+use super::push_source_filter_params;
+
+impl<'a> GetSourceRequest<'a> {
+    /// Only return the given fields of the source document.
+    ///
+    /// Fields in `includes` are returned unless they're also in `excludes`.
+    /// Either list can be empty and both accept wildcards like `user.*`.
+    pub fn with_source_filter(mut self, includes: &[&str], excludes: &[&str]) -> Self {
+        self.url = push_source_filter_params(self.url, includes, excludes);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use genned::endpoints::GetSourceRequest;
+
+    #[test]
+    fn get_source_url() {
+        let req = GetSourceRequest::for_index_ty_id("idx", "ty", "1");
+
+        assert_eq!("/idx/ty/1/_source", *req.url);
+    }
+
+    #[test]
+    fn get_source_with_source_filter() {
+        let req = GetSourceRequest::for_index_ty_id("idx", "ty", "1").with_source_filter(&["title", "user.*"], &["user.password"]);
+
+        assert_eq!("/idx/ty/1/_source?_source_include=title%2Cuser.*&_source_exclude=user.password", *req.url);
+    }
+
+    #[test]
+    fn get_source_with_empty_source_filter() {
+        let req = GetSourceRequest::for_index_ty_id("idx", "ty", "1").with_source_filter(&[], &["user.password"]);
+
+        assert_eq!("/idx/ty/1/_source?_source_exclude=user.password", *req.url);
+    }
+}
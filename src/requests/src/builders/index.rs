@@ -0,0 +1,72 @@
+use serde::Serialize;
+use serde_json;
+
+use genned::endpoints::IndexRequest;
+use genned::params::{Id, Index, Type};
+
+impl<'a> IndexRequest<'a, Vec<u8>> {
+    /// Request to: `/{index}/{type}/{id}` with a document serialised as json.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate serde_json;
+    /// # extern crate elastic_requests;
+    /// # use elastic_requests::*;
+    /// # fn main() {
+    /// let doc = json!({ "title": "a document" });
+    ///
+    /// let req = IndexRequest::for_index_ty_id_doc("myindex", "mytype", "1", &doc).unwrap();
+    /// # }
+    /// ```
+    pub fn for_index_ty_id_doc<IIndex, IType, IId, T>(index: IIndex, ty: IType, id: IId, doc: &T) -> Result<Self, serde_json::Error>
+    where
+        IIndex: Into<Index<'a>>,
+        IType: Into<Type<'a>>,
+        IId: Into<Id<'a>>,
+        T: Serialize,
+    {
+        let body = serde_json::to_vec(doc)?;
+
+        Ok(IndexRequest::for_index_ty_id(index, ty, id, body))
+    }
+
+    /// Request to: `/{index}/{type}` with a document serialised as json.
+    ///
+    /// Elasticsearch generates an id for the document.
+    pub fn for_index_ty_doc<IIndex, IType, T>(index: IIndex, ty: IType, doc: &T) -> Result<Self, serde_json::Error>
+    where
+        IIndex: Into<Index<'a>>,
+        IType: Into<Type<'a>>,
+        T: Serialize,
+    {
+        let body = serde_json::to_vec(doc)?;
+
+        Ok(IndexRequest::for_index_ty(index, ty, body))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use genned::endpoints::IndexRequest;
+
+    #[test]
+    fn index_for_index_ty_id_doc() {
+        let mut doc = BTreeMap::new();
+        doc.insert("title", "a document");
+
+        let req = IndexRequest::for_index_ty_id_doc("idx", "ty", "1", &doc).unwrap();
+
+        assert_eq!("/idx/ty/1", *req.url);
+        assert_eq!(br#"{"title":"a document"}"#.to_vec(), req.body);
+    }
+
+    #[test]
+    fn index_for_index_ty_doc() {
+        let req = IndexRequest::for_index_ty_doc("idx", "ty", &json!({ "title": "a document" })).unwrap();
+
+        assert_eq!("/idx/ty", *req.url);
+        assert_eq!(br#"{"title":"a document"}"#.to_vec(), req.body);
+    }
+}
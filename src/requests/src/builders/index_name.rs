@@ -0,0 +1,360 @@
+use std::convert::{Infallible, TryFrom, TryInto};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use genned::endpoints::{DeleteRequest, GetRequest, IndexRequest, SearchRequest, SimpleSearchRequest};
+use genned::params::{Id, Index, Type};
+
+/// A valid index name.
+///
+/// Index names can't be empty, can't contain uppercase letters and can't contain any of
+/// `\`, `/`, `*`, `?`, `"`, `<`, `>`, `|`, ` `, `,` or `#`.
+/// Any request that takes an index can be given an `IndexName`, so a name can be checked
+/// before the request is built.
+/// The `try_*` constructors on `SearchRequest`, `IndexRequest`, `GetRequest` and `DeleteRequest`
+/// check their index the same way and return an `InvalidIndexName` if it isn't valid.
+///
+/// # Examples
+///
+/// Check a user-supplied index name before searching it:
+///
+/// ```
+/// # #[macro_use] extern crate serde_json;
+/// # extern crate elastic_requests;
+/// # use elastic_requests::*;
+/// # fn main() { run().unwrap() }
+/// # fn run() -> Result<(), Box<::std::error::Error>> {
+/// let index = IndexName::new("logs-2018.01.01")?;
+///
+/// let req = SearchRequest::for_index_ty(index, "log", json!({ "query": { "match_all": {} } }));
+///
+/// assert_eq!("/logs-2018.01.01/log/_search", *req.url);
+/// assert!(IndexName::new("").is_err());
+///
+/// let req = GetRequest::try_for_index_ty_id("logs-2018.01.01", "log", "1")?;
+///
+/// assert_eq!("/logs-2018.01.01/log/1", *req.url);
+/// assert!(GetRequest::try_for_index_ty_id("Logs", "log", "1").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct IndexName(String);
+
+impl IndexName {
+    /// Create an index name, checking that it's valid.
+    pub fn new<IName>(name: IName) -> Result<Self, InvalidIndexName>
+    where
+        IName: Into<String>,
+    {
+        let name = name.into();
+
+        if is_valid_index_name(&name) {
+            Ok(IndexName(name))
+        } else {
+            Err(InvalidIndexName { name: name })
+        }
+    }
+
+    /// Create an index name without checking that it's valid.
+    ///
+    /// # Safety
+    ///
+    /// An invalid name can't cause memory unsafety, but requests built with one will fail when
+    /// they're sent, or in the case of an empty name target every index instead of one.
+    /// The caller must make sure the name is valid.
+    pub unsafe fn new_unchecked<IName>(name: IName) -> Self
+    where
+        IName: Into<String>,
+    {
+        IndexName(name.into())
+    }
+
+    /// Get the index name as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+fn is_valid_index_name(name: &str) -> bool {
+    const INVALID_CHARS: &'static [char] = &['\\', '/', '*', '?', '"', '<', '>', '|', ' ', ',', '#'];
+
+    !name.is_empty() && !name.contains(|c: char| c.is_uppercase() || INVALID_CHARS.contains(&c))
+}
+
+impl FromStr for IndexName {
+    type Err = InvalidIndexName;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        IndexName::new(name)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for IndexName {
+    type Error = InvalidIndexName;
+
+    fn try_from(name: &'a str) -> Result<Self, Self::Error> {
+        IndexName::new(name)
+    }
+}
+
+impl TryFrom<String> for IndexName {
+    type Error = InvalidIndexName;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        IndexName::new(name)
+    }
+}
+
+impl fmt::Display for IndexName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<IndexName> for String {
+    fn from(name: IndexName) -> String {
+        name.0
+    }
+}
+
+impl<'a> From<IndexName> for Index<'a> {
+    fn from(name: IndexName) -> Index<'a> {
+        Index::from(name.0)
+    }
+}
+
+/// A name that can't be used in an `IndexName`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InvalidIndexName {
+    name: String,
+}
+
+impl fmt::Display for InvalidIndexName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid index name '{}': names must be non-empty, lowercase and can't contain '\\', '/', '*', '?', '\"', '<', '>', '|', ' ', ',' or '#'", self.name)
+    }
+}
+
+impl Error for InvalidIndexName {
+    fn description(&self) -> &str {
+        "invalid index name"
+    }
+}
+
+// Converting an `IndexName` into itself can't fail
+impl From<Infallible> for InvalidIndexName {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+fn try_index_name<IIndex>(index: IIndex) -> Result<IndexName, InvalidIndexName>
+where
+    IIndex: TryInto<IndexName>,
+    InvalidIndexName: From<IIndex::Error>,
+{
+    Ok(index.try_into()?)
+}
+
+/// Check each index in a comma-separated list of indices to search.
+///
+/// Every index must be a valid `IndexName`, so an empty index can't be dropped from the url
+/// to search every index instead.
+fn check_search_index(index: String) -> Result<String, InvalidIndexName> {
+    if index.split(',').all(is_valid_index_name) {
+        Ok(index)
+    } else {
+        Err(InvalidIndexName { name: index })
+    }
+}
+
+impl<'a, B> SearchRequest<'a, B> {
+    /// Request to: `/{index}/_search`, checking that each index is a valid `IndexName`.
+    pub fn try_for_index<IIndex>(index: IIndex, body: B) -> Result<Self, InvalidIndexName>
+    where
+        IIndex: Into<String>,
+    {
+        let index = check_search_index(index.into())?;
+
+        Ok(SearchRequest::for_index(index, body))
+    }
+
+    /// Request to: `/{index}/{type}/_search`, checking that each index is a valid `IndexName`.
+    pub fn try_for_index_ty<IIndex, IType>(index: IIndex, ty: IType, body: B) -> Result<Self, InvalidIndexName>
+    where
+        IIndex: Into<String>,
+        IType: Into<String>,
+    {
+        let index = check_search_index(index.into())?;
+
+        Ok(SearchRequest::for_index_ty(index, ty.into(), body))
+    }
+}
+
+impl<'a> SimpleSearchRequest<'a> {
+    /// Request to: `/{index}/_search`, checking that each index is a valid `IndexName`.
+    pub fn try_for_index<IIndex>(index: IIndex) -> Result<Self, InvalidIndexName>
+    where
+        IIndex: Into<String>,
+    {
+        let index = check_search_index(index.into())?;
+
+        Ok(SimpleSearchRequest::for_index(index))
+    }
+
+    /// Request to: `/{index}/{type}/_search`, checking that each index is a valid `IndexName`.
+    pub fn try_for_index_ty<IIndex, IType>(index: IIndex, ty: IType) -> Result<Self, InvalidIndexName>
+    where
+        IIndex: Into<String>,
+        IType: Into<String>,
+    {
+        let index = check_search_index(index.into())?;
+
+        Ok(SimpleSearchRequest::for_index_ty(index, ty.into()))
+    }
+}
+
+impl<'a, B> IndexRequest<'a, B> {
+    /// Request to: `/{index}/{type}`, checking that the index is a valid `IndexName`.
+    pub fn try_for_index_ty<IIndex, IType>(index: IIndex, ty: IType, body: B) -> Result<Self, InvalidIndexName>
+    where
+        IIndex: TryInto<IndexName>,
+        InvalidIndexName: From<IIndex::Error>,
+        IType: Into<Type<'a>>,
+    {
+        Ok(IndexRequest::for_index_ty(try_index_name(index)?, ty, body))
+    }
+
+    /// Request to: `/{index}/{type}/{id}`, checking that the index is a valid `IndexName`.
+    pub fn try_for_index_ty_id<IIndex, IType, IId>(index: IIndex, ty: IType, id: IId, body: B) -> Result<Self, InvalidIndexName>
+    where
+        IIndex: TryInto<IndexName>,
+        InvalidIndexName: From<IIndex::Error>,
+        IType: Into<Type<'a>>,
+        IId: Into<Id<'a>>,
+    {
+        Ok(IndexRequest::for_index_ty_id(try_index_name(index)?, ty, id, body))
+    }
+}
+
+impl<'a> GetRequest<'a> {
+    /// Request to: `/{index}/{type}/{id}`, checking that the index is a valid `IndexName`.
+    pub fn try_for_index_ty_id<IIndex, IType, IId>(index: IIndex, ty: IType, id: IId) -> Result<Self, InvalidIndexName>
+    where
+        IIndex: TryInto<IndexName>,
+        InvalidIndexName: From<IIndex::Error>,
+        IType: Into<Type<'a>>,
+        IId: Into<Id<'a>>,
+    {
+        Ok(GetRequest::for_index_ty_id(try_index_name(index)?, ty, id))
+    }
+}
+
+impl<'a> DeleteRequest<'a> {
+    /// Request to: `/{index}/{type}/{id}`, checking that the index is a valid `IndexName`.
+    pub fn try_for_index_ty_id<IIndex, IType, IId>(index: IIndex, ty: IType, id: IId) -> Result<Self, InvalidIndexName>
+    where
+        IIndex: TryInto<IndexName>,
+        InvalidIndexName: From<IIndex::Error>,
+        IType: Into<Type<'a>>,
+        IId: Into<Id<'a>>,
+    {
+        Ok(DeleteRequest::for_index_ty_id(try_index_name(index)?, ty, id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use genned::http::empty_body;
+
+    #[test]
+    fn index_name_valid() {
+        assert_eq!("twitter", IndexName::new("twitter").unwrap().as_str());
+        assert_eq!("logs-2018.01.01", "logs-2018.01.01".parse::<IndexName>().unwrap().as_str());
+    }
+
+    #[test]
+    fn index_name_invalid() {
+        assert!(IndexName::new("").is_err());
+        assert!(IndexName::new("Twitter").is_err());
+
+        for name in &["a\\b", "a/b", "a*", "a?", "a\"b", "a<b", "a>b", "a|b", "a b", "a,b", "a#b"] {
+            assert!(IndexName::new(*name).is_err(), "expected '{}' to be invalid", name);
+        }
+    }
+
+    #[test]
+    fn index_name_unchecked() {
+        assert_eq!("Twitter", unsafe { IndexName::new_unchecked("Twitter") }.as_str());
+    }
+
+    #[test]
+    fn requests_for_index_name() {
+        let index = IndexName::new("idx").unwrap();
+
+        let get = GetRequest::for_index_ty_id(index.clone(), "ty", "1");
+        let put = IndexRequest::for_index_ty_id(index.clone(), "ty", "1", empty_body());
+        let delete = DeleteRequest::for_index_ty_id(index, "ty", "1");
+
+        assert_eq!("/idx/ty/1", *get.url);
+        assert_eq!("/idx/ty/1", *put.url);
+        assert_eq!("/idx/ty/1", *delete.url);
+    }
+
+    #[test]
+    fn index_name_try_from() {
+        assert_eq!("twitter", IndexName::try_from("twitter").unwrap().as_str());
+        assert_eq!("twitter", IndexName::try_from("twitter".to_owned()).unwrap().as_str());
+
+        assert!(IndexName::try_from("").is_err());
+        assert!(IndexName::try_from("Twitter".to_owned()).is_err());
+    }
+
+    #[test]
+    fn search_try_for_index() {
+        let req = SearchRequest::try_for_index("logs", empty_body()).unwrap();
+        assert_eq!("/logs/_search", *req.url);
+
+        let req = SimpleSearchRequest::try_for_index_ty("a,b", "ty").unwrap();
+        assert_eq!("/a,b/ty/_search", *req.url);
+
+        let req = SimpleSearchRequest::try_for_index(IndexName::new("idx").unwrap()).unwrap();
+        assert_eq!("/idx/_search", *req.url);
+    }
+
+    #[test]
+    fn search_try_for_invalid_index() {
+        assert!(SearchRequest::try_for_index("", empty_body()).is_err());
+        assert!(SearchRequest::try_for_index_ty("", "ty", empty_body()).is_err());
+        assert!(SearchRequest::try_for_index("UPPER", empty_body()).is_err());
+        assert!(SimpleSearchRequest::try_for_index("a*b").is_err());
+        assert!(SimpleSearchRequest::try_for_index_ty("a,", "ty").is_err());
+        assert!(SimpleSearchRequest::try_for_index_ty("a,B", "ty").is_err());
+    }
+
+    #[test]
+    fn document_requests_try_for_index() {
+        let get = GetRequest::try_for_index_ty_id("idx", "ty", "1").unwrap();
+        let put = IndexRequest::try_for_index_ty_id("idx".to_owned(), "ty", "1", empty_body()).unwrap();
+        let post = IndexRequest::try_for_index_ty("idx", "ty", empty_body()).unwrap();
+        let delete = DeleteRequest::try_for_index_ty_id(IndexName::new("idx").unwrap(), "ty", "1").unwrap();
+
+        assert_eq!("/idx/ty/1", *get.url);
+        assert_eq!("/idx/ty/1", *put.url);
+        assert_eq!("/idx/ty", *post.url);
+        assert_eq!("/idx/ty/1", *delete.url);
+    }
+
+    #[test]
+    fn document_requests_try_for_invalid_index() {
+        for index in &["", "UPPER", "a*b"] {
+            assert!(GetRequest::try_for_index_ty_id(*index, "ty", "1").is_err());
+            assert!(IndexRequest::try_for_index_ty_id(*index, "ty", "1", empty_body()).is_err());
+            assert!(IndexRequest::try_for_index_ty(*index, "ty", empty_body()).is_err());
+            assert!(DeleteRequest::try_for_index_ty_id(*index, "ty", "1").is_err());
+        }
+    }
+}
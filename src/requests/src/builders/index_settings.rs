@@ -0,0 +1,137 @@
+use serde_json::{Map, Value};
+
+/// How often the translog is committed to disk.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TranslogDurability {
+    /// Commit the translog after every request.
+    ///
+    /// This is the default.
+    Request,
+    /// Commit the translog in the background.
+    ///
+    /// Writes made since the last commit may be lost if a node fails.
+    Async,
+}
+
+impl TranslogDurability {
+    fn as_str(&self) -> &'static str {
+        match *self {
+            TranslogDurability::Request => "request",
+            TranslogDurability::Async => "async",
+        }
+    }
+}
+
+/// A builder for the body of a [put index settings request][docs-update-settings].
+///
+/// # Examples
+///
+/// Turn off refreshes and replicas while bulk loading an index:
+///
+/// ```
+/// # extern crate elastic_requests;
+/// # use elastic_requests::*;
+/// # fn main() {
+/// let body = IndexSettingsBodyBuilder::new()
+///     .number_of_replicas(0)
+///     .refresh_interval("-1")
+///     .translog_durability(TranslogDurability::Async)
+///     .build();
+///
+/// let req = IndicesPutSettingsRequest::for_index("myindex", body);
+/// # }
+/// ```
+///
+/// The `codec` is a static setting, so it can only be changed on a closed index.
+///
+/// [docs-update-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-update-settings.html
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct IndexSettingsBodyBuilder {
+    index: Map<String, Value>,
+}
+
+impl IndexSettingsBodyBuilder {
+    /// Create a new, empty index settings body.
+    pub fn new() -> Self {
+        IndexSettingsBodyBuilder::default()
+    }
+
+    /// Set the number of replicas each primary shard has.
+    pub fn number_of_replicas(mut self, number_of_replicas: u32) -> Self {
+        self.index.insert("number_of_replicas".to_owned(), Value::from(number_of_replicas));
+
+        self
+    }
+
+    /// Set how often the index is refreshed, like `1s`.
+    ///
+    /// A value of `-1` turns refreshes off.
+    pub fn refresh_interval(mut self, refresh_interval: &str) -> Self {
+        self.index.insert("refresh_interval".to_owned(), Value::String(refresh_interval.to_owned()));
+
+        self
+    }
+
+    /// Set how often the translog is committed to disk.
+    pub fn translog_durability(mut self, durability: TranslogDurability) -> Self {
+        self.index.insert("translog".to_owned(), json!({ "durability": durability.as_str() }));
+
+        self
+    }
+
+    /// Set the compression used for stored fields, like `best_compression`.
+    pub fn codec(mut self, codec: &str) -> Self {
+        self.index.insert("codec".to_owned(), Value::String(codec.to_owned()));
+
+        self
+    }
+
+    /// Build the index settings body.
+    pub fn build(self) -> Value {
+        let mut body = Map::new();
+        body.insert("index".to_owned(), Value::Object(self.index));
+
+        Value::Object(body)
+    }
+}
+
+impl From<IndexSettingsBodyBuilder> for Value {
+    fn from(builder: IndexSettingsBodyBuilder) -> Value {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_settings_body() {
+        let body = IndexSettingsBodyBuilder::new()
+            .number_of_replicas(2)
+            .refresh_interval("30s")
+            .translog_durability(TranslogDurability::Async)
+            .codec("best_compression")
+            .build();
+
+        let expected = json!({
+            "index": {
+                "number_of_replicas": 2,
+                "refresh_interval": "30s",
+                "translog": { "durability": "async" },
+                "codec": "best_compression"
+            }
+        });
+
+        assert_eq!(expected, body);
+    }
+
+    #[test]
+    fn translog_durability_request() {
+        let body = IndexSettingsBodyBuilder::new()
+            .translog_durability(TranslogDurability::Request)
+            .build();
+
+        assert_eq!(json!({ "index": { "translog": { "durability": "request" } } }), body);
+    }
+}
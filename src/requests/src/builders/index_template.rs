@@ -0,0 +1,121 @@
+use serde_json::{Map, Value};
+
+/// A builder for the body of a [put index template request][docs-templates].
+///
+/// # Examples
+///
+/// Create a template that's applied to all new `logs-*` indices:
+///
+/// ```
+/// # #[macro_use] extern crate serde_json;
+/// # extern crate elastic_requests;
+/// # use elastic_requests::*;
+/// # fn main() {
+/// let body = IndexTemplateBodyBuilder::new()
+///     .index_patterns(&["logs-*"])
+///     .order(1)
+///     .settings(json!({ "number_of_shards": 1 }))
+///     .mappings(json!({ "log": { "properties": { "message": { "type": "text" } } } }))
+///     .build();
+///
+/// let req = IndicesPutTemplateRequest::for_name("logs", body);
+/// # }
+/// ```
+///
+/// The template can be fetched with an `IndicesGetTemplateRequest` and removed with an `IndicesDeleteTemplateRequest`.
+///
+/// [docs-templates]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct IndexTemplateBodyBuilder {
+    body: Map<String, Value>,
+}
+
+impl IndexTemplateBodyBuilder {
+    /// Create a new, empty index template body.
+    pub fn new() -> Self {
+        IndexTemplateBodyBuilder::default()
+    }
+
+    /// Set the index name patterns the template applies to.
+    pub fn index_patterns(mut self, patterns: &[&str]) -> Self {
+        let patterns = patterns.iter().map(|pattern| Value::String((*pattern).to_owned())).collect();
+
+        self.body.insert("index_patterns".to_owned(), Value::Array(patterns));
+
+        self
+    }
+
+    /// Set the order the template is merged in.
+    ///
+    /// Templates with a higher order override templates with a lower one when more than one matches an index.
+    pub fn order(mut self, order: i32) -> Self {
+        self.body.insert("order".to_owned(), Value::from(order));
+
+        self
+    }
+
+    /// Set the index settings.
+    pub fn settings(mut self, settings: Value) -> Self {
+        self.body.insert("settings".to_owned(), settings);
+
+        self
+    }
+
+    /// Set the type mappings.
+    pub fn mappings(mut self, mappings: Value) -> Self {
+        self.body.insert("mappings".to_owned(), mappings);
+
+        self
+    }
+
+    /// Set the aliases added to matching indices.
+    pub fn aliases(mut self, aliases: Value) -> Self {
+        self.body.insert("aliases".to_owned(), aliases);
+
+        self
+    }
+
+    /// Build the index template body.
+    pub fn build(self) -> Value {
+        Value::Object(self.body)
+    }
+}
+
+impl From<IndexTemplateBodyBuilder> for Value {
+    fn from(builder: IndexTemplateBodyBuilder) -> Value {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_template_body() {
+        let body = IndexTemplateBodyBuilder::new()
+            .index_patterns(&["te*", "bar*"])
+            .order(2)
+            .settings(json!({ "number_of_shards": 1 }))
+            .mappings(json!({ "type1": { "_source": { "enabled": false } } }))
+            .aliases(json!({ "alias1": {} }))
+            .build();
+
+        let expected = json!({
+            "index_patterns": ["te*", "bar*"],
+            "order": 2,
+            "settings": { "number_of_shards": 1 },
+            "mappings": { "type1": { "_source": { "enabled": false } } },
+            "aliases": { "alias1": {} }
+        });
+
+        assert_eq!(expected, body);
+    }
+
+    #[test]
+    fn empty_index_template_body() {
+        let body = IndexTemplateBodyBuilder::new().build();
+
+        assert_eq!(json!({}), body);
+    }
+}
@@ -0,0 +1,140 @@
+//! Hand-written builders that complement the generated endpoints.
+//!
+//! The generated request types only know how to build url paths.
+//! The methods added here cover url query parameters and request bodies
+//! that are awkward to get right by hand.
+
+use url::form_urlencoded;
+
+use genned::http::Url;
+use genned::params::Index;
+
+mod explain;
+mod suggest;
+mod search_template;
+mod field_caps;
+mod count;
+mod forcemerge;
+mod index_template;
+mod pipeline;
+mod routing;
+mod search;
+mod rollover;
+mod index_settings;
+mod validate_query;
+mod termvectors;
+mod more_like_this;
+mod index;
+mod get_source;
+mod aliases;
+mod preference;
+mod source_filter;
+mod script;
+mod version;
+mod cross_cluster;
+mod bulk;
+mod search_after;
+mod document_id;
+mod index_name;
+mod exists;
+
+pub use self::suggest::SuggestBodyBuilder;
+pub use self::search_template::SearchTemplateBodyBuilder;
+pub use self::index_template::IndexTemplateBodyBuilder;
+pub use self::pipeline::PipelineBodyBuilder;
+pub use self::rollover::RolloverBodyBuilder;
+pub use self::index_settings::{IndexSettingsBodyBuilder, TranslogDurability};
+pub use self::validate_query::ValidateQueryBodyBuilder;
+pub use self::more_like_this::MoreLikeThisQueryBuilder;
+pub use self::aliases::{AddAliasBuilder, AliasActionsBuilder};
+pub use self::preference::Preference;
+pub use self::source_filter::SourceFilter;
+pub use self::script::{ScriptBody, ScriptBodyBuilder};
+pub use self::version::VersionType;
+pub use self::cross_cluster::{CrossClusterIndex, InvalidCrossClusterIndex};
+pub use self::bulk::{BulkAction, BulkBodyBuilder, BulkMeta, InvalidBulkAction};
+pub use self::search_after::SearchAfterCursor;
+pub use self::document_id::DocumentId;
+pub use self::index_name::{IndexName, InvalidIndexName};
+pub use self::exists::BulkExistsRequest;
+
+/// Join a list of index names into a single comma-separated index.
+fn join_indices<'a>(indices: &[&str]) -> Index<'a> {
+    Index::from(indices.join(","))
+}
+
+/// Append a query parameter to a request url.
+///
+/// Both the key and value are url encoded.
+fn push_url_param<'a>(url: Url<'a>, key: &str, value: &str) -> Url<'a> {
+    let mut url = url.to_string();
+
+    url.push(if url.contains('?') { '&' } else { '?' });
+    url.extend(form_urlencoded::byte_serialize(key.as_bytes()));
+    url.push('=');
+    url.extend(form_urlencoded::byte_serialize(value.as_bytes()));
+
+    Url::from(url)
+}
+
+/// Append a comma-separated list query parameter to a request url.
+///
+/// If `values` is empty then the url is returned unchanged.
+fn push_url_list_param<'a>(url: Url<'a>, key: &str, values: &[&str]) -> Url<'a> {
+    if values.is_empty() {
+        url
+    } else {
+        push_url_param(url, key, &values.join(","))
+    }
+}
+
+/// Append source filtering query parameters to a request url.
+///
+/// The parameters use the `_source_include` and `_source_exclude` names from the bundled spec.
+/// Empty lists are ignored.
+fn push_source_filter_params<'a>(url: Url<'a>, includes: &[&str], excludes: &[&str]) -> Url<'a> {
+    let url = push_url_list_param(url, "_source_include", includes);
+
+    push_url_list_param(url, "_source_exclude", excludes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn join_multiple_indices() {
+        let index = join_indices(&["a", "b"]);
+
+        assert_eq!("a,b", &*index);
+    }
+
+    #[test]
+    fn push_first_url_param() {
+        let url = push_url_param(Url::from("/_search"), "q", "*,");
+
+        assert_eq!("/_search?q=*%2C", *url);
+    }
+
+    #[test]
+    fn push_multiple_url_params() {
+        let url = push_url_param(Url::from("/_search"), "a", "1");
+        let url = push_url_param(url, "b", "2");
+
+        assert_eq!("/_search?a=1&b=2", *url);
+    }
+
+    #[test]
+    fn push_empty_url_list_param() {
+        let url = push_url_list_param(Url::from("/_search"), "a", &[]);
+
+        assert_eq!("/_search", *url);
+    }
+
+    #[test]
+    fn push_source_filter_url_params() {
+        let url = push_source_filter_params(Url::from("/idx/ty/1"), &["a"], &["b"]);
+
+        assert_eq!("/idx/ty/1?_source_include=a&_source_exclude=b", *url);
+    }
+}
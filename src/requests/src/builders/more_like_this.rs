@@ -0,0 +1,154 @@
+use serde_json::{Map, Value};
+
+/// A builder for a [more like this query][docs-mlt].
+///
+/// The built query can be used as the `query` node of a search body.
+///
+/// # Examples
+///
+/// Find documents like two existing ones, using their `title` and `body`:
+///
+/// ```
+/// # #[macro_use] extern crate serde_json;
+/// # extern crate elastic_requests;
+/// # use elastic_requests::*;
+/// # fn main() {
+/// let query = MoreLikeThisQueryBuilder::new()
+///     .fields(&["title", "body"])
+///     .like_ids("myindex", "mytype", &["1", "2"])
+///     .min_term_freq(1)
+///     .max_query_terms(12)
+///     .build();
+///
+/// let req = SearchRequest::for_index("myindex", json!({ "query": query }));
+/// # }
+/// ```
+///
+/// [docs-mlt]: https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-mlt-query.html
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct MoreLikeThisQueryBuilder {
+    query: Map<String, Value>,
+    like: Vec<Value>,
+}
+
+impl MoreLikeThisQueryBuilder {
+    /// Create a new, empty more like this query.
+    pub fn new() -> Self {
+        MoreLikeThisQueryBuilder::default()
+    }
+
+    /// Set the fields to take terms from.
+    ///
+    /// If this isn't set then the `index.query.default_field` setting is used.
+    pub fn fields(mut self, fields: &[&str]) -> Self {
+        let fields = fields.iter().map(|field| Value::String((*field).to_owned())).collect();
+
+        self.query.insert("fields".to_owned(), Value::Array(fields));
+
+        self
+    }
+
+    /// Find documents like the documents with the given ids.
+    ///
+    /// Calling this more than once adds to the documents to match.
+    pub fn like_ids(mut self, index: &str, ty: &str, ids: &[&str]) -> Self {
+        self.like.extend(ids.iter().map(|id| json!({ "_index": index, "_type": ty, "_id": id })));
+
+        self
+    }
+
+    /// Ignore terms that appear fewer than this many times in the input documents.
+    pub fn min_term_freq(mut self, min_term_freq: u32) -> Self {
+        self.query.insert("min_term_freq".to_owned(), Value::from(min_term_freq));
+
+        self
+    }
+
+    /// Set the maximum number of terms the query will select.
+    pub fn max_query_terms(mut self, max_query_terms: u32) -> Self {
+        self.query.insert("max_query_terms".to_owned(), Value::from(max_query_terms));
+
+        self
+    }
+
+    /// Ignore terms that appear in fewer than this many documents.
+    pub fn min_doc_freq(mut self, min_doc_freq: u32) -> Self {
+        self.query.insert("min_doc_freq".to_owned(), Value::from(min_doc_freq));
+
+        self
+    }
+
+    /// Set a name for the query.
+    ///
+    /// Each search hit lists the names of the queries it matched in its `matched_queries`.
+    pub fn named(mut self, name: &str) -> Self {
+        self.query.insert("_name".to_owned(), Value::String(name.to_owned()));
+
+        self
+    }
+
+    /// Build the more like this query.
+    pub fn build(self) -> Value {
+        let mut query = self.query;
+        query.insert("like".to_owned(), Value::Array(self.like));
+
+        json!({ "more_like_this": query })
+    }
+}
+
+impl From<MoreLikeThisQueryBuilder> for Value {
+    fn from(builder: MoreLikeThisQueryBuilder) -> Value {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn more_like_this_query() {
+        let query = MoreLikeThisQueryBuilder::new()
+            .fields(&["title", "description"])
+            .like_ids("imdb", "movies", &["1", "2"])
+            .min_term_freq(1)
+            .max_query_terms(12)
+            .min_doc_freq(5)
+            .build();
+
+        let expected = json!({
+            "more_like_this": {
+                "fields": ["title", "description"],
+                "like": [
+                    { "_index": "imdb", "_type": "movies", "_id": "1" },
+                    { "_index": "imdb", "_type": "movies", "_id": "2" }
+                ],
+                "min_term_freq": 1,
+                "max_query_terms": 12,
+                "min_doc_freq": 5
+            }
+        });
+
+        assert_eq!(expected, query);
+    }
+
+    #[test]
+    fn more_like_this_query_named() {
+        let unnamed = MoreLikeThisQueryBuilder::new().like_ids("imdb", "movies", &["1"]);
+        let named = unnamed.clone().named("similar_movies").build();
+
+        assert_eq!("similar_movies", named["more_like_this"]["_name"]);
+        assert!(unnamed.build()["more_like_this"].get("_name").is_none());
+    }
+
+    #[test]
+    fn more_like_this_query_into_search_body() {
+        let query: Value = MoreLikeThisQueryBuilder::new()
+            .like_ids("imdb", "movies", &["1"])
+            .into();
+
+        let body = json!({ "query": query, "size": 5 });
+
+        assert_eq!(json!([{ "_index": "imdb", "_type": "movies", "_id": "1" }]), body["query"]["more_like_this"]["like"]);
+    }
+}
@@ -0,0 +1,87 @@
+use serde_json::{Map, Value};
+
+/// A builder for the body of a [put pipeline request][docs-put-pipeline].
+///
+/// A pipeline can be tested against some sample documents before it's stored by sending it
+/// in the `pipeline` property of an `IngestSimulateRequest` body.
+///
+/// # Examples
+///
+/// Create a pipeline that sets a field on every document:
+///
+/// ```
+/// # #[macro_use] extern crate serde_json;
+/// # extern crate elastic_requests;
+/// # use elastic_requests::*;
+/// # fn main() {
+/// let body = PipelineBodyBuilder::new()
+///     .description("set foo")
+///     .processors(vec![json!({ "set": { "field": "foo", "value": "bar" } })])
+///     .build();
+///
+/// let req = IngestPutPipelineRequest::for_id("my-pipeline", body);
+/// # }
+/// ```
+///
+/// [docs-put-pipeline]: https://www.elastic.co/guide/en/elasticsearch/reference/current/put-pipeline-api.html
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct PipelineBodyBuilder {
+    body: Map<String, Value>,
+}
+
+impl PipelineBodyBuilder {
+    /// Create a new, empty pipeline body.
+    pub fn new() -> Self {
+        PipelineBodyBuilder::default()
+    }
+
+    /// Set the description of the pipeline.
+    pub fn description<IDescription>(mut self, description: IDescription) -> Self
+    where
+        IDescription: Into<String>,
+    {
+        self.body.insert("description".to_owned(), Value::String(description.into()));
+
+        self
+    }
+
+    /// Set the processors the pipeline runs, in order.
+    pub fn processors(mut self, processors: Vec<Value>) -> Self {
+        self.body.insert("processors".to_owned(), Value::Array(processors));
+
+        self
+    }
+
+    /// Build the pipeline body.
+    pub fn build(self) -> Value {
+        Value::Object(self.body)
+    }
+}
+
+impl From<PipelineBodyBuilder> for Value {
+    fn from(builder: PipelineBodyBuilder) -> Value {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pipeline_body() {
+        let body = PipelineBodyBuilder::new()
+            .description("describe pipeline")
+            .processors(vec![json!({ "set": { "field": "foo", "value": "bar" } })])
+            .build();
+
+        let expected = json!({
+            "description": "describe pipeline",
+            "processors": [
+                { "set": { "field": "foo", "value": "bar" } }
+            ]
+        });
+
+        assert_eq!(expected, body);
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt;
+
+use genned::endpoints::{SearchRequest, SimpleSearchRequest};
+use super::push_url_param;
+
+/// The shard copies that should serve a search.
+///
+/// See the [search preference docs][docs-preference] for details.
+///
+/// [docs-preference]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-request-preference.html
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Preference {
+    /// Prefer shard copies allocated on the node handling the request.
+    Local,
+    /// Only use primary shards.
+    Primary,
+    /// Prefer shard copies allocated on the given nodes.
+    ///
+    /// Multiple node ids can be given separated by commas.
+    PreferNode(String),
+    /// Only search the given shards.
+    Shards(Vec<u32>),
+    /// Any other value, like a session id.
+    ///
+    /// Searches with the same custom value are served by the same shard copies.
+    Custom(String),
+}
+
+impl fmt::Display for Preference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Preference::Local => f.write_str("_local"),
+            Preference::Primary => f.write_str("_primary"),
+            Preference::PreferNode(ref nodes) => write!(f, "_prefer_nodes:{}", nodes),
+            Preference::Shards(ref shards) => {
+                let shards: Vec<String> = shards.iter().map(|shard| shard.to_string()).collect();
+
+                write!(f, "_shards:{}", shards.join(","))
+            }
+            Preference::Custom(ref preference) => f.write_str(preference),
+        }
+    }
+}
+
+impl<'a, B> SearchRequest<'a, B> {
+    /// Choose the shard copies that serve the search.
+    pub fn with_preference(mut self, preference: Preference) -> Self {
+        self.url = push_url_param(self.url, "preference", &preference.to_string());
+
+        self
+    }
+}
+
+impl<'a> SimpleSearchRequest<'a> {
+    /// Choose the shard copies that serve the search.
+    pub fn with_preference(mut self, preference: Preference) -> Self {
+        self.url = push_url_param(self.url, "preference", &preference.to_string());
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use genned::http::empty_body;
+
+    #[test]
+    fn preference_to_string() {
+        assert_eq!("_local", Preference::Local.to_string());
+        assert_eq!("_primary", Preference::Primary.to_string());
+        assert_eq!("_prefer_nodes:abc,def", Preference::PreferNode("abc,def".to_owned()).to_string());
+        assert_eq!("_shards:0,1", Preference::Shards(vec![0, 1]).to_string());
+        assert_eq!("user_12", Preference::Custom("user_12".to_owned()).to_string());
+    }
+
+    #[test]
+    fn search_with_preference() {
+        let req = SearchRequest::for_index("idx", empty_body()).with_preference(Preference::Shards(vec![0, 1]));
+
+        assert_eq!("/idx/_search?preference=_shards%3A0%2C1", *req.url);
+    }
+
+    #[test]
+    fn simple_search_with_preference() {
+        let req = SimpleSearchRequest::for_index("idx").with_preference(Preference::Local);
+
+        assert_eq!("/idx/_search?preference=_local", *req.url);
+    }
+}
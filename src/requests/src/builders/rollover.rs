@@ -0,0 +1,124 @@
+use serde_json::{Map, Value};
+
+use genned::endpoints::IndicesRolloverRequest;
+use super::push_url_param;
+
+impl<'a, B> IndicesRolloverRequest<'a, B> {
+    /// Check the rollover conditions without rolling the alias over.
+    ///
+    /// The response reports which conditions were met.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.url = push_url_param(self.url, "dry_run", &dry_run.to_string());
+
+        self
+    }
+}
+
+/// A builder for the body of a [rollover index request][docs-rollover].
+///
+/// The alias is rolled over to a new index when any of the conditions are met.
+///
+/// # Examples
+///
+/// Roll over an alias once its index is a week old or has a million documents:
+///
+/// ```
+/// # extern crate elastic_requests;
+/// # use elastic_requests::*;
+/// # fn main() {
+/// let body = RolloverBodyBuilder::new()
+///     .max_age("7d")
+///     .max_docs(1_000_000)
+///     .build();
+///
+/// let req = IndicesRolloverRequest::for_alias("logs_write", body).dry_run(true);
+/// # }
+/// ```
+///
+/// [docs-rollover]: https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-rollover-index.html
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct RolloverBodyBuilder {
+    conditions: Map<String, Value>,
+}
+
+impl RolloverBodyBuilder {
+    /// Create a new rollover body with no conditions.
+    pub fn new() -> Self {
+        RolloverBodyBuilder::default()
+    }
+
+    /// Roll over when the index is older than the given time unit, like `7d`.
+    pub fn max_age(mut self, max_age: &str) -> Self {
+        self.conditions.insert("max_age".to_owned(), Value::String(max_age.to_owned()));
+
+        self
+    }
+
+    /// Roll over when the index contains at least the given number of documents.
+    pub fn max_docs(mut self, max_docs: u64) -> Self {
+        self.conditions.insert("max_docs".to_owned(), Value::from(max_docs));
+
+        self
+    }
+
+    /// Roll over when the index is larger than the given byte size, like `5gb`.
+    pub fn max_size(mut self, max_size: &str) -> Self {
+        self.conditions.insert("max_size".to_owned(), Value::String(max_size.to_owned()));
+
+        self
+    }
+
+    /// Build the rollover body.
+    pub fn build(self) -> Value {
+        let mut body = Map::new();
+        body.insert("conditions".to_owned(), Value::Object(self.conditions));
+
+        Value::Object(body)
+    }
+}
+
+impl From<RolloverBodyBuilder> for Value {
+    fn from(builder: RolloverBodyBuilder) -> Value {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use genned::endpoints::IndicesRolloverRequest;
+    use genned::http::empty_body;
+
+    #[test]
+    fn rollover_body() {
+        let body = RolloverBodyBuilder::new()
+            .max_age("7d")
+            .max_docs(1000)
+            .max_size("5gb")
+            .build();
+
+        let expected = json!({
+            "conditions": {
+                "max_age": "7d",
+                "max_docs": 1000,
+                "max_size": "5gb"
+            }
+        });
+
+        assert_eq!(expected, body);
+    }
+
+    #[test]
+    fn rollover_for_alias_dry_run() {
+        let req = IndicesRolloverRequest::for_alias("logs_write", empty_body()).dry_run(true);
+
+        assert_eq!("/logs_write/_rollover?dry_run=true", *req.url);
+    }
+
+    #[test]
+    fn rollover_for_alias_new_index() {
+        let req = IndicesRolloverRequest::for_alias_new_index("logs_write", "logs-000002", empty_body());
+
+        assert_eq!("/logs_write/_rollover/logs-000002", *req.url);
+    }
+}
@@ -0,0 +1,76 @@
+use genned::endpoints::{DeleteRequest, GetRequest, IndexRequest, SearchRequest};
+use super::push_url_param;
+
+impl<'a, B> SearchRequest<'a, B> {
+    /// Only search the shards that the given routing value maps to.
+    ///
+    /// Multiple routing values can be given separated by commas.
+    pub fn with_routing<R: Into<String>>(mut self, routing: R) -> Self {
+        self.url = push_url_param(self.url, "routing", &routing.into());
+
+        self
+    }
+}
+
+impl<'a, B> IndexRequest<'a, B> {
+    /// Route the document to a shard using the given value instead of its id.
+    ///
+    /// The same routing value needs to be used to get or delete the document later.
+    pub fn with_routing<R: Into<String>>(mut self, routing: R) -> Self {
+        self.url = push_url_param(self.url, "routing", &routing.into());
+
+        self
+    }
+}
+
+impl<'a> GetRequest<'a> {
+    /// Get a document that was indexed with the given routing value.
+    pub fn with_routing<R: Into<String>>(mut self, routing: R) -> Self {
+        self.url = push_url_param(self.url, "routing", &routing.into());
+
+        self
+    }
+}
+
+impl<'a> DeleteRequest<'a> {
+    /// Delete a document that was indexed with the given routing value.
+    pub fn with_routing<R: Into<String>>(mut self, routing: R) -> Self {
+        self.url = push_url_param(self.url, "routing", &routing.into());
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use genned::endpoints::{DeleteRequest, GetRequest, IndexRequest, SearchRequest};
+    use genned::http::empty_body;
+
+    #[test]
+    fn search_with_routing() {
+        let req = SearchRequest::for_index_ty("idx", "ty", empty_body()).with_routing("user1,user2");
+
+        assert_eq!("/idx/ty/_search?routing=user1%2Cuser2", *req.url);
+    }
+
+    #[test]
+    fn index_with_routing() {
+        let req = IndexRequest::for_index_ty_id("idx", "ty", "1", empty_body()).with_routing("user1");
+
+        assert_eq!("/idx/ty/1?routing=user1", *req.url);
+    }
+
+    #[test]
+    fn get_with_routing() {
+        let req = GetRequest::for_index_ty_id("idx", "ty", "1").with_routing(String::from("user1"));
+
+        assert_eq!("/idx/ty/1?routing=user1", *req.url);
+    }
+
+    #[test]
+    fn delete_with_routing() {
+        let req = DeleteRequest::for_index_ty_id("idx", "ty", "1").with_routing("user1");
+
+        assert_eq!("/idx/ty/1?routing=user1", *req.url);
+    }
+}
@@ -0,0 +1,172 @@
+use serde_json::Value;
+
+use genned::endpoints::{DeleteScriptRequest, GetScriptRequest, PutScriptRequest};
+use genned::http::Url;
+use genned::params::Id;
+
+fn script_url<'a>(id: Id<'a>, context: Option<&str>) -> Url<'a> {
+    let mut url = String::from("/_scripts/");
+    url.push_str(id.as_ref());
+
+    if let Some(context) = context {
+        url.push('/');
+        url.push_str(context);
+    }
+
+    Url::from(url)
+}
+
+impl<'a> PutScriptRequest<'a, Value> {
+    /// Request to: `/_scripts/{id}` to store a script.
+    ///
+    /// If the script has a context then the request is sent to `/_scripts/{id}/{context}` instead.
+    pub fn for_id<IId>(id: IId, script: ScriptBody) -> Self
+    where
+        IId: Into<Id<'a>>,
+    {
+        PutScriptRequest {
+            url: script_url(id.into(), script.context.as_ref().map(|context| context.as_ref())),
+            body: script.body,
+        }
+    }
+}
+
+impl<'a> GetScriptRequest<'a> {
+    /// Request to: `/_scripts/{id}` to get a stored script.
+    pub fn for_id<IId>(id: IId) -> Self
+    where
+        IId: Into<Id<'a>>,
+    {
+        GetScriptRequest {
+            url: script_url(id.into(), None),
+        }
+    }
+}
+
+impl<'a> DeleteScriptRequest<'a> {
+    /// Request to: `/_scripts/{id}` to delete a stored script.
+    pub fn for_id<IId>(id: IId) -> Self
+    where
+        IId: Into<Id<'a>>,
+    {
+        DeleteScriptRequest {
+            url: script_url(id.into(), None),
+        }
+    }
+}
+
+/// The body of a [stored script request][docs-stored-scripts].
+///
+/// Use a `ScriptBodyBuilder` to build a script body.
+///
+/// [docs-stored-scripts]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-scripting-using.html#modules-scripting-stored-scripts
+#[derive(Debug, PartialEq, Clone)]
+pub struct ScriptBody {
+    context: Option<String>,
+    body: Value,
+}
+
+/// A builder for the body of a [stored script request][docs-stored-scripts].
+///
+/// # Examples
+///
+/// Store a painless script that can be used in score queries:
+///
+/// ```
+/// # extern crate elastic_requests;
+/// # use elastic_requests::*;
+/// # fn main() {
+/// let script = ScriptBodyBuilder::new("painless", "Math.log(_score * 2) + params.my_modifier")
+///     .context("score")
+///     .build();
+///
+/// let req = PutScriptRequest::for_id("calculate-score", script);
+/// # }
+/// ```
+///
+/// The script can be fetched with a `GetScriptRequest` and removed with a `DeleteScriptRequest`.
+///
+/// [docs-stored-scripts]: https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-scripting-using.html#modules-scripting-stored-scripts
+#[derive(Debug, PartialEq, Clone)]
+pub struct ScriptBodyBuilder {
+    lang: String,
+    source: String,
+    context: Option<String>,
+}
+
+impl ScriptBodyBuilder {
+    /// Create a script body with the given language, like `painless` or `mustache`, and source.
+    pub fn new(lang: &str, source: &str) -> Self {
+        ScriptBodyBuilder {
+            lang: lang.to_owned(),
+            source: source.to_owned(),
+            context: None,
+        }
+    }
+
+    /// Compile the script for the given context, like `score` or `search`.
+    pub fn context(mut self, context: &str) -> Self {
+        self.context = Some(context.to_owned());
+
+        self
+    }
+
+    /// Build the script body.
+    pub fn build(self) -> ScriptBody {
+        ScriptBody {
+            context: self.context,
+            body: json!({ "script": { "lang": self.lang, "source": self.source } }),
+        }
+    }
+}
+
+impl From<ScriptBodyBuilder> for ScriptBody {
+    fn from(builder: ScriptBodyBuilder) -> ScriptBody {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use genned::http::{DefaultBody, HttpMethod, HttpRequest};
+
+    #[test]
+    fn put_script_for_id() {
+        let req = PutScriptRequest::for_id("my-script", ScriptBodyBuilder::new("painless", "doc['count'].value * 2").build());
+
+        let expected = json!({
+            "script": {
+                "lang": "painless",
+                "source": "doc['count'].value * 2"
+            }
+        });
+
+        assert_eq!("/_scripts/my-script", *req.url);
+        assert_eq!(expected, req.body);
+    }
+
+    #[test]
+    fn put_script_for_id_with_context() {
+        let req = PutScriptRequest::for_id("my-script", ScriptBodyBuilder::new("painless", "_score * 2").context("score").build());
+
+        assert_eq!("/_scripts/my-script/score", *req.url);
+        assert!(req.body["script"].get("context").is_none());
+    }
+
+    #[test]
+    fn get_script_for_id() {
+        let req: HttpRequest<DefaultBody> = GetScriptRequest::for_id("my-script").into();
+
+        assert_eq!(HttpMethod::Get, req.method);
+        assert_eq!("/_scripts/my-script", *req.url);
+    }
+
+    #[test]
+    fn delete_script_for_id() {
+        let req: HttpRequest<DefaultBody> = DeleteScriptRequest::for_id("my-script").into();
+
+        assert_eq!(HttpMethod::Delete, req.method);
+        assert_eq!("/_scripts/my-script", *req.url);
+    }
+}
@@ -0,0 +1,69 @@
+use genned::endpoints::{SearchRequest, SimpleSearchRequest};
+use super::join_indices;
+
+impl<'a, B> SearchRequest<'a, B> {
+    /// Request to: `/{index}/_search` for a list of indices.
+    ///
+    /// The index names are joined into a single comma-separated path segment.
+    pub fn for_indices(indices: &[&str], body: B) -> Self {
+        SearchRequest::for_index(join_indices(indices), body)
+    }
+
+    /// Request to: `/{index}/{type}/_search` for a list of indices.
+    ///
+    /// The index names are joined into a single comma-separated path segment.
+    pub fn for_indices_ty(indices: &[&str], ty: &str, body: B) -> Self {
+        SearchRequest::for_index_ty(join_indices(indices), ty.to_owned(), body)
+    }
+}
+
+impl<'a> SimpleSearchRequest<'a> {
+    /// Request to: `/{index}/_search` for a list of indices.
+    ///
+    /// The index names are joined into a single comma-separated path segment.
+    pub fn for_indices(indices: &[&str]) -> Self {
+        SimpleSearchRequest::for_index(join_indices(indices))
+    }
+
+    /// Request to: `/{index}/{type}/_search` for a list of indices.
+    ///
+    /// The index names are joined into a single comma-separated path segment.
+    pub fn for_indices_ty(indices: &[&str], ty: &str) -> Self {
+        SimpleSearchRequest::for_index_ty(join_indices(indices), ty.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use genned::endpoints::{SearchRequest, SimpleSearchRequest};
+    use genned::http::{empty_body, DefaultBody, HttpMethod, HttpRequest};
+
+    #[test]
+    fn search_for_indices() {
+        let req = SearchRequest::for_indices(&["a", "b", "c"], empty_body());
+
+        assert_eq!("/a,b,c/_search", *req.url);
+    }
+
+    #[test]
+    fn search_for_indices_ty() {
+        let req = SearchRequest::for_indices_ty(&["a", "b", "c"], "ty", empty_body());
+
+        assert_eq!("/a,b,c/ty/_search", *req.url);
+    }
+
+    #[test]
+    fn simple_search_for_indices() {
+        let req: HttpRequest<DefaultBody> = SimpleSearchRequest::for_indices(&["a", "b", "c"]).into();
+
+        assert_eq!(HttpMethod::Get, req.method);
+        assert_eq!("/a,b,c/_search", *req.url);
+    }
+
+    #[test]
+    fn simple_search_for_indices_ty() {
+        let req = SimpleSearchRequest::for_indices_ty(&["a", "b", "c"], "ty");
+
+        assert_eq!("/a,b,c/ty/_search", *req.url);
+    }
+}
@@ -0,0 +1,108 @@
+use serde_json::{Map, Value};
+
+use genned::endpoints::SearchRequest;
+
+/// The sort values of the last hit in a page of search results.
+///
+/// Passing a cursor to the next search request with `with_search_after` returns the hits that come after it.
+/// See the [search after docs][docs-search-after] for details.
+///
+/// # Examples
+///
+/// Get the page of tweets after the last one returned:
+///
+/// ```
+/// # #[macro_use] extern crate serde_json;
+/// # extern crate elastic_requests;
+/// # use elastic_requests::*;
+/// # fn main() {
+/// let cursor = SearchAfterCursor::new(vec![json!(1463538857), json!("tweet#654323")]);
+///
+/// let req = SearchRequest::for_index("twitter", json!({
+///         "size": 10,
+///         "sort": [{ "date": "asc" }, { "tie_breaker_id": "asc" }]
+///     }))
+///     .with_search_after(cursor);
+/// # }
+/// ```
+///
+/// [docs-search-after]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-request-search-after.html
+#[derive(Debug, PartialEq, Clone)]
+pub struct SearchAfterCursor {
+    values: Vec<Value>,
+}
+
+impl SearchAfterCursor {
+    /// Create a cursor from the sort values of a hit.
+    pub fn new(values: Vec<Value>) -> Self {
+        SearchAfterCursor { values: values }
+    }
+
+    /// The sort values of the hit.
+    pub fn values(&self) -> &[Value] {
+        &self.values
+    }
+}
+
+impl From<Vec<Value>> for SearchAfterCursor {
+    fn from(values: Vec<Value>) -> Self {
+        SearchAfterCursor::new(values)
+    }
+}
+
+impl<'b> From<&'b [Value]> for SearchAfterCursor {
+    fn from(values: &'b [Value]) -> Self {
+        SearchAfterCursor::new(values.to_vec())
+    }
+}
+
+impl From<SearchAfterCursor> for Value {
+    fn from(cursor: SearchAfterCursor) -> Value {
+        Value::Array(cursor.values)
+    }
+}
+
+impl<'a> SearchRequest<'a, Value> {
+    /// Set the `search_after` property of the search body.
+    ///
+    /// The search body also needs a `sort` with the same fields the cursor was taken from.
+    /// If the body isn't an object then it's replaced with one.
+    pub fn with_search_after(mut self, cursor: SearchAfterCursor) -> Self {
+        if !self.body.is_object() {
+            self.body = Value::Object(Map::new());
+        }
+
+        if let Value::Object(ref mut body) = self.body {
+            body.insert("search_after".to_owned(), cursor.into());
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_with_search_after() {
+        let cursor = SearchAfterCursor::from(vec![json!(1463538857), json!("tweet#654323")]);
+
+        let req = SearchRequest::for_index("twitter", json!({ "sort": [{ "date": "asc" }] })).with_search_after(cursor);
+
+        let expected = json!({
+            "sort": [{ "date": "asc" }],
+            "search_after": [1463538857, "tweet#654323"]
+        });
+
+        assert_eq!(expected, req.body);
+    }
+
+    #[test]
+    fn search_with_search_after_replaces_previous_cursor() {
+        let req = SearchRequest::for_index("twitter", json!({ "search_after": [1] }))
+            .with_search_after(SearchAfterCursor::from(&[json!(2)][..]));
+
+        assert_eq!(json!({ "search_after": [2] }), req.body);
+    }
+}
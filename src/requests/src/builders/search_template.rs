@@ -0,0 +1,105 @@
+use serde_json::{Map, Value};
+
+/// A builder for the body of a [search template request][docs-search-template].
+///
+/// The same body can be sent with a `SearchTemplateRequest` to run the search,
+/// or a `RenderSearchTemplateRequest` to see the query the template produces.
+///
+/// # Examples
+///
+/// Run a stored template with some parameters:
+///
+/// ```
+/// # #[macro_use] extern crate serde_json;
+/// # extern crate elastic_requests;
+/// # use elastic_requests::*;
+/// # fn main() {
+/// let body = SearchTemplateBodyBuilder::new()
+///     .id("my-template")
+///     .params(json!({ "query_string": "search for these words" }))
+///     .build();
+///
+/// let req = SearchTemplateRequest::for_index("myindex", body);
+/// # }
+/// ```
+///
+/// [docs-search-template]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-template.html
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct SearchTemplateBodyBuilder {
+    body: Map<String, Value>,
+}
+
+impl SearchTemplateBodyBuilder {
+    /// Create a new, empty search template body.
+    pub fn new() -> Self {
+        SearchTemplateBodyBuilder::default()
+    }
+
+    /// Use the stored template with the given id.
+    pub fn id<IId>(mut self, id: IId) -> Self
+    where
+        IId: Into<String>,
+    {
+        self.body.remove("inline");
+        self.body.insert("id".to_owned(), Value::String(id.into()));
+
+        self
+    }
+
+    /// Use the given inline template instead of a stored one.
+    pub fn inline<ITemplate>(mut self, template: ITemplate) -> Self
+    where
+        ITemplate: Into<Value>,
+    {
+        self.body.remove("id");
+        self.body.insert("inline".to_owned(), template.into());
+
+        self
+    }
+
+    /// Set the parameters to render the template with.
+    pub fn params(mut self, params: Value) -> Self {
+        self.body.insert("params".to_owned(), params);
+
+        self
+    }
+
+    /// Build the search template body.
+    pub fn build(self) -> Value {
+        Value::Object(self.body)
+    }
+}
+
+impl From<SearchTemplateBodyBuilder> for Value {
+    fn from(builder: SearchTemplateBodyBuilder) -> Value {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stored_template_body() {
+        let body = SearchTemplateBodyBuilder::new()
+            .id("my-template")
+            .params(json!({ "value": 1 }))
+            .build();
+
+        assert_eq!(json!({ "id": "my-template", "params": { "value": 1 } }), body);
+    }
+
+    #[test]
+    fn inline_template_replaces_id() {
+        let body = SearchTemplateBodyBuilder::new()
+            .id("my-template")
+            .inline(json!({ "query": { "match": { "title": "{{query_string}}" } } }))
+            .build();
+
+        assert_eq!(
+            json!({ "inline": { "query": { "match": { "title": "{{query_string}}" } } } }),
+            body
+        );
+    }
+}
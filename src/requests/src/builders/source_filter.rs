@@ -0,0 +1,205 @@
+use serde_json::{Map, Value};
+
+use genned::endpoints::{GetRequest, SearchRequest};
+use super::{push_source_filter_params, push_url_param};
+
+/// The fields of the `_source` document to return.
+///
+/// See the [source filtering docs][docs-source-filtering] for details.
+///
+/// # Examples
+///
+/// Search for documents but only return their `user` fields, leaving out passwords:
+///
+/// ```
+/// # #[macro_use] extern crate serde_json;
+/// # extern crate elastic_requests;
+/// # use elastic_requests::*;
+/// # fn main() {
+/// let filter = SourceFilter::new().include(&["user.*"]).exclude(&["user.password"]);
+///
+/// let req = SearchRequest::for_index("myindex", json!({ "query": { "match_all": {} } }))
+///     .with_source_filter(filter);
+/// # }
+/// ```
+///
+/// [docs-source-filtering]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-request-source-filtering.html
+#[derive(Debug, PartialEq, Clone)]
+pub struct SourceFilter {
+    enabled: bool,
+    includes: Vec<String>,
+    excludes: Vec<String>,
+}
+
+impl Default for SourceFilter {
+    fn default() -> Self {
+        SourceFilter {
+            enabled: true,
+            includes: Vec::new(),
+            excludes: Vec::new(),
+        }
+    }
+}
+
+impl SourceFilter {
+    /// Create a filter that returns the whole source.
+    pub fn new() -> Self {
+        SourceFilter::default()
+    }
+
+    /// Create a filter that doesn't return any source.
+    pub fn none() -> Self {
+        SourceFilter {
+            enabled: false,
+            ..SourceFilter::default()
+        }
+    }
+
+    /// Only return the given fields.
+    ///
+    /// Fields can use wildcards like `user.*`.
+    /// Calling this more than once adds to the fields to include.
+    pub fn include(mut self, fields: &[&str]) -> Self {
+        self.includes.extend(fields.iter().map(|field| (*field).to_owned()));
+
+        self
+    }
+
+    /// Don't return the given fields, even if they're included.
+    ///
+    /// Fields can use wildcards like `user.*`.
+    /// Calling this more than once adds to the fields to exclude.
+    pub fn exclude(mut self, fields: &[&str]) -> Self {
+        self.excludes.extend(fields.iter().map(|field| (*field).to_owned()));
+
+        self
+    }
+
+    /// Build the value of the `_source` property in a request body.
+    pub fn build(self) -> Value {
+        if !self.enabled {
+            return Value::Bool(false);
+        }
+
+        if self.includes.is_empty() && self.excludes.is_empty() {
+            return Value::Bool(true);
+        }
+
+        let mut filter = Map::new();
+
+        if !self.includes.is_empty() {
+            filter.insert("includes".to_owned(), Value::from(self.includes));
+        }
+
+        if !self.excludes.is_empty() {
+            filter.insert("excludes".to_owned(), Value::from(self.excludes));
+        }
+
+        Value::Object(filter)
+    }
+}
+
+impl From<SourceFilter> for Value {
+    fn from(filter: SourceFilter) -> Value {
+        filter.build()
+    }
+}
+
+impl<'a> SearchRequest<'a, Value> {
+    /// Set the `_source` property of the search body.
+    ///
+    /// If the body isn't an object then it's replaced with one.
+    pub fn with_source_filter(mut self, filter: SourceFilter) -> Self {
+        if !self.body.is_object() {
+            self.body = Value::Object(Map::new());
+        }
+
+        if let Value::Object(ref mut body) = self.body {
+            body.insert("_source".to_owned(), filter.build());
+        }
+
+        self
+    }
+}
+
+impl<'a> GetRequest<'a> {
+    /// Only return the fields of the document source allowed by the filter.
+    pub fn with_source_filter(mut self, filter: SourceFilter) -> Self {
+        if !filter.enabled {
+            self.url = push_url_param(self.url, "_source", "false");
+
+            return self;
+        }
+
+        let includes: Vec<&str> = filter.includes.iter().map(|field| field.as_ref()).collect();
+        let excludes: Vec<&str> = filter.excludes.iter().map(|field| field.as_ref()).collect();
+
+        self.url = push_source_filter_params(self.url, &includes, &excludes);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_filter_body() {
+        let filter = SourceFilter::new().include(&["title", "user.*"]).exclude(&["user.password"]).build();
+
+        assert_eq!(json!({ "includes": ["title", "user.*"], "excludes": ["user.password"] }), filter);
+    }
+
+    #[test]
+    fn source_filter_body_all() {
+        assert_eq!(json!(true), SourceFilter::new().build());
+    }
+
+    #[test]
+    fn source_filter_body_none() {
+        assert_eq!(json!(false), SourceFilter::none().build());
+    }
+
+    #[test]
+    fn search_with_source_filter() {
+        let req = SearchRequest::for_index("idx", json!({ "query": { "match_all": {} } }))
+            .with_source_filter(SourceFilter::new().include(&["title"]));
+
+        let expected = json!({
+            "query": { "match_all": {} },
+            "_source": { "includes": ["title"] }
+        });
+
+        assert_eq!("/idx/_search", *req.url);
+        assert_eq!(expected, req.body);
+    }
+
+    #[test]
+    fn search_with_source_filter_null_body() {
+        let req = SearchRequest::for_index("idx", Value::Null).with_source_filter(SourceFilter::none());
+
+        assert_eq!(json!({ "_source": false }), req.body);
+    }
+
+    #[test]
+    fn get_with_source_filter() {
+        let req = GetRequest::for_index_ty_id("idx", "ty", "1").with_source_filter(SourceFilter::new().include(&["title", "user.*"]).exclude(&["user.password"]));
+
+        assert_eq!("/idx/ty/1?_source_include=title%2Cuser.*&_source_exclude=user.password", *req.url);
+    }
+
+    #[test]
+    fn get_with_source_filter_none() {
+        let req = GetRequest::for_index_ty_id("idx", "ty", "1").with_source_filter(SourceFilter::none());
+
+        assert_eq!("/idx/ty/1?_source=false", *req.url);
+    }
+
+    #[test]
+    fn get_with_source_filter_all() {
+        let req = GetRequest::for_index_ty_id("idx", "ty", "1").with_source_filter(SourceFilter::new());
+
+        assert_eq!("/idx/ty/1", *req.url);
+    }
+}
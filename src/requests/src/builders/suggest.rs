@@ -0,0 +1,134 @@
+use serde_json::{Map, Value};
+
+/// A builder for the body of a [suggest request][docs-suggest].
+///
+/// Each suggestion is given a name that's used to find its results in the response.
+/// The built body can be used with a `SuggestRequest` or as the `suggest` node of a search request.
+///
+/// # Examples
+///
+/// ```
+/// # use elastic_requests::*;
+/// let body = SuggestBodyBuilder::new()
+///     .term("my-term-suggest", "message", "tring out Elasticsearch")
+///     .completion("my-completion-suggest", "suggest", "nir")
+///     .build();
+///
+/// let req = SuggestRequest::for_index("myindex", body);
+/// ```
+///
+/// [docs-suggest]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-suggesters.html
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct SuggestBodyBuilder {
+    suggestions: Map<String, Value>,
+}
+
+impl SuggestBodyBuilder {
+    /// Create a new, empty suggest body.
+    pub fn new() -> Self {
+        SuggestBodyBuilder::default()
+    }
+
+    /// Add a [term suggestion][docs-term] for the given `text` on `field`.
+    ///
+    /// [docs-term]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-suggesters-term.html
+    pub fn term<IName, IField, IText>(self, name: IName, field: IField, text: IText) -> Self
+    where
+        IName: Into<String>,
+        IField: Into<String>,
+        IText: Into<String>,
+    {
+        self.suggestion(name, json!({ "text": text.into(), "term": { "field": field.into() } }))
+    }
+
+    /// Add a [phrase suggestion][docs-phrase] for the given `text` on `field`.
+    ///
+    /// [docs-phrase]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-suggesters-phrase.html
+    pub fn phrase<IName, IField, IText>(self, name: IName, field: IField, text: IText) -> Self
+    where
+        IName: Into<String>,
+        IField: Into<String>,
+        IText: Into<String>,
+    {
+        self.suggestion(name, json!({ "text": text.into(), "phrase": { "field": field.into() } }))
+    }
+
+    /// Add a [completion suggestion][docs-completion] for the given `prefix` on `field`.
+    ///
+    /// [docs-completion]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-suggesters-completion.html
+    pub fn completion<IName, IField, IPrefix>(self, name: IName, field: IField, prefix: IPrefix) -> Self
+    where
+        IName: Into<String>,
+        IField: Into<String>,
+        IPrefix: Into<String>,
+    {
+        self.suggestion(name, json!({ "prefix": prefix.into(), "completion": { "field": field.into() } }))
+    }
+
+    fn suggestion<IName>(mut self, name: IName, suggestion: Value) -> Self
+    where
+        IName: Into<String>,
+    {
+        self.suggestions.insert(name.into(), suggestion);
+
+        self
+    }
+
+    /// Build the suggest body.
+    pub fn build(self) -> Value {
+        Value::Object(self.suggestions)
+    }
+}
+
+impl From<SuggestBodyBuilder> for Value {
+    fn from(builder: SuggestBodyBuilder) -> Value {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_body() {
+        let body = SuggestBodyBuilder::new().build();
+
+        assert_eq!(json!({}), body);
+    }
+
+    #[test]
+    fn term_and_completion_body() {
+        let body = SuggestBodyBuilder::new()
+            .term("my-term", "message", "tring")
+            .completion("my-completion", "suggest", "nir")
+            .build();
+
+        let expected = json!({
+            "my-term": {
+                "text": "tring",
+                "term": { "field": "message" }
+            },
+            "my-completion": {
+                "prefix": "nir",
+                "completion": { "field": "suggest" }
+            }
+        });
+
+        assert_eq!(expected, body);
+    }
+
+    #[test]
+    fn phrase_body() {
+        let body = SuggestBodyBuilder::new().phrase("my-phrase", "title", "noble prize").build();
+
+        let expected = json!({
+            "my-phrase": {
+                "text": "noble prize",
+                "phrase": { "field": "title" }
+            }
+        });
+
+        assert_eq!(expected, body);
+    }
+}
@@ -0,0 +1,56 @@
+use genned::endpoints::TermvectorsRequest;
+use super::{push_url_list_param, push_url_param};
+
+impl<'a, B> TermvectorsRequest<'a, B> {
+    /// Whether to return the total term frequency and document frequency of each term.
+    ///
+    /// Term statistics are expensive to compute, so they aren't returned by default.
+    pub fn term_statistics(mut self, term_statistics: bool) -> Self {
+        self.url = push_url_param(self.url, "term_statistics", &term_statistics.to_string());
+
+        self
+    }
+
+    /// Whether to return the document count, sum of document frequencies and sum of total term frequencies for each field.
+    pub fn field_statistics(mut self, field_statistics: bool) -> Self {
+        self.url = push_url_param(self.url, "field_statistics", &field_statistics.to_string());
+
+        self
+    }
+
+    /// Restrict the term vectors returned to the given field names.
+    ///
+    /// The list is sent as the `fields` url parameter and is ignored if it's empty.
+    pub fn fields(mut self, fields: &[&str]) -> Self {
+        self.url = push_url_list_param(self.url, "fields", fields);
+
+        self
+    }
+
+    /// Whether to return the start and end offsets of each token.
+    pub fn offsets(mut self, offsets: bool) -> Self {
+        self.url = push_url_param(self.url, "offsets", &offsets.to_string());
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use genned::endpoints::TermvectorsRequest;
+    use genned::http::empty_body;
+
+    #[test]
+    fn termvectors_for_index_ty_id() {
+        let req = TermvectorsRequest::for_index_ty_id("idx", "ty", "1", empty_body())
+            .term_statistics(true)
+            .field_statistics(false)
+            .fields(&["title", "text"])
+            .offsets(true);
+
+        assert_eq!(
+            "/idx/ty/1/_termvectors?term_statistics=true&field_statistics=false&fields=title%2Ctext&offsets=true",
+            *req.url
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use serde_json::{Map, Value};
+
+use genned::endpoints::IndicesValidateQueryRequest;
+use super::push_url_param;
+
+impl<'a, B> IndicesValidateQueryRequest<'a, B> {
+    /// Return an explanation of how the query was parsed, or why it's invalid.
+    pub fn explain(mut self, explain: bool) -> Self {
+        self.url = push_url_param(self.url, "explain", &explain.to_string());
+
+        self
+    }
+}
+
+/// A builder for the body of a [validate query request][docs-validate].
+///
+/// The query is checked without being executed.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate serde_json;
+/// # extern crate elastic_requests;
+/// # use elastic_requests::*;
+/// # fn main() {
+/// let body = ValidateQueryBodyBuilder::new()
+///     .query(json!({ "match": { "title": "elasticsearch" } }))
+///     .build();
+///
+/// let req = IndicesValidateQueryRequest::for_index_ty("myindex", "mytype", body).explain(true);
+/// # }
+/// ```
+///
+/// [docs-validate]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-validate.html
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ValidateQueryBodyBuilder {
+    body: Map<String, Value>,
+}
+
+impl ValidateQueryBodyBuilder {
+    /// Create a new, empty validate query body.
+    pub fn new() -> Self {
+        ValidateQueryBodyBuilder::default()
+    }
+
+    /// Set the query to validate.
+    pub fn query(mut self, query: Value) -> Self {
+        self.body.insert("query".to_owned(), query);
+
+        self
+    }
+
+    /// Build the validate query body.
+    pub fn build(self) -> Value {
+        Value::Object(self.body)
+    }
+}
+
+impl From<ValidateQueryBodyBuilder> for Value {
+    fn from(builder: ValidateQueryBodyBuilder) -> Value {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use genned::endpoints::IndicesValidateQueryRequest;
+
+    #[test]
+    fn validate_query_body() {
+        let body = ValidateQueryBodyBuilder::new()
+            .query(json!({ "match": { "title": "elasticsearch" } }))
+            .build();
+
+        assert_eq!(json!({ "query": { "match": { "title": "elasticsearch" } } }), body);
+    }
+
+    #[test]
+    fn validate_query_for_index_ty_explain() {
+        let req = IndicesValidateQueryRequest::for_index_ty("idx", "ty", ValidateQueryBodyBuilder::new().build()).explain(true);
+
+        assert_eq!("/idx/ty/_validate/query?explain=true", *req.url);
+    }
+}
@@ -0,0 +1,88 @@
+use std::fmt;
+
+use genned::endpoints::IndexRequest;
+use super::push_url_param;
+
+/// The way a document version is checked when it's written.
+///
+/// See the [versioning docs][docs-versioning] for details.
+///
+/// [docs-versioning]: https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-index_.html#index-versioning
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum VersionType {
+    /// Only write the document if the given version matches the stored one.
+    Internal,
+    /// Only write the document if the given version is greater than the stored one.
+    External,
+    /// Only write the document if the given version is greater than or equal to the stored one.
+    ExternalGte,
+}
+
+impl fmt::Display for VersionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            VersionType::Internal => f.write_str("internal"),
+            VersionType::External => f.write_str("external"),
+            VersionType::ExternalGte => f.write_str("external_gte"),
+        }
+    }
+}
+
+impl<'a, B> IndexRequest<'a, B> {
+    /// Only index the document if its current version passes the check for the version type.
+    ///
+    /// A request that fails the check returns a version conflict error.
+    pub fn with_version(mut self, version: u64, version_type: VersionType) -> Self {
+        self.url = push_url_param(self.url, "version", &version.to_string());
+        self.url = push_url_param(self.url, "version_type", &version_type.to_string());
+
+        self
+    }
+
+    /// Only index the document if its last write had the given sequence number.
+    ///
+    /// This needs to be used together with `if_primary_term`.
+    pub fn if_seq_no(mut self, seq_no: u64) -> Self {
+        self.url = push_url_param(self.url, "if_seq_no", &seq_no.to_string());
+
+        self
+    }
+
+    /// Only index the document if its last write had the given primary term.
+    ///
+    /// This needs to be used together with `if_seq_no`.
+    pub fn if_primary_term(mut self, primary_term: u64) -> Self {
+        self.url = push_url_param(self.url, "if_primary_term", &primary_term.to_string());
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use genned::http::empty_body;
+
+    #[test]
+    fn index_with_version() {
+        let req = IndexRequest::for_index_ty_id("idx", "ty", "1", empty_body()).with_version(5, VersionType::External);
+
+        assert_eq!("/idx/ty/1?version=5&version_type=external", *req.url);
+    }
+
+    #[test]
+    fn index_with_version_external_gte() {
+        let req = IndexRequest::for_index_ty_id("idx", "ty", "1", empty_body()).with_version(5, VersionType::ExternalGte);
+
+        assert_eq!("/idx/ty/1?version=5&version_type=external_gte", *req.url);
+    }
+
+    #[test]
+    fn index_if_seq_no_primary_term() {
+        let req = IndexRequest::for_index_ty_id("idx", "ty", "1", empty_body())
+            .if_seq_no(10)
+            .if_primary_term(2);
+
+        assert_eq!("/idx/ty/1?if_seq_no=10&if_primary_term=2", *req.url);
+    }
+}
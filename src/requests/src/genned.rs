@@ -3090,7 +3090,7 @@ pub mod endpoints {
         pub url: Url<'a>,
     }
     impl<'a> DeleteRequest<'a> {
-        #[doc = "Request to: `/{index}/{type}/{id}`"]
+        #[doc = "Request to: `/{index}/{type}/{id}`\n\n# Examples\n\nUse a numeric id:\n\n```\n# use elastic_requests::*;\nlet req = DeleteRequest::for_index_ty_id(\"myindex\", \"mytype\", DocumentId::from(42));\n\nassert_eq!(\"/myindex/mytype/42\", *req.url);\n```"]
         pub fn for_index_ty_id<IIndex, IType, IId>(index: IIndex, ty: IType, id: IId) -> Self
         where
             IIndex: Into<Index<'a>>,
@@ -3439,11 +3439,12 @@ pub mod endpoints {
     impl<'a> CatShardsUrlParams<'a> {
         pub fn url(self) -> Url<'a> {
             match self {
-                CatShardsUrlParams::None => Url::from("/_cat/shards"),
+                CatShardsUrlParams::None => Url::from("/_cat/shards?format=json"),
                 CatShardsUrlParams::Index(ref index) => {
-                    let mut url = String::with_capacity(13usize + index.len());
+                    let mut url = String::with_capacity(25usize + index.len());
                     url.push_str("/_cat/shards/");
                     url.push_str(index.as_ref());
+                    url.push_str("?format=json");
                     Url::from(url)
                 }
             }
@@ -3455,13 +3456,13 @@ pub mod endpoints {
         pub url: Url<'a>,
     }
     impl<'a> CatShardsRequest<'a> {
-        #[doc = "Request to: `/_cat/shards`"]
+        #[doc = "Request to: `/_cat/shards?format=json`"]
         pub fn new() -> Self {
             CatShardsRequest {
                 url: CatShardsUrlParams::None.url(),
             }
         }
-        #[doc = "Request to: `/_cat/shards/{index}`"]
+        #[doc = "Request to: `/_cat/shards/{index}?format=json`"]
         pub fn for_index<IIndex>(index: IIndex) -> Self
         where
             IIndex: Into<Index<'a>>,
@@ -3758,7 +3759,7 @@ pub mod endpoints {
         pub url: Url<'a>,
     }
     impl<'a> GetRequest<'a> {
-        #[doc = "Request to: `/{index}/{type}/{id}`"]
+        #[doc = "Request to: `/{index}/{type}/{id}`\n\n# Examples\n\nUse a numeric id:\n\n```\n# use elastic_requests::*;\nlet req = GetRequest::for_index_ty_id(\"myindex\", \"mytype\", DocumentId::from(42));\n\nassert_eq!(\"/myindex/mytype/42\", *req.url);\n```"]
         pub fn for_index_ty_id<IIndex, IType, IId>(index: IIndex, ty: IType, id: IId) -> Self
         where
             IIndex: Into<Index<'a>>,
@@ -4056,7 +4057,7 @@ pub mod endpoints {
     impl CatHealthUrlParams {
         pub fn url<'a>(self) -> Url<'a> {
             match self {
-                CatHealthUrlParams::None => Url::from("/_cat/health"),
+                CatHealthUrlParams::None => Url::from("/_cat/health?format=json"),
             }
         }
     }
@@ -4066,7 +4067,7 @@ pub mod endpoints {
         pub url: Url<'a>,
     }
     impl<'a> CatHealthRequest<'a> {
-        #[doc = "Request to: `/_cat/health`"]
+        #[doc = "Request to: `/_cat/health?format=json`"]
         pub fn new() -> Self {
             CatHealthRequest {
                 url: CatHealthUrlParams::None.url(),
@@ -4316,7 +4317,7 @@ pub mod endpoints {
     impl CatNodesUrlParams {
         pub fn url<'a>(self) -> Url<'a> {
             match self {
-                CatNodesUrlParams::None => Url::from("/_cat/nodes"),
+                CatNodesUrlParams::None => Url::from("/_cat/nodes?format=json"),
             }
         }
     }
@@ -4326,7 +4327,7 @@ pub mod endpoints {
         pub url: Url<'a>,
     }
     impl<'a> CatNodesRequest<'a> {
-        #[doc = "Request to: `/_cat/nodes`"]
+        #[doc = "Request to: `/_cat/nodes?format=json`"]
         pub fn new() -> Self {
             CatNodesRequest {
                 url: CatNodesUrlParams::None.url(),
@@ -5248,7 +5249,7 @@ pub mod endpoints {
                 body: body,
             }
         }
-        #[doc = "Request to: `/{index}/{type}/{id}`"]
+        #[doc = "Request to: `/{index}/{type}/{id}`\n\n# Examples\n\nUse a numeric id:\n\n```\n# use elastic_requests::*;\nlet req = IndexRequest::for_index_ty_id(\"myindex\", \"mytype\", DocumentId::from(42), empty_body());\n\nassert_eq!(\"/myindex/mytype/42\", *req.url);\n```"]
         pub fn for_index_ty_id<IIndex, IType, IId>(index: IIndex, ty: IType, id: IId, body: B) -> Self
         where
             IIndex: Into<Index<'a>>,
@@ -5380,11 +5381,12 @@ pub mod endpoints {
     impl<'a> CatIndicesUrlParams<'a> {
         pub fn url(self) -> Url<'a> {
             match self {
-                CatIndicesUrlParams::None => Url::from("/_cat/indices"),
+                CatIndicesUrlParams::None => Url::from("/_cat/indices?format=json"),
                 CatIndicesUrlParams::Index(ref index) => {
-                    let mut url = String::with_capacity(14usize + index.len());
+                    let mut url = String::with_capacity(26usize + index.len());
                     url.push_str("/_cat/indices/");
                     url.push_str(index.as_ref());
+                    url.push_str("?format=json");
                     Url::from(url)
                 }
             }
@@ -5396,13 +5398,13 @@ pub mod endpoints {
         pub url: Url<'a>,
     }
     impl<'a> CatIndicesRequest<'a> {
-        #[doc = "Request to: `/_cat/indices`"]
+        #[doc = "Request to: `/_cat/indices?format=json`"]
         pub fn new() -> Self {
             CatIndicesRequest {
                 url: CatIndicesUrlParams::None.url(),
             }
         }
-        #[doc = "Request to: `/_cat/indices/{index}`"]
+        #[doc = "Request to: `/_cat/indices/{index}?format=json`"]
         pub fn for_index<IIndex>(index: IIndex) -> Self
         where
             IIndex: Into<Index<'a>>,
@@ -6422,6 +6424,105 @@ pub mod endpoints {
             }
         }
     }
+    #[derive(Debug, PartialEq, Clone)]
+    enum FieldCapsUrlParams<'a> {
+        None,
+        Index(Index<'a>),
+    }
+    impl<'a> FieldCapsUrlParams<'a> {
+        pub fn url(self) -> Url<'a> {
+            match self {
+                FieldCapsUrlParams::None => Url::from("/_field_caps"),
+                FieldCapsUrlParams::Index(ref index) => {
+                    let mut url = String::with_capacity(13usize + index.len());
+                    url.push_str("/");
+                    url.push_str(index.as_ref());
+                    url.push_str("/_field_caps");
+                    Url::from(url)
+                }
+            }
+        }
+    }
+    #[derive(Debug, PartialEq, Clone)]
+    #[doc = "`Post: /_field_caps`\n\n[Elasticsearch Documentation](http://www.elastic.co/guide/en/elasticsearch/reference/master/search-field-caps.html)"]
+    pub struct FieldCapsRequest<'a, B> {
+        pub url: Url<'a>,
+        pub body: B,
+    }
+    impl<'a, B> FieldCapsRequest<'a, B> {
+        #[doc = "Request to: `/_field_caps`"]
+        pub fn new(body: B) -> Self {
+            FieldCapsRequest {
+                url: FieldCapsUrlParams::None.url(),
+                body: body,
+            }
+        }
+        #[doc = "Request to: `/{index}/_field_caps`"]
+        pub fn for_index<IIndex>(index: IIndex, body: B) -> Self
+        where
+            IIndex: Into<Index<'a>>,
+        {
+            FieldCapsRequest {
+                url: FieldCapsUrlParams::Index(index.into()).url(),
+                body: body,
+            }
+        }
+    }
+    impl<'a, B> Into<HttpRequest<'a, B>> for FieldCapsRequest<'a, B> {
+        fn into(self) -> HttpRequest<'a, B> {
+            HttpRequest {
+                url: self.url,
+                method: HttpMethod::Post,
+                body: Some(self.body),
+            }
+        }
+    }
+    #[derive(Debug, PartialEq, Clone)]
+    enum IndicesSplitUrlParams<'a> {
+        IndexTarget(Index<'a>, Target<'a>),
+    }
+    impl<'a> IndicesSplitUrlParams<'a> {
+        pub fn url(self) -> Url<'a> {
+            match self {
+                IndicesSplitUrlParams::IndexTarget(ref index, ref target) => {
+                    let mut url = String::with_capacity(9usize + index.len() + target.len());
+                    url.push_str("/");
+                    url.push_str(index.as_ref());
+                    url.push_str("/_split/");
+                    url.push_str(target.as_ref());
+                    Url::from(url)
+                }
+            }
+        }
+    }
+    #[derive(Debug, PartialEq, Clone)]
+    #[doc = "`Post: /{index}/_split/{target}`\n\n[Elasticsearch Documentation](http://www.elastic.co/guide/en/elasticsearch/reference/master/indices-split-index.html)"]
+    pub struct IndicesSplitRequest<'a, B> {
+        pub url: Url<'a>,
+        pub body: B,
+    }
+    impl<'a, B> IndicesSplitRequest<'a, B> {
+        #[doc = "Request to: `/{index}/_split/{target}`"]
+        pub fn for_index_target<IIndex, ITarget>(index: IIndex, target: ITarget, body: B) -> Self
+        where
+            IIndex: Into<Index<'a>>,
+            ITarget: Into<Target<'a>>,
+        {
+            IndicesSplitRequest {
+                url: IndicesSplitUrlParams::IndexTarget(index.into(), target.into()).url(),
+                body: body,
+            }
+        }
+    }
+    impl<'a, B> Into<HttpRequest<'a, B>> for IndicesSplitRequest<'a, B> {
+        fn into(self) -> HttpRequest<'a, B> {
+            HttpRequest {
+                url: self.url,
+                method: HttpMethod::Post,
+                body: Some(self.body),
+            }
+        }
+    }
 }
 
 pub mod http {
@@ -70,6 +70,24 @@
 //! takes_req(SearchRequest::for_index("test_index", empty_body()));
 //! ```
 //!
+//! # Recording requests
+//!
+//! A `HttpRequest` with a byte body can be serialised, so requests can be written to a file and replayed later.
+//! The body is written as a string if it's valid UTF-8, or as base64 otherwise:
+//!
+//! ```
+//! # extern crate serde_json;
+//! # extern crate elastic_requests;
+//! # use elastic_requests::*;
+//! # fn main() {
+//! let req: HttpRequest<_> = SearchRequest::for_index("test_index", "{}").into();
+//!
+//! let recorded = serde_json::to_string(&req).unwrap();
+//!
+//! assert_eq!(r#"{"method":"POST","url":"/test_index/_search","body":"{}"}"#, recorded);
+//! # }
+//! ```
+//!
 //! # Why are these docs useless?
 //!
 //! This library is automatically generated, so there's a lot more work to do
@@ -82,7 +100,17 @@
 
 #![deny(warnings)]
 
+extern crate base64;
+extern crate serde;
+#[macro_use]
+extern crate serde_json;
+extern crate url;
+#[cfg(feature = "uuid")]
+extern crate uuid;
+
 mod genned;
+mod record;
+pub mod builders;
 
 /// Common url params like `Id` and `Index`.
 ///
@@ -115,6 +143,7 @@ pub mod endpoints {
 pub use genned::http::*;
 pub use self::params::*;
 pub use self::endpoints::*;
+pub use self::builders::*;
 
 #[cfg(test)]
 mod tests {
@@ -153,6 +182,22 @@ mod tests {
         do_something_with_static_request(req).join().unwrap();
     }
 
+    #[test]
+    fn split_index() {
+        let req = IndicesSplitRequest::for_index_target("source", "target", empty_body());
+
+        assert_eq!("/source/_split/target", *req.url);
+    }
+
+    #[test]
+    fn cat_requests_use_json_format() {
+        assert_eq!("/_cat/indices?format=json", *CatIndicesRequest::new().url);
+        assert_eq!("/_cat/indices/idx?format=json", *CatIndicesRequest::for_index("idx").url);
+        assert_eq!("/_cat/nodes?format=json", *CatNodesRequest::new().url);
+        assert_eq!("/_cat/shards/idx?format=json", *CatShardsRequest::for_index("idx").url);
+        assert_eq!("/_cat/health?format=json", *CatHealthRequest::new().url);
+    }
+
     #[test]
     fn id_from_number() {
         let ids = vec![
@@ -0,0 +1,101 @@
+//! Serialisation for recording requests.
+//!
+//! Requests are serialised as their method, url and body.
+//! A body that's valid UTF-8 is written as `body`, anything else is written as base64 in `body_base64`.
+
+use std::str;
+use serde::{Serialize, Serializer};
+use serde::ser::SerializeStruct;
+
+use genned::http::{HttpMethod, HttpRequest};
+
+impl Serialize for HttpMethod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let method = match *self {
+            HttpMethod::Head => "HEAD",
+            HttpMethod::Get => "GET",
+            HttpMethod::Post => "POST",
+            HttpMethod::Put => "PUT",
+            HttpMethod::Delete => "DELETE",
+            HttpMethod::Patch => "PATCH",
+        };
+
+        serializer.serialize_str(method)
+    }
+}
+
+impl<'a, B> Serialize for HttpRequest<'a, B>
+where
+    B: AsRef<[u8]>,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let len = if self.body.is_some() { 3 } else { 2 };
+
+        let mut state = serializer.serialize_struct("HttpRequest", len)?;
+        state.serialize_field("method", &self.method)?;
+        state.serialize_field("url", &*self.url)?;
+
+        if let Some(ref body) = self.body {
+            let body = body.as_ref();
+
+            match str::from_utf8(body) {
+                Ok(body) => state.serialize_field("body", body)?,
+                Err(_) => state.serialize_field("body_base64", &base64::encode(body))?,
+            }
+        }
+
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use genned::endpoints::{IndexRequest, PingHeadRequest};
+    use genned::http::{DefaultBody, HttpRequest};
+
+    #[test]
+    fn serialize_utf8_body() {
+        let req: HttpRequest<_> = IndexRequest::for_index_ty_id("idx", "ty", "1", r#"{"title":"a document"}"#).into();
+
+        let ser = serde_json::to_value(&req).unwrap();
+
+        let expected = json!({
+            "method": "POST",
+            "url": "/idx/ty/1",
+            "body": r#"{"title":"a document"}"#
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialize_binary_body() {
+        let req: HttpRequest<_> = IndexRequest::for_index_ty_id("idx", "ty", "1", vec![0, 159, 146, 150]).into();
+
+        let ser = serde_json::to_value(&req).unwrap();
+
+        let expected = json!({
+            "method": "POST",
+            "url": "/idx/ty/1",
+            "body_base64": "AJ+Slg=="
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialize_no_body() {
+        let req: HttpRequest<DefaultBody> = PingHeadRequest::new().into();
+
+        let ser = serde_json::to_value(&req).unwrap();
+
+        assert_eq!(json!({ "method": "HEAD", "url": "/" }), ser);
+    }
+}
@@ -43,7 +43,8 @@ fn main() {
     let mut endpoints = from_dir(dir)
         .expect("Couldn't parse the REST API spec")
         .add_simple_search()
-        .add_get_ping_req();
+        .add_get_ping_req()
+        .add_cat_json_format();
 
     endpoints = endpoints
         .into_iter()
@@ -142,6 +143,7 @@ fn dedup_urls(endpoint: (String, Endpoint)) -> (String, Endpoint) {
 trait CustomEndpoints {
     fn add_simple_search(self) -> Self;
     fn add_get_ping_req(self) -> Self;
+    fn add_cat_json_format(self) -> Self;
 }
 
 impl CustomEndpoints for Vec<(String, Endpoint)> {
@@ -181,6 +183,27 @@ impl CustomEndpoints for Vec<(String, Endpoint)> {
                 endpoints
             })
     }
+
+    fn add_cat_json_format(self) -> Vec<(String, Endpoint)> {
+        self.into_iter()
+            .map(|(name, mut endpoint)| {
+                match name.as_ref() {
+                    // These cat endpoints have typed rows in `elastic_responses`, so always ask for json
+                    "cat.indices" | "cat.nodes" | "cat.shards" | "cat.health" => {
+                        endpoint.url.paths = endpoint
+                            .url
+                            .paths
+                            .into_iter()
+                            .map(|path| Path(format!("{}?format=json", path)))
+                            .collect();
+                    }
+                    _ => (),
+                }
+
+                (name, endpoint)
+            })
+            .collect()
+    }
 }
 
 fn endpoints_mod(tokens: &mut Tokens, derives: Tokens, http_mod: &'static str, endpoints: Vec<(String, Endpoint)>, params_to_emit: &mut BTreeMap<String, bool>) {
@@ -0,0 +1,40 @@
+//! Elasticsearch Reqwest Client Samples
+//!
+//! This sample assumes you have a node running on `localhost`.
+//!
+//! This sample wraps the client in a middleware that logs each request
+//! to `stderr` before it's sent.
+
+extern crate elastic_reqwest;
+
+use elastic_reqwest::{Error, RequestParams, SyncElasticClient};
+use elastic_reqwest::middleware::{Middleware, MiddlewareClient, PreparedRequest};
+use elastic_reqwest::req::PingRequest;
+
+/// A middleware that prints the method and url of each request.
+struct LoggingMiddleware;
+
+impl Middleware for LoggingMiddleware {
+    fn before_send(&self, req: &mut PreparedRequest) {
+        eprintln!("Sending request: {} {}", req.method(), req.url());
+    }
+}
+
+fn run() -> Result<(), Error> {
+    // Get a new default client and wrap it in the logging middleware.
+    let (client, _) = elastic_reqwest::sync::default()?;
+    let client = MiddlewareClient::new(client, LoggingMiddleware);
+
+    let params = RequestParams::default();
+
+    // Send the request. The middleware logs it first.
+    let http_res = client.elastic_req(&params, PingRequest::new())?;
+
+    println!("Got response: {}", http_res.status());
+
+    Ok(())
+}
+
+fn main() {
+    run().unwrap();
+}
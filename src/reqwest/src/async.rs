@@ -1,19 +1,31 @@
 /*! Asynchronous http client. */
 
 use std::mem;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use bytes::Bytes;
 use serde::de::DeserializeOwned;
 use serde_json::Value;
-use reqwest::unstable::async::{Body, Client, ClientBuilder, Decoder, RequestBuilder, Response};
-use futures::{Future, Poll, Stream};
+use reqwest::StatusCode;
+use reqwest::header::Headers;
+use reqwest::unstable::async::{Body, Chunk, Client, ClientBuilder, Decoder, RequestBuilder, Response};
+use futures::{Async, Future, Poll, Stream};
+use futures::future::Either;
+use futures::task::{self, Task};
 use tokio_core::reactor::Handle;
 
 use private;
 use super::req::HttpRequest;
 use super::res::parsing::{IsOk, Parse};
-use super::{build_method, build_url, Error, RequestParams};
+use super::{build_method, build_url, check_body_len, check_content_len, BufferedBody, Error, RequestParams};
 
-/** Get a default `Client` and `RequestParams`. */
+/**
+Get a default `Client` and `RequestParams`.
+
+The `Client` keeps a pool of connections that are reused across requests, so it should be shared rather than rebuilt for each request.
+Idle connections are kept alive in the pool for 90 seconds, and there's no limit on the number of idle connections per host.
+The version of `reqwest` used here doesn't support changing these settings.
+*/
 pub fn default(handle: &Handle) -> Result<(Client, RequestParams), Error> {
     ClientBuilder::new()
         .build(handle)
@@ -55,6 +67,12 @@ impl From<Value> for AsyncBody {
     }
 }
 
+impl From<BufferedBody> for AsyncBody {
+    fn from(body: BufferedBody) -> AsyncBody {
+        AsyncBody(body.into_bytes().into())
+    }
+}
+
 impl From<&'static [u8]> for AsyncBody {
     fn from(body: &'static [u8]) -> AsyncBody {
         AsyncBody(Bytes::from(body).into())
@@ -75,7 +93,7 @@ pub trait AsyncElasticClient: private::Sealed {
     # Examples
     
     Bring the `AsyncElasticClient` trait into scope and call `elastic_req` with any type that can be converted into a `req::HttpRequest`.
-    This method returns a raw `reqwest::Response`.
+    This method returns a raw `AsyncResponse`.
     
     ```no_run
     # extern crate elastic_reqwest;
@@ -98,17 +116,156 @@ pub trait AsyncElasticClient: private::Sealed {
     where
         I: Into<HttpRequest<'static, B>>,
         B: Into<AsyncBody>;
+
+    /**
+    Send a request and get a response, unless the request is cancelled first.
+
+    The returned future races the request against the `token`.
+    If the token is cancelled before a response is received then the request is dropped, which closes its connection, and the future returns `Error::Cancelled`.
+    If the token is already cancelled then the request is never sent.
+
+    The response body is read by a separate future, like the one returned by `parse().from_response`.
+    To also cancel while the body is being read, race that future against `token.cancelled()`.
+    Cancelling part way through a body drops the connection and never returns the partial body.
+
+    # Examples
+
+    ```no_run
+    # extern crate elastic_reqwest;
+    # extern crate tokio_core;
+    # use elastic_reqwest::req::SimpleSearchRequest;
+    # fn main() {
+    # let mut core = tokio_core::reactor::Core::new().unwrap();
+    # let request = SimpleSearchRequest::for_index_ty("myindex", "mytype");
+    use elastic_reqwest::AsyncElasticClient;
+    use elastic_reqwest::async::CancellationToken;
+
+    let (client, params) = elastic_reqwest::async::default(&core.handle()).unwrap();
+
+    let token = CancellationToken::new();
+    let http_future = client.elastic_req_cancellable(&params, request, token.clone());
+
+    // Cancel the request from somewhere else
+    token.cancel();
+
+    assert!(core.run(http_future).is_err());
+    # }
+    ```
+    */
+    fn elastic_req_cancellable<I, B>(&self, params: &RequestParams, req: I, token: CancellationToken) -> Pending
+    where
+        I: Into<HttpRequest<'static, B>>,
+        B: Into<AsyncBody>;
+}
+
+/**
+A token that can be used to cancel pending requests.
+
+Cloning a token returns a handle to the same token, so cancelling any clone cancels all of them.
+Once a token is cancelled it stays cancelled.
+*/
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<Mutex<CancellationState>>,
+}
+
+#[derive(Default)]
+struct CancellationState {
+    cancelled: bool,
+    next_id: usize,
+    waiting: HashMap<usize, Task>,
+}
+
+impl CancellationToken {
+    /** Create a new token that isn't cancelled. */
+    pub fn new() -> Self {
+        CancellationToken::default()
+    }
+
+    /** Cancel the token, waking any futures waiting on it. */
+    pub fn cancel(&self) {
+        let waiting = {
+            let mut state = self.inner.lock().expect("cancellation state poisoned");
+            state.cancelled = true;
+            mem::replace(&mut state.waiting, HashMap::new())
+        };
+
+        for (_, task) in waiting {
+            task.notify();
+        }
+    }
+
+    /** Whether or not the token has been cancelled. */
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.lock().expect("cancellation state poisoned").cancelled
+    }
+
+    /** Get a future that completes when the token is cancelled. */
+    pub fn cancelled(&self) -> Cancelled {
+        Cancelled {
+            token: self.clone(),
+            id: None,
+        }
+    }
+}
+
+/**
+A future returned by calling `CancellationToken::cancelled`.
+
+Each future registers its task with the token the first time it's polled, and removes it when it's dropped.
+*/
+pub struct Cancelled {
+    token: CancellationToken,
+    id: Option<usize>,
+}
+
+impl Future for Cancelled {
+    type Item = ();
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        let mut state = self.token.inner.lock().expect("cancellation state poisoned");
+
+        if state.cancelled {
+            Ok(Async::Ready(()))
+        } else {
+            let id = match self.id {
+                Some(id) => id,
+                None => {
+                    let id = state.next_id;
+                    state.next_id = state.next_id.wrapping_add(1);
+                    self.id = Some(id);
+
+                    id
+                }
+            };
+
+            // Replace any task from a previous poll, so the token only holds one per future
+            state.waiting.insert(id, task::current());
+            Ok(Async::NotReady)
+        }
+    }
+}
+
+impl Drop for Cancelled {
+    fn drop(&mut self) {
+        if let Some(id) = self.id {
+            if let Ok(mut state) = self.token.inner.lock() {
+                state.waiting.remove(&id);
+            }
+        }
+    }
 }
 
 /** A future returned by calling `elastic_req`. */
 pub struct Pending {
-    inner: Box<Future<Item = Response, Error = Error>>,
+    inner: Box<Future<Item = AsyncResponse, Error = Error>>,
 }
 
 impl Pending {
     fn new<F>(fut: F) -> Self
     where
-        F: Future<Item = Response, Error = Error> + 'static,
+        F: Future<Item = AsyncResponse, Error = Error> + 'static,
     {
         Pending {
             inner: Box::new(fut),
@@ -117,7 +274,7 @@ impl Pending {
 }
 
 impl Future for Pending {
-    type Item = Response;
+    type Item = AsyncResponse;
     type Error = Error;
 
     fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
@@ -155,17 +312,141 @@ impl AsyncElasticClient for Client {
         I: Into<HttpRequest<'static, B>>,
         B: Into<AsyncBody>,
     {
+        let limits = ResponseLimits::from_params(params);
+
         let mut req = build_req(&self, params, req);
-        Pending::new(req.send().map_err(Into::into))
+        Pending::new(req.send().map_err(Into::into).and_then(move |res| AsyncResponse::new(limits, res)))
+    }
+
+    fn elastic_req_cancellable<I, B>(&self, params: &RequestParams, req: I, token: CancellationToken) -> Pending
+    where
+        I: Into<HttpRequest<'static, B>>,
+        B: Into<AsyncBody>,
+    {
+        let limits = ResponseLimits::from_params(params);
+
+        let mut req = build_req(&self, params, req);
+
+        // The token is polled first so an already cancelled request is never sent
+        let cancellable = token
+            .cancelled()
+            .select2(req.send().map_err(Into::into))
+            .then(move |res| match res {
+                Ok(Either::A(_)) => Err(Error::Cancelled),
+                Ok(Either::B((res, _))) => AsyncResponse::new(limits, res),
+                Err(Either::A((err, _))) | Err(Either::B((err, _))) => Err(err),
+            });
+
+        Pending::new(cancellable)
     }
 }
 
 impl private::Sealed for Client {}
 
+/**
+A raw response returned by `AsyncElasticClient::elastic_req`.
+
+The body can be read as a stream of chunks using `into_body`.
+The stream returns an error once more of the body has been read than the limit set by `RequestParams::max_response_bytes`.
+*/
+#[derive(Debug)]
+pub struct AsyncResponse {
+    inner: Response,
+    limits: ResponseLimits,
+}
+
+/** The limits from `RequestParams` that apply to a response. */
+#[derive(Debug, Clone, Copy)]
+struct ResponseLimits {
+    max_response_bytes: Option<usize>,
+    capture_body_on_error_limit: Option<usize>,
+}
+
+impl ResponseLimits {
+    fn from_params(params: &RequestParams) -> Self {
+        ResponseLimits {
+            max_response_bytes: params.get_max_response_bytes(),
+            capture_body_on_error_limit: params.get_capture_body_on_error_limit(),
+        }
+    }
+}
+
+impl AsyncResponse {
+    fn new(limits: ResponseLimits, res: Response) -> Result<Self, Error> {
+        check_content_len(limits.max_response_bytes, res.headers())?;
+
+        Ok(AsyncResponse {
+            inner: res,
+            limits: limits,
+        })
+    }
+
+    /** Get the HTTP status for the response. */
+    pub fn status(&self) -> StatusCode {
+        self.inner.status()
+    }
+
+    /** Get the headers for the response. */
+    pub fn headers(&self) -> &Headers {
+        self.inner.headers()
+    }
+
+    /** Get the limit set by `RequestParams::capture_body_on_error_limit`, if there is one. */
+    pub fn capture_body_on_error_limit(&self) -> Option<usize> {
+        self.limits.capture_body_on_error_limit
+    }
+
+    /** Convert the response into a stream of body chunks. */
+    pub fn into_body(mut self) -> AsyncResponseBody {
+        AsyncResponseBody {
+            inner: mem::replace(self.inner.body_mut(), Decoder::empty()),
+            max_response_bytes: self.limits.max_response_bytes,
+            read: 0,
+        }
+    }
+
+    /**
+    Convert the response into a raw `reqwest::unstable::async::Response`.
+
+    Reading from the raw response isn't limited by `RequestParams::max_response_bytes`.
+    */
+    pub fn into_inner(self) -> Response {
+        self.inner
+    }
+}
+
+/** The body of an `AsyncResponse`. */
+#[derive(Debug)]
+pub struct AsyncResponseBody {
+    inner: Decoder,
+    max_response_bytes: Option<usize>,
+    read: usize,
+}
+
+impl Stream for AsyncResponseBody {
+    type Item = Chunk;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        match self.inner.poll()? {
+            Async::Ready(Some(chunk)) => {
+                self.read += chunk.len();
+
+                // Returning an error stops a `concat2` before the rest of the body is buffered
+                check_body_len(self.max_response_bytes, self.read)?;
+
+                Ok(Async::Ready(Some(chunk)))
+            }
+            Async::Ready(None) => Ok(Async::Ready(None)),
+            Async::NotReady => Ok(Async::NotReady),
+        }
+    }
+}
+
 /** Represents a response that can be parsed into a concrete Elasticsearch response. */
 pub trait AsyncFromResponse<TResponse>: private::Sealed {
     /** Parse a response into a concrete response type. */
-    fn from_response(self, response: Response) -> FromResponse<TResponse>;
+    fn from_response(self, response: AsyncResponse) -> FromResponse<TResponse>;
 }
 
 /** A future returned by calling `elastic_req`. */
@@ -196,14 +477,18 @@ impl<TResponse> Future for FromResponse<TResponse> {
 impl<TResponse> private::Sealed for Parse<TResponse> {}
 
 impl<TResponse: IsOk + DeserializeOwned + 'static> AsyncFromResponse<TResponse> for Parse<TResponse> {
-    fn from_response(self, mut response: Response) -> FromResponse<TResponse> {
+    fn from_response(self, response: AsyncResponse) -> FromResponse<TResponse> {
         let status: u16 = response.status().into();
-        let body_future = mem::replace(response.body_mut(), Decoder::empty())
-            .concat2()
-            .map_err(Into::into);
+
+        let parse = match response.capture_body_on_error_limit() {
+            Some(limit) => self.capture_body_on_error_limit(limit),
+            None => self,
+        };
+
+        let body_future = response.into_body().concat2();
 
         let de_future = body_future.and_then(move |body| {
-            self.from_slice(status, body.as_ref()).map_err(Into::into)
+            parse.from_slice(status, body.as_ref()).map_err(Into::into)
         });
 
         FromResponse::new(de_future)
@@ -216,9 +501,11 @@ mod tests {
     use reqwest::unstable::async::{Client, RequestBuilder};
     use reqwest::header::ContentType;
     use tokio_core::reactor::Core;
+    use futures::future;
 
     use super::*;
     use req::*;
+    use {parse, serve_chunked_once, serve_once};
 
     fn params() -> RequestParams {
         RequestParams::new("eshost:9200/path")
@@ -315,6 +602,130 @@ mod tests {
         assert_req(expected, req);
     }
 
+    #[test]
+    fn cancelled_token_completes() {
+        let token = CancellationToken::new();
+        let other = token.clone();
+
+        assert!(!other.is_cancelled());
+
+        token.cancel();
+
+        assert!(other.is_cancelled());
+        core().run(other.cancelled()).unwrap();
+    }
+
+    #[test]
+    fn cancelled_futures_do_not_leak_tasks() {
+        let mut core = core();
+        let token = CancellationToken::new();
+
+        for _ in 0..100 {
+            let mut cancelled = token.cancelled();
+
+            core.run(future::lazy(|| {
+                for _ in 0..10 {
+                    assert_eq!(Async::NotReady, cancelled.poll().unwrap());
+                }
+
+                Ok::<_, Error>(())
+            })).unwrap();
+
+            assert_eq!(1, token.inner.lock().unwrap().waiting.len());
+        }
+
+        assert_eq!(0, token.inner.lock().unwrap().waiting.len());
+    }
+
+    #[test]
+    fn cancelled_req_returns_cancelled_err() {
+        let mut core = core();
+        let cli = Client::new(&core.handle());
+
+        let token = CancellationToken::new();
+        token.cancel();
+
+        let req = cli.elastic_req_cancellable(&RequestParams::new("http://eshost:9200"), PingHeadRequest::new(), token);
+
+        match core.run(req) {
+            Err(Error::Cancelled) => (),
+            _ => panic!("expected a cancelled error"),
+        }
+    }
+
+    const BODY: &'static str = r#"{"_index":"idx","_type":"ty","_id":"1","_version":1,"found":true,"_source":{"title":"a document"}}"#;
+
+    #[test]
+    fn response_within_limit() {
+        let mut core = core();
+        let cli = Client::new(&core.handle());
+
+        let (url, _) = serve_chunked_once("200 OK", BODY, 16);
+        let params = RequestParams::new(url).max_response_bytes(BODY.len());
+
+        let res = cli.elastic_req(&params, PingRequest::new())
+            .and_then(|res| parse::<Value>().from_response(res));
+
+        assert_eq!("idx", core.run(res).unwrap()["_index"]);
+    }
+
+    #[test]
+    fn response_too_large() {
+        let mut core = core();
+        let cli = Client::new(&core.handle());
+
+        let (url, _) = serve_once("200 OK", BODY);
+        let params = RequestParams::new(url).max_response_bytes(10);
+
+        match core.run(cli.elastic_req(&params, PingRequest::new())) {
+            Err(Error::ResponseTooLarge { limit, received }) => {
+                assert_eq!(10, limit);
+                assert_eq!(BODY.len(), received);
+            }
+            res => panic!("expected a response too large error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn parse_error_capture_body_on_error_limit() {
+        use res::SearchResponse;
+        use res::error::{ParseResponseError, ResponseError};
+
+        let mut core = core();
+        let cli = Client::new(&core.handle());
+
+        let (url, _) = serve_once("200 OK", r#"{ "took": "not a number" }"#);
+        let params = RequestParams::new(url).capture_body_on_error_limit(0);
+
+        let res = cli.elastic_req(&params, PingRequest::new())
+            .and_then(|res| parse::<SearchResponse<Value>>().from_response(res));
+
+        match core.run(res) {
+            Err(Error::Response(ResponseError::Parse(ParseResponseError::Body { raw_body, .. }))) => assert_eq!(None, raw_body),
+            res => panic!("expected a body parse error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn chunked_response_too_large() {
+        let mut core = core();
+        let cli = Client::new(&core.handle());
+
+        let (url, _) = serve_chunked_once("200 OK", BODY, 16);
+        let params = RequestParams::new(url).max_response_bytes(10);
+
+        let res = cli.elastic_req(&params, PingRequest::new())
+            .and_then(|res| parse::<Value>().from_response(res));
+
+        match core.run(res) {
+            Err(Error::ResponseTooLarge { limit, received }) => {
+                assert_eq!(10, limit);
+                assert!(received > limit && received < BODY.len());
+            }
+            res => panic!("expected a response too large error, got {:?}", res),
+        }
+    }
+
     #[test]
     fn owned_string_into_body() {
         AsyncBody::from(String::new());
@@ -346,4 +757,9 @@ mod tests {
     fn json_value_into_body() {
         AsyncBody::from(json!({}));
     }
+
+    #[test]
+    fn buffered_into_body() {
+        AsyncBody::from(BufferedBody::from(json!({})));
+    }
 }
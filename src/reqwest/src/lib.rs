@@ -170,7 +170,7 @@ use elastic_reqwest::res::SearchResponse;
 let (client, _) = elastic_reqwest::sync::default().unwrap();
 
 let params = RequestParams::default()
-    .url_param("pretty", true)
+    .pretty(true)
     .url_param("q", "*");
 
 let search = SimpleSearchRequest::for_index_ty(
@@ -226,15 +226,24 @@ extern crate serde_json;
 extern crate tokio_core;
 extern crate url;
 
+#[cfg(feature = "reqwest-0.9")]
+extern crate http;
+#[cfg(feature = "reqwest-0.9")]
+extern crate hyper;
+
 mod private {
     pub trait Sealed {}
 }
 
 pub mod sync;
 pub mod async;
+pub mod middleware;
+
+#[cfg(feature = "testing")]
+pub mod testing;
 
-pub use self::sync::{SyncBody, SyncElasticClient, SyncFromResponse};
-pub use self::async::{AsyncBody, AsyncElasticClient, AsyncFromResponse};
+pub use self::sync::{SyncBody, SyncElasticClient, SyncFromResponse, SyncResponse};
+pub use self::async::{AsyncBody, AsyncElasticClient, AsyncFromResponse, AsyncResponse};
 
 /**
 Request types.
@@ -258,11 +267,18 @@ pub use self::res::parse;
 
 use std::sync::Arc;
 use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
 use std::str;
+use std::vec;
+use bytes::Bytes;
 use reqwest::Error as ReqwestError;
 use reqwest::header::{ContentType, Header, Headers};
+use serde_json::Value;
 use url::form_urlencoded::Serializer;
 
+#[cfg(feature = "reqwest-0.9")]
+use http::HeaderMap;
+
 use self::res::error::ResponseError;
 use self::req::HttpMethod;
 
@@ -277,6 +293,13 @@ quick_error! {
             display("http error: {}", err)
             cause(err)
         }
+        /** A json serialisation error. */
+        Json(err: serde_json::Error) {
+            from()
+            description("json error")
+            display("json error: {}", err)
+            cause(err)
+        }
         /** A response error. */
         Response(err: ResponseError) {
             from()
@@ -284,11 +307,142 @@ quick_error! {
             display("response error: {}", err)
             cause(err)
         }
+        /**
+        A response body was larger than the limit set by `RequestParams::max_response_bytes`.
+
+        The `received` length is the `Content-Length` of the response if it declared one.
+        Otherwise it's the number of bytes that were read before the limit was passed, so it may be less than the full body.
+        */
+        ResponseTooLarge { limit: usize, received: usize } {
+            description("response too large")
+            display("response too large: received {} bytes but the limit is {} bytes", received, limit)
+        }
+        /** A request was cancelled by an `async::CancellationToken` before it completed. */
+        Cancelled {
+            description("request cancelled")
+            display("request cancelled")
+        }
         #[doc(hidden)]
         __NonExhaustive
     }
 }
 
+/**
+A request body that's already been serialised.
+
+Cloning a `BufferedBody` is cheap because the serialised bytes are shared between clones.
+This makes it possible to send the same body in many requests, like when fanning a search out to multiple clusters, while only serialising it once.
+`BufferedBody` can be used as the body for both the `SyncElasticClient` and `AsyncElasticClient`.
+
+# Examples
+
+Send the same search body to two clusters:
+
+```no_run
+# #[macro_use] extern crate serde_json;
+# extern crate elastic_reqwest;
+# use elastic_reqwest::{BufferedBody, RequestParams, SyncElasticClient};
+# use elastic_reqwest::req::SearchRequest;
+# fn main() {
+let (client, _) = elastic_reqwest::sync::default().unwrap();
+
+let body = BufferedBody::from(json!({ "query": { "match_all": {} } }));
+
+for params in &[RequestParams::new("http://cluster_one:9200"), RequestParams::new("http://cluster_two:9200")] {
+    let res = client.elastic_req(params, SearchRequest::for_index("myindex", body.clone())).unwrap();
+}
+# }
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct BufferedBody(Bytes);
+
+impl BufferedBody {
+    /** Get the serialised body. */
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /** Convert the body into its serialised bytes. */
+    pub fn into_bytes(self) -> Bytes {
+        self.0
+    }
+}
+
+impl From<Bytes> for BufferedBody {
+    fn from(body: Bytes) -> BufferedBody {
+        BufferedBody(body)
+    }
+}
+
+impl From<Vec<u8>> for BufferedBody {
+    fn from(body: Vec<u8>) -> BufferedBody {
+        BufferedBody(body.into())
+    }
+}
+
+impl From<String> for BufferedBody {
+    fn from(body: String) -> BufferedBody {
+        BufferedBody(body.into())
+    }
+}
+
+impl From<Value> for BufferedBody {
+    fn from(body: Value) -> BufferedBody {
+        BufferedBody(body.to_string().into())
+    }
+}
+
+/**
+Serialise the body of a request up front so the request can be sent more than once.
+
+# Examples
+
+Buffer a search request and send it to two clusters:
+
+```no_run
+# #[macro_use] extern crate serde_json;
+# extern crate elastic_reqwest;
+# use elastic_reqwest::{BufferBody, RequestParams, SyncElasticClient};
+# use elastic_reqwest::req::SearchRequest;
+# fn main() {
+let (client, _) = elastic_reqwest::sync::default().unwrap();
+
+let body = json!({ "query": { "match_all": {} } }).to_string();
+let (req, _) = SearchRequest::for_index("myindex", body).buffer_body();
+
+for params in &[RequestParams::new("http://cluster_one:9200"), RequestParams::new("http://cluster_two:9200")] {
+    let res = client.elastic_req(params, req.clone()).unwrap();
+}
+# }
+```
+*/
+pub trait BufferBody {
+    /** The request with a buffered body. */
+    type Buffered;
+
+    /** Buffer the body of the request, returning the buffered request along with its serialised body. */
+    fn buffer_body(self) -> (Self::Buffered, Bytes);
+}
+
+impl<'a, B> BufferBody for req::SearchRequest<'a, B>
+where
+    B: AsRef<[u8]>,
+{
+    type Buffered = req::SearchRequest<'a, BufferedBody>;
+
+    fn buffer_body(self) -> (Self::Buffered, Bytes) {
+        let body = Bytes::from(self.body.as_ref());
+
+        let req = req::SearchRequest {
+            url: self.url,
+            body: BufferedBody(body.clone()),
+        };
+
+        (req, body)
+    }
+}
+
 /**
 Misc parameters for any request.
 
@@ -332,7 +486,7 @@ With url query parameters:
 # use elastic_reqwest::RequestParams;
 # fn main() {
 let params = RequestParams::default()
-    .url_param("pretty", true)
+    .pretty(true)
     .url_param("q", "*");
 # }
 ```
@@ -342,6 +496,11 @@ pub struct RequestParams {
     /** Base url for Elasticsearch. */ base_url: String,
     /** Simple key-value store for url query params. */ url_params: BTreeMap<&'static str, String>,
     /** The complete set of headers that will be sent with the request. */ headers_factory: Option<Arc<Fn(&mut Headers) + Send + Sync + 'static>>,
+    /** The largest response body that will be accepted. */ max_response_bytes: Option<usize>,
+    /** The largest response body that's captured when it can't be deserialised. */ capture_body_on_error_limit: Option<usize>,
+    /** Whether the base url has been changed since the params were created. */
+    #[cfg(debug_assertions)]
+    base_url_set: bool,
 }
 
 impl RequestParams {
@@ -356,11 +515,29 @@ impl RequestParams {
             base_url: base.into(),
             headers_factory: None,
             url_params: BTreeMap::new(),
+            max_response_bytes: None,
+            capture_body_on_error_limit: None,
+            #[cfg(debug_assertions)]
+            base_url_set: false,
         }
     }
 
-    /** Set the base url for the Elasticsearch node. */
+    /** 
+    Set the base url for the Elasticsearch node.
+
+    # Panics
+
+    In debug builds, this method panics if the base url has already been set since the params were created.
+    Setting it twice is usually a mistake where one of the values is silently ignored.
+    In release builds the last value wins.
+    */
     pub fn base_url<T: Into<String>>(mut self, base: T) -> Self {
+        #[cfg(debug_assertions)]
+        {
+            assert!(!self.base_url_set, "the base url has already been set to '{}'", self.base_url);
+            self.base_url_set = true;
+        }
+
         self.base_url = base.into();
 
         self
@@ -376,6 +553,81 @@ impl RequestParams {
         self
     }
 
+    /** Remove a url param value if it's been set. */
+    pub fn remove_url_param(mut self, key: &'static str) -> Self {
+        self.url_params.remove(key);
+        self
+    }
+
+    /** Remove all url param values. */
+    pub fn clear_url_params(mut self) -> Self {
+        self.url_params.clear();
+        self
+    }
+
+    /** Set the `pretty` url param to return formatted json. */
+    pub fn pretty(self, pretty: bool) -> Self {
+        self.url_param("pretty", pretty)
+    }
+
+    /** Set the `human` url param to return values like sizes and times in a human readable format. */
+    pub fn human(self, human: bool) -> Self {
+        self.url_param("human", human)
+    }
+
+    /**
+    Set the `filter_path` url param to only return the given parts of responses.
+
+    Paths can use wildcards, like `hits.hits._source` or `*.id`.
+    */
+    pub fn filter_path(self, paths: &[&str]) -> Self {
+        self.url_param("filter_path", paths.join(","))
+    }
+
+    /** Set the `error_trace` url param to include stack traces in error responses. */
+    pub fn error_trace(self, error_trace: bool) -> Self {
+        self.url_param("error_trace", error_trace)
+    }
+
+    /**
+    Set the `timeout` url param, like `30s`.
+
+    This is how long Elasticsearch waits for the request to be processed on the cluster.
+    It doesn't limit how long the client waits for a response.
+    */
+    pub fn request_timeout(self, timeout: &str) -> Self {
+        self.url_param("timeout", timeout)
+    }
+
+    /**
+    Set the largest response body that will be accepted, in bytes.
+
+    Responses with a larger `Content-Length` are returned as an `Error::ResponseTooLarge` before their body is read.
+    The bytes of the body are also counted as they're read, so chunked and compressed responses, or responses that understate their `Content-Length`,
+    return an `Error::ResponseTooLarge` as soon as the limit is passed.
+    This is only a safeguard in the client.
+    It doesn't ask Elasticsearch to truncate the response, so the request still runs in full on the cluster.
+
+    By default there is no limit.
+    */
+    pub fn max_response_bytes(mut self, limit: usize) -> Self {
+        self.max_response_bytes = Some(limit);
+        self
+    }
+
+    /**
+    Set the largest response body, in bytes, that's captured when it can't be deserialised into the response type.
+
+    Bodies that are no larger than the limit are included in the parse error.
+    Only up to the limit is buffered, so setting the limit to `0` streams every successful response without capturing its body.
+
+    By default the limit is `res::parsing::DEFAULT_CAPTURE_BODY_ON_ERROR_LIMIT`.
+    */
+    pub fn capture_body_on_error_limit(mut self, limit: usize) -> Self {
+        self.capture_body_on_error_limit = Some(limit);
+        self
+    }
+
     /** Set a request header. */
     pub fn header<H>(self, header: H) -> Self
     where
@@ -384,6 +636,41 @@ impl RequestParams {
         self.headers(move |h| h.set(header.clone()))
     }
 
+    /**
+    Override a request header for a single request.
+
+    The returned `WithHeaders` borrows these params instead of cloning them.
+    Headers set on the override replace any with the same name on the params.
+
+    # Examples
+
+    ```no_run
+    # extern crate reqwest;
+    # extern crate elastic_reqwest;
+    # use elastic_reqwest::req::SimpleSearchRequest;
+    # use reqwest::header::Authorization;
+    # fn main() {
+    # let request = SimpleSearchRequest::for_index_ty("myindex", "mytype");
+    use elastic_reqwest::SyncElasticClient;
+
+    let (client, params) = elastic_reqwest::sync::default().unwrap();
+
+    let params = params.with_header_override(Authorization("let me in".to_owned()));
+
+    let http_res = client.elastic_req(&params, request).unwrap();
+    # }
+    ```
+    */
+    pub fn with_header_override<H>(&self, header: H) -> WithHeaders
+    where
+        H: Header,
+    {
+        WithHeaders {
+            params: self,
+            headers: Headers::new(),
+        }.header(header)
+    }
+
     /** 
     Set a header value on the params.
     
@@ -410,11 +697,57 @@ impl RequestParams {
         self
     }
 
+    /** 
+    Set request headers on a `http::HeaderMap`.
+
+    This is a migration path to `reqwest` `0.9`, which uses the `HeaderMap` type from the `http` crate instead of `Headers`.
+    Values inserted into the map are merged with any headers set by `header`.
+    If the same header is set by both then whichever was called last wins.
+
+    # Examples
+
+    ```
+    # extern crate http;
+    # extern crate elastic_reqwest;
+    # use elastic_reqwest::RequestParams;
+    # use http::header::{HeaderValue, AUTHORIZATION};
+    # fn main() {
+    let params = RequestParams::default()
+        .header_map(|h| {
+            h.insert(AUTHORIZATION, HeaderValue::from_static("let me in"));
+        });
+    # }
+    ```
+    */
+    #[cfg(feature = "reqwest-0.9")]
+    pub fn header_map<F>(self, header_map_factory: F) -> Self
+    where
+        F: Fn(&mut HeaderMap) + Send + Sync + 'static,
+    {
+        self.headers(move |headers| {
+            let mut header_map = HeaderMap::new();
+            header_map_factory(&mut header_map);
+
+            let header_map: Headers = header_map.into();
+            headers.extend(header_map.iter());
+        })
+    }
+
     /** Get the base url. */
     pub fn get_base_url(&self) -> &str {
         &self.base_url
     }
 
+    /** Get the largest response body that will be accepted, in bytes. */
+    pub fn get_max_response_bytes(&self) -> Option<usize> {
+        self.max_response_bytes
+    }
+
+    /** Get the largest response body that's captured when it can't be deserialised, in bytes. */
+    pub fn get_capture_body_on_error_limit(&self) -> Option<usize> {
+        self.capture_body_on_error_limit
+    }
+
     /** Create a new `Headers` structure, and thread it through the configuration functions. */
     pub fn get_headers(&self) -> Headers {
         let mut headers = Headers::new();
@@ -427,6 +760,44 @@ impl RequestParams {
         headers
     }
 
+    /**
+    Iterate over the headers as name and value pairs.
+
+    The headers are the same as the ones returned by `get_headers`, including the default `Content-Type`.
+    Headers are built on demand, so the pairs are owned rather than borrowed from the params.
+
+    # Examples
+
+    ```
+    # extern crate reqwest;
+    # extern crate elastic_reqwest;
+    # use elastic_reqwest::RequestParams;
+    # use reqwest::header::Authorization;
+    # fn main() {
+    let params = RequestParams::default()
+        .header(Authorization("let me in".to_owned()));
+
+    for (name, value) in params.headers_iter() {
+        println!("-H '{}: {}'", name, value);
+    }
+    # }
+    ```
+    */
+    pub fn headers_iter(&self) -> vec::IntoIter<(String, String)> {
+        let pairs: Vec<_> = self.get_headers()
+            .iter()
+            .map(|header| (header.name().to_owned(), header.value_string()))
+            .collect();
+
+        pairs.into_iter()
+    }
+
+    /** Create a new `http::HeaderMap` containing the same headers as `get_headers`. */
+    #[cfg(feature = "reqwest-0.9")]
+    pub fn get_header_map(&self) -> HeaderMap {
+        self.get_headers().into()
+    }
+
     /** 
     Get the url query params as a formatted string.
     
@@ -453,16 +824,186 @@ impl Default for RequestParams {
     }
 }
 
-fn build_url<'a>(req_url: &str, params: &RequestParams) -> String {
+impl RequestParams {
+    /** 
+    Get the headers as sorted name and value pairs.
+
+    Headers are produced by functions that can't be compared directly, so the headers they set are compared instead.
+    */
+    fn header_pairs(&self) -> Vec<(String, String)> {
+        let mut pairs: Vec<_> = self.headers_iter()
+            .map(|(name, value)| (name.to_lowercase(), value))
+            .collect();
+
+        pairs.sort();
+
+        pairs
+    }
+}
+
+impl PartialEq for RequestParams {
+    fn eq(&self, other: &RequestParams) -> bool {
+        self.base_url == other.base_url && self.url_params == other.url_params && self.max_response_bytes == other.max_response_bytes
+            && self.capture_body_on_error_limit == other.capture_body_on_error_limit && self.header_pairs() == other.header_pairs()
+    }
+}
+
+impl Eq for RequestParams {}
+
+impl Hash for RequestParams {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.base_url.hash(state);
+        self.url_params.hash(state);
+        self.max_response_bytes.hash(state);
+        self.capture_body_on_error_limit.hash(state);
+        self.header_pairs().hash(state);
+    }
+}
+
+impl<'a> From<&'a RequestParams> for RequestParams {
+    fn from(params: &'a RequestParams) -> Self {
+        params.clone()
+    }
+}
+
+/**
+Request parameters borrowed from a `RequestParams` with some headers overridden.
+
+Use `RequestParams::with_header_override` to create one.
+*/
+pub struct WithHeaders<'a> {
+    params: &'a RequestParams,
+    headers: Headers,
+}
+
+impl<'a> WithHeaders<'a> {
+    /** Override another request header. */
+    pub fn header<H>(mut self, header: H) -> Self
+    where
+        H: Header,
+    {
+        self.headers.set(header);
+        self
+    }
+}
+
+/**
+Parameters that can be sent with a request.
+
+This trait is implemented by `RequestParams` and `WithHeaders`, and by references to those types.
+*/
+pub trait HttpParams: private::Sealed {
+    /** Get the base url. */
+    fn get_base_url(&self) -> &str;
+
+    /** Get the url query params as a formatted string. */
+    fn get_url_qry(&self) -> (usize, Option<String>);
+
+    /** Get the headers that will be sent with the request. */
+    fn get_headers(&self) -> Headers;
+
+    /** Get the largest response body that will be accepted, in bytes. */
+    fn get_max_response_bytes(&self) -> Option<usize>;
+
+    /** Get the largest response body that's captured when it can't be deserialised, in bytes. */
+    fn get_capture_body_on_error_limit(&self) -> Option<usize>;
+}
+
+impl HttpParams for RequestParams {
+    fn get_base_url(&self) -> &str {
+        RequestParams::get_base_url(self)
+    }
+
+    fn get_url_qry(&self) -> (usize, Option<String>) {
+        RequestParams::get_url_qry(self)
+    }
+
+    fn get_headers(&self) -> Headers {
+        RequestParams::get_headers(self)
+    }
+
+    fn get_max_response_bytes(&self) -> Option<usize> {
+        RequestParams::get_max_response_bytes(self)
+    }
+
+    fn get_capture_body_on_error_limit(&self) -> Option<usize> {
+        RequestParams::get_capture_body_on_error_limit(self)
+    }
+}
+
+impl private::Sealed for RequestParams {}
+
+impl<'a> HttpParams for WithHeaders<'a> {
+    fn get_base_url(&self) -> &str {
+        self.params.get_base_url()
+    }
+
+    fn get_url_qry(&self) -> (usize, Option<String>) {
+        self.params.get_url_qry()
+    }
+
+    fn get_headers(&self) -> Headers {
+        let mut headers = self.params.get_headers();
+        headers.extend(self.headers.iter());
+
+        headers
+    }
+
+    fn get_max_response_bytes(&self) -> Option<usize> {
+        self.params.get_max_response_bytes()
+    }
+
+    fn get_capture_body_on_error_limit(&self) -> Option<usize> {
+        self.params.get_capture_body_on_error_limit()
+    }
+}
+
+impl<'a> private::Sealed for WithHeaders<'a> {}
+
+impl<'a, T: HttpParams + ?Sized> HttpParams for &'a T {
+    fn get_base_url(&self) -> &str {
+        (**self).get_base_url()
+    }
+
+    fn get_url_qry(&self) -> (usize, Option<String>) {
+        (**self).get_url_qry()
+    }
+
+    fn get_headers(&self) -> Headers {
+        (**self).get_headers()
+    }
+
+    fn get_max_response_bytes(&self) -> Option<usize> {
+        (**self).get_max_response_bytes()
+    }
+
+    fn get_capture_body_on_error_limit(&self) -> Option<usize> {
+        (**self).get_capture_body_on_error_limit()
+    }
+}
+
+impl<'a, T: private::Sealed + ?Sized> private::Sealed for &'a T {}
+
+fn build_url<'a, P>(req_url: &str, params: &P) -> String
+where
+    P: HttpParams + ?Sized,
+{
     let (qry_len, qry) = params.get_url_qry();
+    let base_url = params.get_base_url();
 
-    let mut url = String::with_capacity(params.base_url.len() + req_url.len() + qry_len);
+    let mut url = String::with_capacity(base_url.len() + req_url.len() + qry_len);
 
-    url.push_str(&params.base_url);
+    url.push_str(base_url);
     url.push_str(&req_url);
 
     if let Some(qry) = qry {
-        url.push_str(&qry);
+        // The request url may already contain query parameters
+        if req_url.contains('?') {
+            url.push('&');
+            url.push_str(&qry[1..]);
+        } else {
+            url.push_str(&qry);
+        }
     }
 
     url
@@ -479,14 +1020,98 @@ fn build_method(method: HttpMethod) -> reqwest::Method {
     }
 }
 
+/** Check the `Content-Length` of a response against the limit set by `RequestParams::max_response_bytes`. */
+fn check_content_len(limit: Option<usize>, headers: &Headers) -> Result<(), Error> {
+    use reqwest::header::ContentLength;
+
+    match (limit, headers.get::<ContentLength>()) {
+        (Some(limit), Some(&ContentLength(len))) if len as usize > limit => Err(Error::ResponseTooLarge {
+            limit: limit,
+            received: len as usize,
+        }),
+        _ => Ok(()),
+    }
+}
+
+/** Check the number of bytes read from a response body against the limit set by `RequestParams::max_response_bytes`. */
+fn check_body_len(limit: Option<usize>, read: usize) -> Result<(), Error> {
+    match limit {
+        Some(limit) if read > limit => Err(Error::ResponseTooLarge {
+            limit: limit,
+            received: read,
+        }),
+        _ => Ok(()),
+    }
+}
+
 #[cfg(test)]
 fn assert_send<T: Send>() {}
 
 #[cfg(test)]
 fn assert_sync<T: Sync>() {}
 
+/**
+Serve a single canned json response on a local port and return its base url.
+
+The handle returns the start of the request that was received.
+*/
+#[cfg(test)]
+fn serve_once(status: &'static str, body: &'static str) -> (String, ::std::thread::JoinHandle<String>) {
+    serve_raw_once(format!(
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    ))
+}
+
+/**
+Serve a single canned json response without a `Content-Length` on a local port and return its base url.
+
+The body is sent using chunked encoding, in chunks of `chunk_len` bytes.
+*/
+#[cfg(test)]
+fn serve_chunked_once(status: &'static str, body: &'static str, chunk_len: usize) -> (String, ::std::thread::JoinHandle<String>) {
+    let mut res = format!(
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nTransfer-Encoding: chunked\r\nConnection: close\r\n\r\n",
+        status
+    );
+
+    for chunk in body.as_bytes().chunks(chunk_len) {
+        res.push_str(&format!("{:x}\r\n{}\r\n", chunk.len(), str::from_utf8(chunk).unwrap()));
+    }
+
+    res.push_str("0\r\n\r\n");
+
+    serve_raw_once(res)
+}
+
+#[cfg(test)]
+fn serve_raw_once(res: String) -> (String, ::std::thread::JoinHandle<String>) {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let handle = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+
+        let mut buf = [0; 4096];
+        let len = stream.read(&mut buf).unwrap();
+
+        stream.write_all(res.as_bytes()).unwrap();
+
+        String::from_utf8_lossy(&buf[..len]).into_owned()
+    });
+
+    (format!("http://{}", addr), handle)
+}
+
 #[cfg(test)]
 mod tests {
+    use std::collections::hash_map::DefaultHasher;
     use reqwest::header::{Authorization, ContentType, Referer};
     use super::*;
 
@@ -525,6 +1150,99 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "reqwest-0.9")]
+    #[test]
+    fn header_map_has_default_content_type() {
+        use http::header::CONTENT_TYPE;
+
+        let req = RequestParams::default();
+
+        let headers = req.get_header_map();
+
+        assert_eq!("application/json", headers[CONTENT_TYPE]);
+    }
+
+    #[cfg(feature = "reqwest-0.9")]
+    #[test]
+    fn set_headers_with_header_map() {
+        use http::header::{HeaderValue, AUTHORIZATION, REFERER};
+
+        let req = RequestParams::default()
+            .header(Referer::new("/not-the-value"))
+            .header_map(|h| {
+                h.insert(REFERER, HeaderValue::from_static("/People.html#tim"));
+                h.insert(AUTHORIZATION, HeaderValue::from_static("let me in"));
+            });
+
+        let headers = req.get_headers();
+
+        assert_eq!(Some(&ContentType::json()), headers.get::<ContentType>());
+        assert_eq!(
+            Some(&Referer::new("/People.html#tim")),
+            headers.get::<Referer>()
+        );
+        assert_eq!(
+            Some(&Authorization("let me in".to_owned())),
+            headers.get::<Authorization<String>>()
+        );
+    }
+
+    #[test]
+    fn headers_iter_yields_all_headers() {
+        let req = RequestParams::default()
+            .header(Referer::new("/People.html#tim"))
+            .header(Authorization("let me in".to_owned()));
+
+        let mut headers: Vec<_> = req.headers_iter().collect();
+        headers.sort();
+
+        let expected = vec![
+            ("Authorization".to_owned(), "let me in".to_owned()),
+            ("Content-Type".to_owned(), "application/json".to_owned()),
+            ("Referer".to_owned(), "/People.html#tim".to_owned()),
+        ];
+
+        assert_eq!(expected, headers);
+    }
+
+    #[test]
+    fn header_override_replaces_params_header() {
+        let params = RequestParams::default()
+            .header(Referer::new("/not-the-value"))
+            .header(Authorization("let me in".to_owned()));
+
+        let req = params.with_header_override(Referer::new("/People.html#tim"));
+
+        let headers = HttpParams::get_headers(&req);
+
+        assert_eq!(Some(&ContentType::json()), headers.get::<ContentType>());
+        assert_eq!(
+            Some(&Referer::new("/People.html#tim")),
+            headers.get::<Referer>()
+        );
+        assert_eq!(
+            Some(&Authorization("let me in".to_owned())),
+            headers.get::<Authorization<String>>()
+        );
+
+        assert_eq!(
+            Some(&Referer::new("/not-the-value")),
+            params.get_headers().get::<Referer>()
+        );
+    }
+
+    #[test]
+    fn header_override_borrows_base_url_and_query() {
+        let params = RequestParams::new("http://eshost:9200").url_param("pretty", true);
+
+        let req = params.with_header_override(Referer::new("/People.html#tim"));
+
+        assert_eq!(
+            "http://eshost:9200/_search?pretty=true",
+            build_url("/_search", &req)
+        );
+    }
+
     #[test]
     fn request_params_has_default_base_url() {
         let req = RequestParams::default();
@@ -552,10 +1270,180 @@ mod tests {
         );
     }
 
+    #[test]
+    fn request_params_can_set_common_url_query() {
+        let req = RequestParams::default()
+            .pretty(true)
+            .human(false)
+            .filter_path(&["hits.hits._source", "took"])
+            .error_trace(true)
+            .request_timeout("30s");
+
+        assert_eq!(
+            Some(String::from("?error_trace=true&filter_path=hits.hits._source%2Ctook&human=false&pretty=true&timeout=30s")),
+            req.get_url_qry().1
+        );
+    }
+
+    #[test]
+    fn request_params_common_url_query_overrides_url_param() {
+        let req = RequestParams::default().url_param("pretty", false).pretty(true);
+
+        assert_eq!(Some(String::from("?pretty=true")), req.get_url_qry().1);
+    }
+
+    #[test]
+    fn request_params_can_remove_url_query() {
+        let req = RequestParams::default()
+            .url_param("pretty", true)
+            .url_param("q", "*")
+            .remove_url_param("pretty")
+            .remove_url_param("human");
+
+        assert_eq!(Some(String::from("?q=*")), req.get_url_qry().1);
+    }
+
+    #[test]
+    fn request_params_can_clear_url_query() {
+        let req = RequestParams::default()
+            .pretty(true)
+            .url_param("q", "*")
+            .clear_url_params();
+
+        assert_eq!((0, None), req.get_url_qry());
+    }
+
+    #[test]
+    fn build_url_with_params() {
+        let req = RequestParams::new("http://eshost:9200").url_param("pretty", true);
+
+        assert_eq!("http://eshost:9200/_search?pretty=true", build_url("/_search", &req));
+    }
+
+    #[test]
+    fn build_url_with_request_query_and_params() {
+        let req = RequestParams::new("http://eshost:9200").url_param("pretty", true);
+
+        assert_eq!(
+            "http://eshost:9200/_search?q=*&pretty=true",
+            build_url("/_search?q=*", &req)
+        );
+    }
+
     #[test]
     fn empty_request_params_returns_empty_string() {
         let req = RequestParams::default();
 
         assert_eq!((0, None), req.get_url_qry());
     }
+
+    #[test]
+    fn request_params_from_ref() {
+        let req = RequestParams::new("http://eshost:9200").url_param("pretty", true);
+
+        let owned: RequestParams = (&req).into();
+
+        assert_eq!("http://eshost:9200", owned.get_base_url());
+        assert_eq!(req.get_url_qry(), owned.get_url_qry());
+    }
+
+    fn hash<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn equal_request_params_have_equal_hashes() {
+        let params1 = RequestParams::new("http://eshost:9200")
+            .url_param("pretty", true)
+            .header(Authorization("let me in".to_owned()))
+            .header(Referer::new("/people"));
+
+        let params2 = RequestParams::new("http://eshost:9200")
+            .header(Referer::new("/people"))
+            .url_param("pretty", true)
+            .header(Authorization("let me in".to_owned()));
+
+        assert!(params1 == params2);
+        assert_eq!(hash(&params1), hash(&params2));
+    }
+
+    #[test]
+    fn request_params_with_different_headers_are_not_equal() {
+        let params1 = RequestParams::default().header(Authorization("let me in".to_owned()));
+        let params2 = RequestParams::default().header(Authorization("let me out".to_owned()));
+
+        assert!(params1 != params2);
+        assert!(params1 != RequestParams::default());
+    }
+
+    #[test]
+    fn request_params_with_different_urls_are_not_equal() {
+        let params = RequestParams::default().url_param("pretty", true);
+
+        assert!(params != RequestParams::default());
+        assert!(params != params.clone().base_url("http://eshost:9200"));
+    }
+
+    #[test]
+    fn search_after_pages_through_hits() {
+        use self::req::{SearchAfterCursor, SearchRequest};
+        use self::res::SearchResponse;
+
+        let query = json!({ "size": 1, "sort": [{ "date": "asc" }] });
+
+        let first_page = br#"{
+            "took": 1, "timed_out": false,
+            "_shards": { "total": 1, "successful": 1, "failed": 0 },
+            "hits": { "total": 2, "max_score": null, "hits": [
+                { "_index": "twitter", "_type": "tweet", "_id": "1", "_score": null, "sort": [1463538855000] }
+            ] }
+        }"#;
+
+        let first_page = parse::<SearchResponse>().from_slice(200, &first_page[..]).unwrap();
+        let cursor = SearchAfterCursor::from(first_page.last_sort_values().unwrap());
+
+        let req = SearchRequest::for_index("twitter", query.clone()).with_search_after(cursor);
+        assert_eq!(json!([1463538855000u64]), req.body["search_after"]);
+
+        let second_page = br#"{
+            "took": 1, "timed_out": false,
+            "_shards": { "total": 1, "successful": 1, "failed": 0 },
+            "hits": { "total": 2, "max_score": null, "hits": [
+                { "_index": "twitter", "_type": "tweet", "_id": "2", "_score": null, "sort": [1463538857000] }
+            ] }
+        }"#;
+
+        let second_page = parse::<SearchResponse>().from_slice(200, &second_page[..]).unwrap();
+        let cursor = SearchAfterCursor::from(second_page.last_sort_values().unwrap());
+
+        let req = SearchRequest::for_index("twitter", query).with_search_after(cursor);
+        assert_eq!(json!([1463538857000u64]), req.body["search_after"]);
+    }
+
+    #[test]
+    fn buffered_body_clones_share_bytes() {
+        let body = BufferedBody::from(json!({ "query": { "match_all": {} } }));
+        let cloned = body.clone();
+
+        assert_eq!(br#"{"query":{"match_all":{}}}"#, cloned.as_bytes());
+        assert_eq!(body.as_bytes().as_ptr(), cloned.as_bytes().as_ptr());
+    }
+
+    #[test]
+    fn buffer_search_body() {
+        let (req, body) = req::SearchRequest::for_index("idx", r#"{"query":{"match_all":{}}}"#).buffer_body();
+
+        assert_eq!(&br#"{"query":{"match_all":{}}}"#[..], &body[..]);
+        assert_eq!(&body[..], req.body.as_bytes());
+        assert_eq!("/idx/_search", *req.url);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn request_params_panics_setting_base_url_twice() {
+        let _ = RequestParams::default().base_url("http://a:9200").base_url("http://b:9200");
+    }
 }
@@ -0,0 +1,180 @@
+/*!
+Hooks for inspecting and changing requests before they're sent.
+
+Wrap a `reqwest::Client` in a `MiddlewareClient` to run a `Middleware` on every request.
+This can be used to add trace ids, log requests or set headers at the last moment.
+*/
+
+use reqwest::{Client, Method, Request, Url};
+use reqwest::header::Headers;
+
+use private;
+use super::req::HttpRequest;
+use super::sync::{build_req, check_response_len, SyncBody, SyncElasticClient, SyncResponse};
+use super::{Error, HttpParams};
+
+/**
+A request that's ready to be sent.
+
+The headers can be changed, but the method and url can only be read.
+This stops a middleware from redirecting requests somewhere else.
+*/
+pub struct PreparedRequest(Request);
+
+impl PreparedRequest {
+    /** Get the method for the request. */
+    pub fn method(&self) -> &Method {
+        self.0.method()
+    }
+
+    /** Get the url for the request. */
+    pub fn url(&self) -> &Url {
+        self.0.url()
+    }
+
+    /** Get the headers for the request. */
+    pub fn headers(&self) -> &Headers {
+        self.0.headers()
+    }
+
+    /** Get a mutable reference to the headers for the request. */
+    pub fn headers_mut(&mut self) -> &mut Headers {
+        self.0.headers_mut()
+    }
+}
+
+/** A hook that's called with each request before it's sent. */
+pub trait Middleware {
+    /** Inspect or change a request before it's sent. */
+    fn before_send(&self, req: &mut PreparedRequest);
+}
+
+/**
+A client that runs a `Middleware` on each request before sending it.
+
+# Examples
+
+Set a header on every request:
+
+```no_run
+# extern crate reqwest;
+# extern crate elastic_reqwest;
+# use elastic_reqwest::req::PingRequest;
+# use reqwest::header::Authorization;
+use elastic_reqwest::SyncElasticClient;
+use elastic_reqwest::middleware::{Middleware, MiddlewareClient, PreparedRequest};
+
+struct Auth;
+
+impl Middleware for Auth {
+    fn before_send(&self, req: &mut PreparedRequest) {
+        req.headers_mut().set(Authorization("let me in".to_owned()));
+    }
+}
+
+# fn main() {
+let (client, params) = elastic_reqwest::sync::default().unwrap();
+let client = MiddlewareClient::new(client, Auth);
+
+let http_res = client.elastic_req(&params, PingRequest::new()).unwrap();
+# }
+```
+*/
+pub struct MiddlewareClient<M> {
+    client: Client,
+    middleware: M,
+}
+
+impl<M: Middleware> MiddlewareClient<M> {
+    /** Wrap a client with a middleware. */
+    pub fn new(client: Client, middleware: M) -> Self {
+        MiddlewareClient {
+            client: client,
+            middleware: middleware,
+        }
+    }
+
+    /** Get the inner client. */
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /** Get the middleware. */
+    pub fn middleware(&self) -> &M {
+        &self.middleware
+    }
+}
+
+impl<M: Middleware> SyncElasticClient for MiddlewareClient<M> {
+    fn elastic_req<P, I, B>(&self, params: P, req: I) -> Result<SyncResponse, Error>
+    where
+        P: HttpParams,
+        I: Into<HttpRequest<'static, B>>,
+        B: Into<SyncBody>,
+    {
+        let req = build_req(&self.client, &params, req).build()?;
+
+        let mut req = PreparedRequest(req);
+        self.middleware.before_send(&mut req);
+
+        let res = self.client.execute(req.0)?;
+
+        check_response_len(&params, res)
+    }
+}
+
+impl<M> private::Sealed for MiddlewareClient<M> {}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use reqwest::header::Referer;
+    use super::*;
+    use req::PingRequest;
+    use serve_once;
+    use RequestParams;
+
+    struct SetReferer;
+
+    impl Middleware for SetReferer {
+        fn before_send(&self, req: &mut PreparedRequest) {
+            req.headers_mut().set(Referer::new("/People.html#tim"));
+        }
+    }
+
+    struct RecordUrl(Mutex<Vec<String>>);
+
+    impl Middleware for RecordUrl {
+        fn before_send(&self, req: &mut PreparedRequest) {
+            let url = format!("{} {}", req.method(), req.url());
+            self.0.lock().unwrap().push(url);
+        }
+    }
+
+    #[test]
+    fn middleware_sets_header() {
+        let (url, req) = serve_once("200 OK", "{}");
+        let params = RequestParams::new(url);
+
+        let client = MiddlewareClient::new(Client::new(), SetReferer);
+        client.elastic_req(&params, PingRequest::new()).unwrap();
+
+        let req = req.join().unwrap().to_lowercase();
+
+        assert!(req.contains("referer: /people.html#tim"));
+        assert!(!req.contains("content-type"));
+    }
+
+    #[test]
+    fn middleware_sees_method_and_url() {
+        let (url, _) = serve_once("200 OK", "{}");
+        let params = RequestParams::new(url.clone());
+
+        let client = MiddlewareClient::new(Client::new(), RecordUrl(Mutex::new(Vec::new())));
+        client.elastic_req(&params, PingRequest::new()).unwrap();
+
+        let recorded = client.middleware().0.lock().unwrap();
+
+        assert_eq!(vec![format!("GET {}/", url)], *recorded);
+    }
+}
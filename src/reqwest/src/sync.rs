@@ -1,17 +1,27 @@
 /*! Synchronous http client. */
 
-use std::io::Cursor;
+use std::io::{self, Cursor, Read};
 use std::fs::File;
+use serde::Serialize;
 use serde::de::DeserializeOwned;
-use serde_json::Value;
-use reqwest::{Body, Client, ClientBuilder, RequestBuilder, Response};
+use serde_json::{self, Value};
+use reqwest::{Body, Client, ClientBuilder, RequestBuilder, Response, StatusCode};
+use reqwest::header::{ContentLength, ContentType, Headers};
 
 use private;
-use super::req::HttpRequest;
+use super::req::{DeleteRequest, GetRequest, HttpMethod, HttpRequest, IndexRequest};
+use super::res::{DeleteResponse, GetResponse, IndexResponse};
+use super::res::error::{ParseResponseError, ResponseError};
 use super::res::parsing::{IsOk, Parse};
-use super::{build_method, build_url, Error, RequestParams};
+use super::{build_method, build_url, check_body_len, check_content_len, parse, BufferedBody, Error, HttpParams, RequestParams};
 
-/** Get a default `Client` and `RequestParams`. */
+/**
+Get a default `Client` and `RequestParams`.
+
+The `Client` keeps a pool of connections that are reused across requests, so it should be shared rather than rebuilt for each request.
+Idle connections are kept alive in the pool for 90 seconds, and there's no limit on the number of idle connections per host.
+The version of `reqwest` used here doesn't support changing these settings.
+*/
 pub fn default() -> Result<(Client, RequestParams), Error> {
     ClientBuilder::new()
         .build()
@@ -19,55 +29,72 @@ pub fn default() -> Result<(Client, RequestParams), Error> {
         .map_err(Into::into)
 }
 
-/** A type that can be converted into a request body. */
-pub struct SyncBody(Body);
+/**
+A type that can be converted into a request body.
+
+A body converted from `None` is empty, and requests with an empty body are sent without a `Content-Type`.
+*/
+pub struct SyncBody(Option<Body>);
 
 impl SyncBody {
     /** Convert the body into its inner value. */
     pub fn into_inner(self) -> Body {
-        self.0
+        self.0.unwrap_or_else(|| Body::from(Vec::new()))
     }
 }
 
 impl From<Body> for SyncBody {
     fn from(body: Body) -> SyncBody {
-        SyncBody(body)
+        SyncBody(Some(body))
+    }
+}
+
+impl<B: Into<SyncBody>> From<Option<B>> for SyncBody {
+    fn from(body: Option<B>) -> SyncBody {
+        SyncBody(body.and_then(|body| body.into().0))
     }
 }
 
 impl From<File> for SyncBody {
     fn from(body: File) -> SyncBody {
-        SyncBody(body.into())
+        SyncBody(Some(body.into()))
     }
 }
 
 impl From<Vec<u8>> for SyncBody {
     fn from(body: Vec<u8>) -> SyncBody {
-        SyncBody(body.into())
+        SyncBody(Some(body.into()))
     }
 }
 
 impl From<String> for SyncBody {
     fn from(body: String) -> SyncBody {
-        SyncBody(body.into())
+        SyncBody(Some(body.into()))
     }
 }
 
 impl From<Value> for SyncBody {
     fn from(body: Value) -> SyncBody {
-        SyncBody(body.to_string().into())
+        SyncBody(Some(body.to_string().into()))
+    }
+}
+
+impl From<BufferedBody> for SyncBody {
+    fn from(body: BufferedBody) -> SyncBody {
+        // The blocking `Body` needs an owned buffer, so the bytes are copied but not reserialised
+        SyncBody(Some(body.as_bytes().to_vec().into()))
     }
 }
 
 impl From<&'static [u8]> for SyncBody {
     fn from(body: &'static [u8]) -> SyncBody {
-        SyncBody(Body::new(Cursor::new(body)))
+        SyncBody(Some(Body::new(Cursor::new(body))))
     }
 }
 
 impl From<&'static str> for SyncBody {
     fn from(body: &'static str) -> SyncBody {
-        SyncBody(Body::new(Cursor::new(body)))
+        SyncBody(Some(Body::new(Cursor::new(body))))
     }
 }
 
@@ -80,7 +107,7 @@ pub trait SyncElasticClient: private::Sealed {
     
     Bring the `SyncElasticClient` trait into scope and call `elastic_req` with any type that
     can be converted into a `req::HttpRequest`.
-    This method returns a raw `reqwest::Response`.
+    This method returns a raw `SyncResponse`.
     
     ```no_run
     # extern crate elastic_reqwest;
@@ -94,16 +121,142 @@ pub trait SyncElasticClient: private::Sealed {
     let http_res = client.elastic_req(&params, request).unwrap();
     # }
     ```
+
+    The `params` can be borrowed or owned, so temporary parameters can be used to override
+    the defaults for a single request:
+
+    ```no_run
+    # extern crate elastic_reqwest;
+    # use elastic_reqwest::req::SimpleSearchRequest;
+    # fn main () {
+    # let request = SimpleSearchRequest::for_index_ty("myindex", "mytype");
+    use elastic_reqwest::SyncElasticClient;
+    
+    let (client, params) = elastic_reqwest::sync::default().unwrap();
+    
+    let http_res = client.elastic_req(params.clone().pretty(true), request).unwrap();
+    # }
+    ```
+
+    To override a header without cloning the params, use `RequestParams::with_header_override`.
     */
-    fn elastic_req<I, B>(&self, params: &RequestParams, req: I) -> Result<Response, Error>
+    fn elastic_req<P, I, B>(&self, params: P, req: I) -> Result<SyncResponse, Error>
     where
+        P: HttpParams,
         I: Into<HttpRequest<'static, B>>,
         B: Into<SyncBody>;
+
+    /**
+    Delete a single document by its index, type and id.
+
+    Returns `Ok(true)` if the document was deleted and `Ok(false)` if it wasn't found.
+    Any other failure is returned as an `Err`.
+
+    # Examples
+
+    ```no_run
+    # extern crate elastic_reqwest;
+    # fn main () {
+    use elastic_reqwest::SyncElasticClient;
+
+    let (client, params) = elastic_reqwest::sync::default().unwrap();
+
+    let deleted = client.elastic_delete_by_id(&params, "myindex", "mytype", "1").unwrap();
+    # }
+    ```
+    */
+    fn elastic_delete_by_id(&self, params: &RequestParams, index: &str, ty: &str, id: &str) -> Result<bool, Error> {
+        let req = DeleteRequest::for_index_ty_id(index.to_owned(), ty.to_owned(), id.to_owned());
+
+        let res = self.elastic_req(params, req)?;
+        let res: DeleteResponse = parse().from_response(res)?;
+
+        Ok(res.deleted())
+    }
+
+    /**
+    Get the source of a single document by its index, type and id.
+
+    Returns `Ok(None)` if the document wasn't found.
+    Any other failure is returned as an `Err`.
+
+    # Examples
+
+    ```no_run
+    # extern crate serde_json;
+    # extern crate elastic_reqwest;
+    # use serde_json::Value;
+    # fn main () {
+    use elastic_reqwest::SyncElasticClient;
+
+    let (client, params) = elastic_reqwest::sync::default().unwrap();
+
+    let doc: Option<Value> = client.elastic_get_source(&params, "myindex", "mytype", "1").unwrap();
+    # }
+    ```
+    */
+    fn elastic_get_source<T>(&self, params: &RequestParams, index: &str, ty: &str, id: &str) -> Result<Option<T>, Error>
+    where
+        T: DeserializeOwned,
+    {
+        let req = GetRequest::for_index_ty_id(index.to_owned(), ty.to_owned(), id.to_owned());
+
+        let res = self.elastic_req(params, req)?;
+        let res: GetResponse<T> = parse().from_response(res)?;
+
+        Ok(res.into_document())
+    }
+
+    /**
+    Index a single document.
+
+    The document is serialised as json.
+    If an `id` is given then the document is `PUT` with that id, otherwise it's `POST`ed and Elasticsearch generates an id.
+
+    # Examples
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # extern crate elastic_reqwest;
+    # fn main () {
+    use elastic_reqwest::SyncElasticClient;
+
+    let (client, params) = elastic_reqwest::sync::default().unwrap();
+
+    let doc = json!({ "title": "a document" });
+
+    let res = client.elastic_index_doc(&params, "myindex", "mytype", Some("1"), &doc).unwrap();
+    # }
+    ```
+    */
+    fn elastic_index_doc<T>(&self, params: &RequestParams, index: &str, ty: &str, id: Option<&str>, doc: &T) -> Result<IndexResponse, Error>
+    where
+        T: Serialize,
+    {
+        let body = serde_json::to_vec(doc)?;
+
+        let req: HttpRequest<_> = match id {
+            Some(id) => HttpRequest {
+                method: HttpMethod::Put,
+                ..IndexRequest::for_index_ty_id(index.to_owned(), ty.to_owned(), id.to_owned(), body).into()
+            },
+            None => IndexRequest::for_index_ty(index.to_owned(), ty.to_owned(), body).into(),
+        };
+
+        let res = self.elastic_req(params, req)?;
+
+        parse().from_response(res)
+    }
 }
 
-/** Build a synchronous `reqwest::RequestBuilder` from an Elasticsearch request. */
-pub fn build_req<I, B>(client: &Client, params: &RequestParams, req: I) -> RequestBuilder
+/**
+Build a synchronous `reqwest::RequestBuilder` from an Elasticsearch request.
+
+Requests without a body are sent with a `Content-Length` of `0` and no `Content-Type`.
+*/
+pub fn build_req<P, I, B>(client: &Client, params: &P, req: I) -> RequestBuilder
 where
+    P: HttpParams + ?Sized,
     I: Into<HttpRequest<'static, B>>,
     B: Into<SyncBody>,
 {
@@ -111,14 +264,21 @@ where
 
     let url = build_url(&req.url, &params);
     let method = build_method(req.method);
-    let body = req.body;
+    let body = req.body.and_then(|body| body.into().0);
 
     let mut req = client.request(method, &url);
     {
-        req.headers(params.get_headers());
+        let mut headers = params.get_headers();
+
+        if body.is_none() {
+            headers.remove::<ContentType>();
+            headers.set(ContentLength(0));
+        }
+
+        req.headers(headers);
 
         if let Some(body) = body {
-            req.body(body.into().into_inner());
+            req.body(body);
         }
     }
 
@@ -126,12 +286,103 @@ where
 }
 
 impl SyncElasticClient for Client {
-    fn elastic_req<I, B>(&self, params: &RequestParams, req: I) -> Result<Response, Error>
+    fn elastic_req<P, I, B>(&self, params: P, req: I) -> Result<SyncResponse, Error>
     where
+        P: HttpParams,
         I: Into<HttpRequest<'static, B>>,
         B: Into<SyncBody>,
     {
-        build_req(&self, params, req).send().map_err(Into::into)
+        let res = build_req(&self, &params, req).send()?;
+
+        check_response_len(&params, res)
+    }
+}
+
+/**
+Check a response against the limit set by `RequestParams::max_response_bytes`.
+
+A response with a larger `Content-Length` is rejected before any of the body is read.
+Otherwise the response is wrapped so the rest of the body is counted as it's read.
+*/
+pub(crate) fn check_response_len<P>(params: &P, res: Response) -> Result<SyncResponse, Error>
+where
+    P: HttpParams + ?Sized,
+{
+    let limit = params.get_max_response_bytes();
+
+    check_content_len(limit, res.headers())?;
+
+    Ok(SyncResponse {
+        inner: res,
+        max_response_bytes: limit,
+        capture_body_on_error_limit: params.get_capture_body_on_error_limit(),
+        read: 0,
+    })
+}
+
+/**
+A raw response returned by `SyncElasticClient::elastic_req`.
+
+The body can be read using `Read`.
+Reading returns an error once more of the body has been read than the limit set by `RequestParams::max_response_bytes`.
+*/
+#[derive(Debug)]
+pub struct SyncResponse {
+    inner: Response,
+    max_response_bytes: Option<usize>,
+    capture_body_on_error_limit: Option<usize>,
+    read: usize,
+}
+
+impl SyncResponse {
+    /** Get the HTTP status for the response. */
+    pub fn status(&self) -> StatusCode {
+        self.inner.status()
+    }
+
+    /** Get the headers for the response. */
+    pub fn headers(&self) -> &Headers {
+        self.inner.headers()
+    }
+
+    /** Get the limit set by `RequestParams::capture_body_on_error_limit`, if there is one. */
+    pub fn capture_body_on_error_limit(&self) -> Option<usize> {
+        self.capture_body_on_error_limit
+    }
+
+    /**
+    Read the rest of the body into a buffer.
+
+    Returns an `Error::ResponseTooLarge` once more of the body has been read than the limit set by `RequestParams::max_response_bytes`.
+    */
+    pub fn into_bytes(mut self) -> Result<Vec<u8>, Error> {
+        let mut body = Vec::new();
+        let res = self.read_to_end(&mut body);
+
+        check_body_len(self.max_response_bytes, self.read)?;
+        res.map_err(|e| ResponseError::from(ParseResponseError::from(e)))?;
+
+        Ok(body)
+    }
+
+    /**
+    Convert the response into a raw `reqwest::Response`.
+
+    Reading from the raw response isn't limited by `RequestParams::max_response_bytes`.
+    */
+    pub fn into_inner(self) -> Response {
+        self.inner
+    }
+}
+
+impl Read for SyncResponse {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = self.inner.read(buf)?;
+        self.read += len;
+
+        check_body_len(self.max_response_bytes, self.read).map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+
+        Ok(len)
     }
 }
 
@@ -140,23 +391,33 @@ impl private::Sealed for Client {}
 /** Represents a response that can be parsed into a concrete Elasticsearch response. */
 pub trait SyncFromResponse<TResponse> {
     /** Parse a response into a concrete response type. */
-    fn from_response(self, response: Response) -> Result<TResponse, Error>;
+    fn from_response(self, response: SyncResponse) -> Result<TResponse, Error>;
 }
 
 impl<TResponse: IsOk + DeserializeOwned> SyncFromResponse<TResponse> for Parse<TResponse> {
-    fn from_response(self, response: Response) -> Result<TResponse, Error> {
+    fn from_response(self, mut response: SyncResponse) -> Result<TResponse, Error> {
         let status: u16 = response.status().into();
 
-        self.from_reader(status, response).map_err(Into::into)
+        let parse = match response.capture_body_on_error_limit {
+            Some(limit) => self.capture_body_on_error_limit(limit),
+            None => self,
+        };
+
+        let res = parse.from_reader(status, &mut response);
+
+        // Reading past the limit fails parsing, so return the limit error instead of the parse error
+        check_body_len(response.max_response_bytes, response.read)?;
+
+        res.map_err(Into::into)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use reqwest::{Client, Method, RequestBuilder};
-    use reqwest::header::ContentType;
     use super::*;
     use req::*;
+    use {serve_chunked_once, serve_once, BufferBody};
 
     fn params() -> RequestParams {
         RequestParams::new("eshost:9200/path")
@@ -167,10 +428,11 @@ mod tests {
     fn expected_req(cli: &Client, method: Method, url: &str, body: Option<Vec<u8>>) -> RequestBuilder {
         let mut req = cli.request(method, url);
         {
-            req.header(ContentType::json());
-
             if let Some(body) = body {
+                req.header(ContentType::json());
                 req.body(body);
+            } else {
+                req.header(ContentLength(0));
             }
         }
 
@@ -181,6 +443,12 @@ mod tests {
         assert_eq!(format!("{:?}", expected), format!("{:?}", actual));
     }
 
+    const DELETE_FOUND: &'static str = r#"{"found":true,"_index":"idx","_type":"ty","_id":"1","_version":2,"result":"deleted","_shards":{"total":2,"successful":1,"failed":0}}"#;
+    const DELETE_NOT_FOUND: &'static str = r#"{"found":false,"_index":"idx","_type":"ty","_id":"1","_version":3,"result":"not_found","_shards":{"total":2,"successful":1,"failed":0}}"#;
+    const GET_FOUND: &'static str = r#"{"_index":"idx","_type":"ty","_id":"1","_version":1,"found":true,"_source":{"title":"a document"}}"#;
+    const GET_NOT_FOUND: &'static str = r#"{"_index":"idx","_type":"ty","_id":"1","found":false}"#;
+    const INDEX_CREATED: &'static str = r#"{"_index":"idx","_type":"ty","_id":"1","_version":1,"created":true,"result":"created","_shards":{"total":2,"successful":1,"failed":0}}"#;
+
     #[test]
     fn head_req() {
         let cli = Client::new();
@@ -249,6 +517,249 @@ mod tests {
         assert_req(expected, req);
     }
 
+    #[test]
+    fn delete_by_id_found() {
+        let (url, _) = serve_once("200 OK", DELETE_FOUND);
+        let params = RequestParams::new(url);
+
+        let deleted = Client::new()
+            .elastic_delete_by_id(&params, "idx", "ty", "1")
+            .unwrap();
+
+        assert!(deleted);
+    }
+
+    #[test]
+    fn delete_by_id_not_found() {
+        let (url, _) = serve_once("404 Not Found", DELETE_NOT_FOUND);
+        let params = RequestParams::new(url);
+
+        let deleted = Client::new()
+            .elastic_delete_by_id(&params, "idx", "ty", "1")
+            .unwrap();
+
+        assert!(!deleted);
+    }
+
+    #[test]
+    fn get_source_found() {
+        let (url, _) = serve_once("200 OK", GET_FOUND);
+        let params = RequestParams::new(url);
+
+        let doc: Option<Value> = Client::new()
+            .elastic_get_source(&params, "idx", "ty", "1")
+            .unwrap();
+
+        assert_eq!(Some(json!({ "title": "a document" })), doc);
+    }
+
+    #[test]
+    fn get_source_not_found() {
+        let (url, _) = serve_once("404 Not Found", GET_NOT_FOUND);
+        let params = RequestParams::new(url);
+
+        let doc: Option<Value> = Client::new()
+            .elastic_get_source(&params, "idx", "ty", "1")
+            .unwrap();
+
+        assert_eq!(None, doc);
+    }
+
+    #[test]
+    fn send_buffered_search_twice() {
+        let (req, body) = SearchRequest::for_index("idx", r#"{"query":{"match_all":{}}}"#).buffer_body();
+
+        for _ in 0..2 {
+            let (url, sent) = serve_once("200 OK", "{}");
+            let params = RequestParams::new(url);
+
+            Client::new().elastic_req(&params, req.clone()).unwrap();
+
+            let sent = sent.join().unwrap().to_lowercase();
+
+            assert!(sent.starts_with("post /idx/_search "));
+            assert!(sent.contains(&format!("content-length: {}\r\n", body.len())));
+        }
+    }
+
+    #[test]
+    fn index_doc_with_id() {
+        let (url, req) = serve_once("201 Created", INDEX_CREATED);
+        let params = RequestParams::new(url);
+
+        let res = Client::new()
+            .elastic_index_doc(&params, "idx", "ty", Some("1"), &json!({ "title": "a document" }))
+            .unwrap();
+
+        assert!(res.created());
+        assert!(req.join().unwrap().starts_with("PUT /idx/ty/1 "));
+    }
+
+    #[test]
+    fn index_doc_without_id() {
+        let (url, req) = serve_once("201 Created", INDEX_CREATED);
+        let params = RequestParams::new(url);
+
+        let res = Client::new()
+            .elastic_index_doc(&params, "idx", "ty", None, &json!({ "title": "a document" }))
+            .unwrap();
+
+        assert!(res.created());
+        assert!(req.join().unwrap().starts_with("POST /idx/ty "));
+    }
+
+    #[test]
+    fn response_within_limit() {
+        let (url, _) = serve_once("200 OK", GET_FOUND);
+        let params = RequestParams::new(url).max_response_bytes(GET_FOUND.len());
+
+        let res = Client::new().elastic_req(&params, PingRequest::new());
+
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn response_too_large() {
+        let (url, _) = serve_once("200 OK", GET_FOUND);
+        let params = RequestParams::new(url).max_response_bytes(10);
+
+        let res = Client::new().elastic_req(&params, PingRequest::new());
+
+        match res {
+            Err(Error::ResponseTooLarge { limit, received }) => {
+                assert_eq!(10, limit);
+                assert_eq!(GET_FOUND.len(), received);
+            }
+            res => panic!("expected a response too large error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn chunked_response_within_limit() {
+        let (url, _) = serve_chunked_once("200 OK", GET_FOUND, 16);
+        let params = RequestParams::new(url).max_response_bytes(GET_FOUND.len());
+
+        let res = Client::new().elastic_req(&params, PingRequest::new()).unwrap();
+        let res: Value = parse().from_response(res).unwrap();
+
+        assert_eq!("idx", res["_index"]);
+    }
+
+    #[test]
+    fn chunked_response_too_large() {
+        let (url, _) = serve_chunked_once("200 OK", GET_FOUND, 16);
+        let params = RequestParams::new(url).max_response_bytes(10);
+
+        let res = Client::new().elastic_req(&params, PingRequest::new()).unwrap();
+        let res: Result<Value, _> = parse().from_response(res);
+
+        match res {
+            Err(Error::ResponseTooLarge { limit, received }) => {
+                assert_eq!(10, limit);
+                assert!(received > limit);
+            }
+            res => panic!("expected a response too large error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn chunked_response_too_large_read() {
+        let (url, _) = serve_chunked_once("200 OK", GET_FOUND, 16);
+        let params = RequestParams::new(url).max_response_bytes(10);
+
+        let mut res = Client::new().elastic_req(&params, PingRequest::new()).unwrap();
+
+        let mut body = Vec::new();
+        assert!(res.read_to_end(&mut body).is_err());
+        assert!(body.len() <= 10);
+    }
+
+    const INVALID_SEARCH: &'static str = r#"{ "took": "not a number" }"#;
+
+    fn parse_invalid_search(params: RequestParams) -> Option<String> {
+        use res::SearchResponse;
+        use res::error::{ParseResponseError, ResponseError};
+
+        let res = Client::new().elastic_req(&params, PingRequest::new()).unwrap();
+        let res: Result<SearchResponse<Value>, _> = parse().from_response(res);
+
+        match res {
+            Err(Error::Response(ResponseError::Parse(ParseResponseError::Body { raw_body, .. }))) => raw_body,
+            res => panic!("expected a body parse error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn parse_error_captures_body() {
+        let (url, _) = serve_once("200 OK", INVALID_SEARCH);
+        let params = RequestParams::new(url);
+
+        assert_eq!(Some(INVALID_SEARCH.to_owned()), parse_invalid_search(params));
+    }
+
+    #[test]
+    fn parse_error_capture_body_on_error_limit() {
+        let (url, _) = serve_once("200 OK", INVALID_SEARCH);
+        let params = RequestParams::new(url).capture_body_on_error_limit(0);
+
+        assert_eq!(None, parse_invalid_search(params));
+    }
+
+    #[test]
+    fn chunked_response_into_bytes() {
+        let (url, _) = serve_chunked_once("200 OK", GET_FOUND, 16);
+        let params = RequestParams::new(url).max_response_bytes(GET_FOUND.len());
+
+        let res = Client::new().elastic_req(&params, PingRequest::new()).unwrap();
+
+        assert_eq!(GET_FOUND.as_bytes(), &*res.into_bytes().unwrap());
+    }
+
+    #[test]
+    fn chunked_response_too_large_into_bytes() {
+        let (url, _) = serve_chunked_once("200 OK", GET_FOUND, 16);
+        let params = RequestParams::new(url).max_response_bytes(10);
+
+        let res = Client::new().elastic_req(&params, PingRequest::new()).unwrap();
+
+        match res.into_bytes() {
+            Err(Error::ResponseTooLarge { limit, .. }) => assert_eq!(10, limit),
+            res => panic!("expected a response too large error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn none_body_req() {
+        let cli = Client::new();
+        let req = build_req(
+            &cli,
+            &params(),
+            SearchRequest::for_index_ty("idx", "ty", None::<Vec<u8>>),
+        );
+
+        let url = "eshost:9200/path/idx/ty/_search?pretty=true&q=*";
+
+        let expected = expected_req(&cli, Method::Post, url, None);
+
+        assert_req(expected, req);
+    }
+
+    #[test]
+    fn some_body_req() {
+        let cli = Client::new();
+        let req = build_req(
+            &cli,
+            &params(),
+            SearchRequest::for_index_ty("idx", "ty", Some(vec![])),
+        );
+
+        let url = "eshost:9200/path/idx/ty/_search?pretty=true&q=*";
+
+        let expected = expected_req(&cli, Method::Post, url, Some(vec![]));
+
+        assert_req(expected, req);
+    }
+
     #[test]
     fn file_into_body() {
         SyncBody::from(File::open("Cargo.toml").unwrap());
@@ -285,4 +796,9 @@ mod tests {
     fn json_value_into_body() {
         SyncBody::from(json!({}));
     }
+
+    #[test]
+    fn buffered_into_body() {
+        SyncBody::from(BufferedBody::from(json!({})));
+    }
 }
@@ -0,0 +1,39 @@
+/*! Helpers for integration tests that run against a live Elasticsearch node. */
+
+use super::req::{empty_body, IndicesFlushRequest, IndicesRefreshRequest, Index};
+use super::res::ShardsResponse;
+use super::{parse, Error, RequestParams, SyncElasticClient, SyncFromResponse};
+
+/** 
+Refresh and then flush an index.
+
+Documents that were just indexed aren't visible to searches until the index is refreshed.
+Call this after indexing test data and before querying it.
+The responses for the refresh and flush are returned in that order.
+
+# Examples
+
+```no_run
+# extern crate elastic_reqwest;
+# fn main() {
+let (client, params) = elastic_reqwest::sync::default().unwrap();
+
+elastic_reqwest::testing::refresh_and_flush(&client, &params, "myindex").unwrap();
+# }
+```
+*/
+pub fn refresh_and_flush<C, I>(client: &C, params: &RequestParams, index: I) -> Result<(ShardsResponse, ShardsResponse), Error>
+where
+    C: SyncElasticClient,
+    I: Into<Index<'static>>,
+{
+    let index = index.into();
+
+    let refresh = client.elastic_req(params, IndicesRefreshRequest::for_index(index.clone(), empty_body()))?;
+    let refresh = parse::<ShardsResponse>().from_response(refresh)?;
+
+    let flush = client.elastic_req(params, IndicesFlushRequest::for_index(index, empty_body()))?;
+    let flush = parse::<ShardsResponse>().from_response(flush)?;
+
+    Ok((refresh, flush))
+}
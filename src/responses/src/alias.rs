@@ -0,0 +1,83 @@
+/*!
+Response types for a [get alias request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html#alias-retrieving).
+*/
+
+use std::collections::BTreeMap;
+use std::collections::btree_map::Iter;
+use serde_json::Value;
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/** Response for a [get alias request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html#alias-retrieving).
+
+Aliases are keyed by index name and then by alias name.
+*/
+#[derive(Deserialize, Debug)]
+pub struct GetAliasResponse {
+    #[serde(flatten)] indices: BTreeMap<String, IndexAliases>,
+}
+
+impl GetAliasResponse {
+    /** Get the aliases for an index. */
+    pub fn index(&self, index: &str) -> Option<&BTreeMap<String, AliasInfo>> {
+        self.indices.get(index).map(|index| &index.aliases)
+    }
+
+    /** Get an alias on an index. */
+    pub fn alias(&self, index: &str, alias: &str) -> Option<&AliasInfo> {
+        self.index(index).and_then(|aliases| aliases.get(alias))
+    }
+
+    /** Iterate over the indices and their aliases. */
+    pub fn indices(&self) -> Iter<String, IndexAliases> {
+        self.indices.iter()
+    }
+}
+
+/** The aliases on a single index. */
+#[derive(Deserialize, Debug)]
+pub struct IndexAliases {
+    #[serde(default)] aliases: BTreeMap<String, AliasInfo>,
+}
+
+impl IndexAliases {
+    /** The aliases on the index, keyed by alias name. */
+    pub fn aliases(&self) -> &BTreeMap<String, AliasInfo> {
+        &self.aliases
+    }
+}
+
+/** The configuration of a single alias. */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct AliasInfo {
+    filter: Option<Value>,
+    search_routing: Option<String>,
+    index_routing: Option<String>,
+}
+
+impl AliasInfo {
+    /** The query documents need to match when searching through the alias. */
+    pub fn filter(&self) -> Option<&Value> {
+        self.filter.as_ref()
+    }
+
+    /** The routing value used when searching through the alias. */
+    pub fn search_routing(&self) -> Option<&str> {
+        self.search_routing.as_ref().map(|routing| routing.as_ref())
+    }
+
+    /** The routing value used when indexing through the alias. */
+    pub fn index_routing(&self) -> Option<&str> {
+        self.index_routing.as_ref().map(|routing| routing.as_ref())
+    }
+}
+
+impl IsOk for GetAliasResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
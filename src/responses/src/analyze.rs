@@ -0,0 +1,72 @@
+/*!
+Response types for an [analyze request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-analyze.html).
+*/
+
+use std::slice::Iter;
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/** Response for an [analyze request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-analyze.html). */
+#[derive(Deserialize, Debug)]
+pub struct AnalyzeResponse {
+    #[serde(default)] tokens: Vec<AnalyzeToken>,
+}
+
+impl AnalyzeResponse {
+    /** Iterate over the tokens produced by the analyzer. */
+    pub fn tokens(&self) -> Iter<AnalyzeToken> {
+        self.tokens.iter()
+    }
+
+    /** Get the text of each token produced by the analyzer. */
+    pub fn token_strings(&self) -> Vec<&str> {
+        self.tokens.iter().map(|token| token.token()).collect()
+    }
+}
+
+/** A token produced by an analyzer. */
+#[derive(Deserialize, Debug)]
+pub struct AnalyzeToken {
+    token: String,
+    start_offset: u64,
+    end_offset: u64,
+    #[serde(rename = "type")] ty: String,
+    position: u64,
+}
+
+impl AnalyzeToken {
+    /** The text of the token. */
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /** The offset of the first character of the token in the original text. */
+    pub fn start_offset(&self) -> u64 {
+        self.start_offset
+    }
+
+    /** The offset after the last character of the token in the original text. */
+    pub fn end_offset(&self) -> u64 {
+        self.end_offset
+    }
+
+    /** The type of the token, like `<ALPHANUM>`. */
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    /** The position of the token in the stream of tokens. */
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+}
+
+impl IsOk for AnalyzeResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
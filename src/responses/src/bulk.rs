@@ -163,6 +163,43 @@ impl<TIndex, TType, TId> BulkResponse<TIndex, TType, TId> {
         self.errors
     }
 
+    /**
+    Returns `true` if any bulk items failed.
+
+    This is the same as `is_err`.
+    */
+    pub fn has_errors(&self) -> bool {
+        self.errors
+    }
+
+    /** The number of bulk items that failed. */
+    pub fn error_count(&self) -> usize {
+        self.items.iter().filter(|item| item.is_err()).count()
+    }
+
+    /**
+    The fraction of bulk items that failed, between `0.0` and `1.0`.
+
+    A response without any items has an error rate of `0.0`.
+    */
+    pub fn error_rate(&self) -> f64 {
+        if self.items.is_empty() {
+            0.0
+        } else {
+            self.error_count() as f64 / self.items.len() as f64
+        }
+    }
+
+    /** Iterate through the bulk items that succeeded. */
+    pub fn successful_items(&self) -> OkItemIter<TIndex, TType, TId> {
+        OkItemIter(self.items.iter())
+    }
+
+    /** Iterate through the bulk items that failed. */
+    pub fn failed_items(&self) -> ErrorItemIter<TIndex, TType, TId> {
+        ErrorItemIter(self.items.iter())
+    }
+
     /**
     Iterate through the bulk items.
     
@@ -212,6 +249,40 @@ impl<TIndex, TType, TId> IntoIterator for BulkResponse<TIndex, TType, TId> {
     }
 }
 
+/** A borrowing iterator for the bulk items that succeeded. */
+pub struct OkItemIter<'a, TIndex: 'a, TType: 'a, TId: 'a>(Iter<'a, ItemResult<TIndex, TType, TId>>);
+
+impl<'a, TIndex: 'a, TType: 'a, TId: 'a> Iterator for OkItemIter<'a, TIndex, TType, TId> {
+    type Item = &'a OkItem<TIndex, TType, TId>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(item) = self.0.next() {
+            if let Ok(ref item) = *item {
+                return Some(item);
+            }
+        }
+
+        None
+    }
+}
+
+/** A borrowing iterator for the bulk items that failed. */
+pub struct ErrorItemIter<'a, TIndex: 'a, TType: 'a, TId: 'a>(Iter<'a, ItemResult<TIndex, TType, TId>>);
+
+impl<'a, TIndex: 'a, TType: 'a, TId: 'a> Iterator for ErrorItemIter<'a, TIndex, TType, TId> {
+    type Item = &'a ErrorItem<TIndex, TType, TId>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(item) = self.0.next() {
+            if let Err(ref item) = *item {
+                return Some(item);
+            }
+        }
+
+        None
+    }
+}
+
 /** An owning iterator for a bulk item that may have succeeded or failed. */
 pub struct ResultIntoIter<TIndex, TType, TId>(IntoIter<ItemResult<TIndex, TType, TId>>);
 
@@ -453,6 +524,7 @@ pub struct ErrorItem<TIndex = DefaultAllocatedField, TType = DefaultAllocatedFie
     index: TIndex,
     ty: TType,
     id: TId,
+    status: u16,
     err: BulkError,
 }
 
@@ -476,6 +548,20 @@ impl<TIndex, TType, TId> ErrorItem<TIndex, TType, TId> {
     pub fn id(&self) -> &TId {
         &self.id
     }
+
+    /** The http status code for this item, like `409` for a version conflict. */
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /**
+    The error returned for this item.
+
+    The error is an object with properties like `type` and `reason`.
+    */
+    pub fn error(&self) -> &Value {
+        &self.err
+    }
 }
 
 impl<TIndex, TType, TId> fmt::Display for ErrorItem<TIndex, TType, TId>
@@ -571,6 +657,7 @@ where
                 index: self.inner.index,
                 ty: self.inner.ty,
                 id: self.inner.id,
+                status: self.inner.status,
                 err: err,
             }),
             None => None,
@@ -0,0 +1,411 @@
+/*!
+Response types for [cat requests](https://www.elastic.co/guide/en/elasticsearch/reference/current/cat.html).
+
+Cat responses are a list of rows.
+Parse them as a `Vec` of the row type, like `parse::<Vec<CatIndicesRow>>()`.
+Values are returned by Elasticsearch as strings, so numeric values are parsed when they're accessed.
+
+Cat indices and cat shards responses can also be parsed as a `CatIndicesResponse` or `CatShardsResponse`, which have methods for filtering the rows.
+*/
+
+use cluster_health::ClusterHealthStatus;
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+fn parse_num(value: &Option<String>) -> Option<u64> {
+    value.as_ref().and_then(|value| value.parse().ok())
+}
+
+fn as_str(value: &Option<String>) -> Option<&str> {
+    value.as_ref().map(|value| value.as_ref())
+}
+
+/** Response for a [cat indices request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cat-indices.html). */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct CatIndicesResponse(Vec<CatIndicesRow>);
+
+impl CatIndicesResponse {
+    /** The rows in the response, one for each index. */
+    pub fn rows(&self) -> &[CatIndicesRow] {
+        &self.0
+    }
+
+    /** Convert the response into its rows. */
+    pub fn into_rows(self) -> Vec<CatIndicesRow> {
+        self.0
+    }
+
+    /**
+    Get the rows for indices with the given health.
+
+    Closed indices don't have a health, so they're never returned.
+    */
+    pub fn filter_by_health(&self, health: ClusterHealthStatus) -> Vec<&CatIndicesRow> {
+        self.0.iter().filter(|row| row.health_status() == Some(health)).collect()
+    }
+}
+
+/** A row in a response for a [cat indices request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cat-indices.html). */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct CatIndicesRow {
+    health: Option<String>,
+    status: Option<String>,
+    index: String,
+    uuid: Option<String>,
+    pri: Option<String>,
+    rep: Option<String>,
+    #[serde(rename = "docs.count")] docs_count: Option<String>,
+    #[serde(rename = "docs.deleted")] docs_deleted: Option<String>,
+    #[serde(rename = "store.size")] store_size: Option<String>,
+    #[serde(rename = "pri.store.size")] pri_store_size: Option<String>,
+}
+
+impl CatIndicesRow {
+    /** The health of the index, like `green`. */
+    pub fn health(&self) -> Option<&str> {
+        as_str(&self.health)
+    }
+
+    /** The health of the index, if it's one of `green`, `yellow` or `red`. */
+    pub fn health_status(&self) -> Option<ClusterHealthStatus> {
+        match self.health() {
+            Some("green") => Some(ClusterHealthStatus::Green),
+            Some("yellow") => Some(ClusterHealthStatus::Yellow),
+            Some("red") => Some(ClusterHealthStatus::Red),
+            _ => None,
+        }
+    }
+
+    /** Whether the index is `open` or `close`. */
+    pub fn status(&self) -> Option<&str> {
+        as_str(&self.status)
+    }
+
+    /** The name of the index. */
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /** The unique id of the index. */
+    pub fn uuid(&self) -> Option<&str> {
+        as_str(&self.uuid)
+    }
+
+    /** The number of primary shards. */
+    pub fn pri(&self) -> Option<u64> {
+        parse_num(&self.pri)
+    }
+
+    /** The number of replica shards. */
+    pub fn rep(&self) -> Option<u64> {
+        parse_num(&self.rep)
+    }
+
+    /** The number of documents in the index. */
+    pub fn docs_count(&self) -> Option<u64> {
+        parse_num(&self.docs_count)
+    }
+
+    /** The number of deleted documents in the index. */
+    pub fn docs_deleted(&self) -> Option<u64> {
+        parse_num(&self.docs_deleted)
+    }
+
+    /** The size of all shards of the index, like `5.2kb`. */
+    pub fn store_size(&self) -> Option<&str> {
+        as_str(&self.store_size)
+    }
+
+    /** The size of the primary shards of the index, like `5.2kb`. */
+    pub fn pri_store_size(&self) -> Option<&str> {
+        as_str(&self.pri_store_size)
+    }
+}
+
+/** A row in a response for a [cat nodes request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cat-nodes.html). */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct CatNodesRow {
+    ip: Option<String>,
+    #[serde(rename = "heap.percent")] heap_percent: Option<String>,
+    #[serde(rename = "ram.percent")] ram_percent: Option<String>,
+    cpu: Option<String>,
+    load_1m: Option<String>,
+    load_5m: Option<String>,
+    load_15m: Option<String>,
+    #[serde(rename = "node.role")] node_role: Option<String>,
+    master: Option<String>,
+    name: String,
+}
+
+impl CatNodesRow {
+    /** The ip address of the node. */
+    pub fn ip(&self) -> Option<&str> {
+        as_str(&self.ip)
+    }
+
+    /** The percentage of the heap that's in use. */
+    pub fn heap_percent(&self) -> Option<u64> {
+        parse_num(&self.heap_percent)
+    }
+
+    /** The percentage of memory that's in use. */
+    pub fn ram_percent(&self) -> Option<u64> {
+        parse_num(&self.ram_percent)
+    }
+
+    /** The percentage of cpu that's in use. */
+    pub fn cpu(&self) -> Option<u64> {
+        parse_num(&self.cpu)
+    }
+
+    /** The load average over the last minute. */
+    pub fn load_1m(&self) -> Option<&str> {
+        as_str(&self.load_1m)
+    }
+
+    /** The load average over the last 5 minutes. */
+    pub fn load_5m(&self) -> Option<&str> {
+        as_str(&self.load_5m)
+    }
+
+    /** The load average over the last 15 minutes. */
+    pub fn load_15m(&self) -> Option<&str> {
+        as_str(&self.load_15m)
+    }
+
+    /** The roles of the node, like `mdi` for a master eligible, data and ingest node. */
+    pub fn node_role(&self) -> Option<&str> {
+        as_str(&self.node_role)
+    }
+
+    /** Whether or not the node is the elected master. */
+    pub fn is_master(&self) -> bool {
+        as_str(&self.master) == Some("*")
+    }
+
+    /** The name of the node. */
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/** Response for a [cat shards request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cat-shards.html). */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct CatShardsResponse(Vec<CatShardsRow>);
+
+impl CatShardsResponse {
+    /** The rows in the response, one for each shard. */
+    pub fn rows(&self) -> &[CatShardsRow] {
+        &self.0
+    }
+
+    /** Convert the response into its rows. */
+    pub fn into_rows(self) -> Vec<CatShardsRow> {
+        self.0
+    }
+
+    /** Get the rows for shards that aren't allocated to a node. */
+    pub fn unassigned_shards(&self) -> Vec<&CatShardsRow> {
+        self.0.iter().filter(|row| row.state() == "UNASSIGNED").collect()
+    }
+}
+
+/** A row in a response for a [cat shards request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cat-shards.html). */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct CatShardsRow {
+    index: String,
+    shard: String,
+    prirep: String,
+    state: String,
+    docs: Option<String>,
+    store: Option<String>,
+    ip: Option<String>,
+    node: Option<String>,
+}
+
+impl CatShardsRow {
+    /** The name of the index the shard belongs to. */
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /** The number of the shard. */
+    pub fn shard(&self) -> Option<u64> {
+        self.shard.parse().ok()
+    }
+
+    /** Whether or not the shard is a primary. */
+    pub fn is_primary(&self) -> bool {
+        self.prirep == "p"
+    }
+
+    /** The state of the shard, like `STARTED` or `UNASSIGNED`. */
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    /** The number of documents in the shard. */
+    pub fn docs(&self) -> Option<u64> {
+        parse_num(&self.docs)
+    }
+
+    /** The size of the shard, like `5.2kb`. */
+    pub fn store(&self) -> Option<&str> {
+        as_str(&self.store)
+    }
+
+    /** The ip address of the node the shard is allocated to. */
+    pub fn ip(&self) -> Option<&str> {
+        as_str(&self.ip)
+    }
+
+    /** The name of the node the shard is allocated to. */
+    pub fn node(&self) -> Option<&str> {
+        as_str(&self.node)
+    }
+}
+
+/** A row in a response for a [cat health request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cat-health.html). */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct CatHealthRow {
+    epoch: Option<String>,
+    timestamp: Option<String>,
+    cluster: String,
+    status: String,
+    #[serde(rename = "node.total")] node_total: Option<String>,
+    #[serde(rename = "node.data")] node_data: Option<String>,
+    shards: Option<String>,
+    pri: Option<String>,
+    relo: Option<String>,
+    init: Option<String>,
+    unassign: Option<String>,
+    pending_tasks: Option<String>,
+    max_task_wait_time: Option<String>,
+    active_shards_percent: Option<String>,
+}
+
+impl CatHealthRow {
+    /** The time of the health check, in seconds since the epoch. */
+    pub fn epoch(&self) -> Option<u64> {
+        parse_num(&self.epoch)
+    }
+
+    /** The time of the health check, like `11:04:51`. */
+    pub fn timestamp(&self) -> Option<&str> {
+        as_str(&self.timestamp)
+    }
+
+    /** The name of the cluster. */
+    pub fn cluster(&self) -> &str {
+        &self.cluster
+    }
+
+    /** The health of the cluster, like `green`. */
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+
+    /** The number of nodes in the cluster. */
+    pub fn node_total(&self) -> Option<u64> {
+        parse_num(&self.node_total)
+    }
+
+    /** The number of data nodes in the cluster. */
+    pub fn node_data(&self) -> Option<u64> {
+        parse_num(&self.node_data)
+    }
+
+    /** The number of active shards. */
+    pub fn shards(&self) -> Option<u64> {
+        parse_num(&self.shards)
+    }
+
+    /** The number of active primary shards. */
+    pub fn pri(&self) -> Option<u64> {
+        parse_num(&self.pri)
+    }
+
+    /** The number of relocating shards. */
+    pub fn relo(&self) -> Option<u64> {
+        parse_num(&self.relo)
+    }
+
+    /** The number of initializing shards. */
+    pub fn init(&self) -> Option<u64> {
+        parse_num(&self.init)
+    }
+
+    /** The number of unassigned shards. */
+    pub fn unassign(&self) -> Option<u64> {
+        parse_num(&self.unassign)
+    }
+
+    /** The number of pending cluster tasks. */
+    pub fn pending_tasks(&self) -> Option<u64> {
+        parse_num(&self.pending_tasks)
+    }
+
+    /** How long the oldest pending task has been waiting, like `-` or `10ms`. */
+    pub fn max_task_wait_time(&self) -> Option<&str> {
+        as_str(&self.max_task_wait_time)
+    }
+
+    /** The percentage of shards that are active, like `100.0%`. */
+    pub fn active_shards_percent(&self) -> Option<&str> {
+        as_str(&self.active_shards_percent)
+    }
+}
+
+impl IsOk for Vec<CatIndicesRow> {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+impl IsOk for CatIndicesResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+impl IsOk for Vec<CatNodesRow> {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+impl IsOk for Vec<CatShardsRow> {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+impl IsOk for CatShardsResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+impl IsOk for Vec<CatHealthRow> {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
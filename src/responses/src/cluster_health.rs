@@ -0,0 +1,122 @@
+/*!
+Response types for a [cluster health request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-health.html).
+*/
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/** Response for a [cluster health request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-health.html). */
+#[derive(Deserialize, Debug)]
+pub struct ClusterHealthResponse {
+    cluster_name: String,
+    status: ClusterHealthStatus,
+    #[serde(default)] timed_out: bool,
+    number_of_nodes: u64,
+    number_of_data_nodes: u64,
+    active_primary_shards: u64,
+    active_shards: u64,
+    relocating_shards: u64,
+    initializing_shards: u64,
+    unassigned_shards: u64,
+    #[serde(default)] number_of_pending_tasks: u64,
+}
+
+impl ClusterHealthResponse {
+    /** The name of the cluster. */
+    pub fn cluster_name(&self) -> &str {
+        &self.cluster_name
+    }
+
+    /** The health of the cluster. */
+    pub fn status(&self) -> ClusterHealthStatus {
+        self.status
+    }
+
+    /**
+    Whether or not the cluster is accepting writes.
+
+    This is `true` when the status is green or yellow.
+    */
+    pub fn is_healthy(&self) -> bool {
+        match self.status {
+            ClusterHealthStatus::Green | ClusterHealthStatus::Yellow => true,
+            ClusterHealthStatus::Red => false,
+        }
+    }
+
+    /** Whether or not all shards are allocated. */
+    pub fn is_green(&self) -> bool {
+        self.status == ClusterHealthStatus::Green
+    }
+
+    /** Whether or not some primary shards are unallocated. */
+    pub fn is_red(&self) -> bool {
+        self.status == ClusterHealthStatus::Red
+    }
+
+    /** Whether or not the request timed out before the cluster reached the requested status. */
+    pub fn timed_out(&self) -> bool {
+        self.timed_out
+    }
+
+    /** The number of nodes in the cluster. */
+    pub fn number_of_nodes(&self) -> u64 {
+        self.number_of_nodes
+    }
+
+    /** The number of data nodes in the cluster. */
+    pub fn number_of_data_nodes(&self) -> u64 {
+        self.number_of_data_nodes
+    }
+
+    /** The number of active primary shards. */
+    pub fn active_primary_shards(&self) -> u64 {
+        self.active_primary_shards
+    }
+
+    /** The number of active primary and replica shards. */
+    pub fn active_shards(&self) -> u64 {
+        self.active_shards
+    }
+
+    /** The number of relocating shards. */
+    pub fn relocating_shards(&self) -> u64 {
+        self.relocating_shards
+    }
+
+    /** The number of initializing shards. */
+    pub fn initializing_shards(&self) -> u64 {
+        self.initializing_shards
+    }
+
+    /** The number of unassigned shards. */
+    pub fn unassigned_shards(&self) -> u64 {
+        self.unassigned_shards
+    }
+
+    /** The number of cluster-level changes that haven't been executed yet. */
+    pub fn number_of_pending_tasks(&self) -> u64 {
+        self.number_of_pending_tasks
+    }
+}
+
+/** The health of a cluster. */
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClusterHealthStatus {
+    /** All shards are allocated. */
+    #[serde(rename = "green")] Green,
+    /** All primary shards are allocated but some replicas aren't. */
+    #[serde(rename = "yellow")] Yellow,
+    /** Some primary shards aren't allocated. */
+    #[serde(rename = "red")] Red,
+}
+
+impl IsOk for ClusterHealthResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            // A health request that times out waiting for a status still returns the current health
+            200...299 | 408 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
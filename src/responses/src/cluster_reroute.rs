@@ -0,0 +1,143 @@
+/*!
+Response types for a [cluster reroute request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-reroute.html).
+*/
+
+use std::collections::BTreeMap;
+use std::collections::btree_map::Iter;
+use serde_json::{self, Value};
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/** Response for a [cluster reroute request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-reroute.html). */
+#[derive(Deserialize, Debug)]
+pub struct ClusterRerouteResponse {
+    #[serde(default)] acknowledged: bool,
+    #[serde(default)] state: Value,
+}
+
+impl ClusterRerouteResponse {
+    /**
+    Whether or not the request was acknowledged.
+
+    This doesn't necessarily mean the request has been fully processed.
+    */
+    pub fn acknowledged(&self) -> bool {
+        self.acknowledged
+    }
+
+    /**
+    The cluster state after the reroute.
+
+    The state is returned as it would be after the commands were applied, even for a dry run.
+    */
+    pub fn state(&self) -> &Value {
+        &self.state
+    }
+
+    /** Parse the routing table from the cluster state, if it was returned. */
+    pub fn routing_table(&self) -> Option<RoutingTable> {
+        self.state
+            .get("routing_table")
+            .and_then(|routing_table| serde_json::from_value(routing_table.clone()).ok())
+    }
+
+    /**
+    The number of shard copies that are being moved or allocated by the reroute.
+
+    These are shards in the routing table that are `RELOCATING` or `INITIALIZING`.
+    */
+    pub fn rerouted_shard_count(&self) -> usize {
+        self.routing_table()
+            .map(|routing_table| routing_table.shards().filter(|shard| shard.is_rerouted()).count())
+            .unwrap_or(0)
+    }
+}
+
+/** The shards allocated for each index in the cluster. */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct RoutingTable {
+    #[serde(default)] indices: BTreeMap<String, IndexRoutingTable>,
+}
+
+impl RoutingTable {
+    /** Iterate over the indices and their shards. */
+    pub fn indices(&self) -> Iter<String, IndexRoutingTable> {
+        self.indices.iter()
+    }
+
+    /** Iterate over every shard copy in the routing table. */
+    pub fn shards<'a>(&'a self) -> Box<Iterator<Item = &'a ShardRouting> + 'a> {
+        Box::new(self.indices.values().flat_map(|index| index.shards.values().flat_map(|copies| copies.iter())))
+    }
+}
+
+/** The shards allocated for a single index, keyed by shard number. */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct IndexRoutingTable {
+    #[serde(default)] shards: BTreeMap<String, Vec<ShardRouting>>,
+}
+
+impl IndexRoutingTable {
+    /** Iterate over the shard numbers and their primary and replica copies. */
+    pub fn shards(&self) -> Iter<String, Vec<ShardRouting>> {
+        self.shards.iter()
+    }
+}
+
+/** The allocation of a single primary or replica shard copy. */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct ShardRouting {
+    index: String,
+    shard: u32,
+    state: String,
+    primary: bool,
+    node: Option<String>,
+    relocating_node: Option<String>,
+}
+
+impl ShardRouting {
+    /** The index the shard belongs to. */
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /** The shard number. */
+    pub fn shard(&self) -> u32 {
+        self.shard
+    }
+
+    /** The state of the shard, like `STARTED` or `RELOCATING`. */
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    /** Whether or not this is the primary copy of the shard. */
+    pub fn primary(&self) -> bool {
+        self.primary
+    }
+
+    /** The id of the node the shard is allocated to. */
+    pub fn node(&self) -> Option<&str> {
+        self.node.as_ref().map(|node| node.as_ref())
+    }
+
+    /** The id of the node the shard is moving to, if it's relocating. */
+    pub fn relocating_node(&self) -> Option<&str> {
+        self.relocating_node.as_ref().map(|node| node.as_ref())
+    }
+
+    /** Whether or not the shard is being moved or allocated. */
+    pub fn is_rerouted(&self) -> bool {
+        self.state == "RELOCATING" || self.state == "INITIALIZING"
+    }
+}
+
+impl IsOk for ClusterRerouteResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
@@ -2,6 +2,7 @@
 Response types for a standard command.
 */
 
+use common::Shards;
 use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
 use error::*;
 
@@ -30,3 +31,70 @@ impl IsOk for CommandResponse {
         }
     }
 }
+
+/** An acknowledgement response for commands that also wait for shards to become active.
+
+Commands like opening, closing, shrinking or splitting an index return this response.
+*/
+#[derive(Deserialize, Debug, Clone)]
+pub struct AcknowledgedResponse {
+    acknowledged: bool,
+    #[serde(default)] shards_acknowledged: bool,
+}
+
+impl AcknowledgedResponse {
+    /** 
+    Whether or not the request was acknowledged.
+    
+    This doesn't necessarily mean the request has been fully processed.
+    */
+    pub fn acknowledged(&self) -> bool {
+        self.acknowledged
+    }
+
+    /** 
+    Whether or not the requisite number of shard copies were started before the request timed out.
+
+    A request can be acknowledged even if its shards weren't started in time.
+    */
+    pub fn shards_acknowledged(&self) -> bool {
+        self.shards_acknowledged
+    }
+}
+
+impl IsOk for AcknowledgedResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+/** A response for commands that are broadcast to shards instead of being acknowledged.
+
+Commands like refreshing, flushing or force merging an index return this response.
+*/
+#[derive(Deserialize, Debug, Clone)]
+pub struct ShardsResponse {
+    #[serde(rename = "_shards")] shards: Shards,
+}
+
+impl ShardsResponse {
+    /** Shards metadata for the request. */
+    pub fn shards(&self) -> &Shards {
+        &self.shards
+    }
+}
+
+/** Response for a [force merge request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-forcemerge.html). */
+pub type ForcemergeResponse = ShardsResponse;
+
+impl IsOk for ShardsResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
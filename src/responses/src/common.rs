@@ -26,10 +26,17 @@ impl Shards {
     }
 }
 
-#[derive(Deserialize, Debug)]
-pub(crate) enum DocumentResult {
+/** The outcome of a write to a single document. */
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteResult {
+    /** A new document was created. */
+    #[serde(rename = "created")] Created,
+    /** The document was deleted. */
     #[serde(rename = "deleted")] Deleted,
+    /** An existing document was updated. */
     #[serde(rename = "updated")] Updated,
+    /** The document wasn't found. */
     #[serde(rename = "not_found")] NotFound,
+    /** The document wasn't changed. */
     #[serde(rename = "noop")] NoOp,
 }
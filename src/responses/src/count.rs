@@ -0,0 +1,35 @@
+/*!
+Response types for a [count request](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-count.html).
+*/
+
+use common::Shards;
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/** Response for a [count request](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-count.html). */
+#[derive(Deserialize, Debug)]
+pub struct CountResponse {
+    count: u64,
+    #[serde(rename = "_shards")] shards: Shards,
+}
+
+impl CountResponse {
+    /** The number of documents matching the query. */
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /** Shards metadata for the request. */
+    pub fn shards(&self) -> &Shards {
+        &self.shards
+    }
+}
+
+impl IsOk for CountResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
@@ -3,7 +3,7 @@ Response types for a [delete document request](https://www.elastic.co/guide/en/e
 */
 
 use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
-use common::DocumentResult;
+use common::WriteResult;
 use error::*;
 
 /** Response for a [delete document request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-delete.html). */
@@ -15,7 +15,7 @@ pub struct DeleteResponse {
     #[serde(rename = "_version")] version: Option<u32>,
     #[serde(rename = "_routing")] routing: Option<String>,
     found: bool,
-    result: DocumentResult,
+    result: WriteResult,
 }
 
 impl DeleteResponse {
@@ -27,7 +27,7 @@ impl DeleteResponse {
     /** Whether or not the document was deleted. */
     pub fn deleted(&self) -> bool {
         match self.result {
-            DocumentResult::Deleted => true,
+            WriteResult::Deleted => true,
             _ => false,
         }
     }
@@ -0,0 +1,83 @@
+/*!
+Response types for an [eql search request](https://www.elastic.co/guide/en/elasticsearch/reference/current/eql-search-api.html).
+*/
+
+use std::slice::Iter;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/**
+Response for an [eql search request](https://www.elastic.co/guide/en/elasticsearch/reference/current/eql-search-api.html).
+
+Event sources are deserialised into `T`, which defaults to `serde_json::Value`.
+*/
+#[derive(Deserialize, Debug)]
+pub struct EqlSearchResponse<T = Value> {
+    took: u64,
+    #[serde(default)] timed_out: bool,
+    hits: EqlHits<T>,
+}
+
+#[derive(Deserialize, Debug)]
+struct EqlHits<T> {
+    #[serde(default = "Vec::new")] events: Vec<EqlEvent<T>>,
+}
+
+impl<T> EqlSearchResponse<T> {
+    /** Time in milliseconds it took for Elasticsearch to process the request. */
+    pub fn took(&self) -> u64 {
+        self.took
+    }
+
+    /** Whether or not the request timed out before completing. */
+    pub fn timed_out(&self) -> bool {
+        self.timed_out
+    }
+
+    /** Iterate over the events matched by the query. */
+    pub fn events(&self) -> Iter<EqlEvent<T>> {
+        self.hits.events.iter()
+    }
+
+    /** Get the ids of the events matched by the query. */
+    pub fn event_ids(&self) -> Vec<&str> {
+        self.hits.events.iter().map(|event| event.id()).collect()
+    }
+}
+
+/** An event matched by an eql query. */
+#[derive(Deserialize, Debug)]
+pub struct EqlEvent<T> {
+    #[serde(rename = "_index")] index: String,
+    #[serde(rename = "_id")] id: String,
+    #[serde(rename = "_source")] source: Option<T>,
+}
+
+impl<T> EqlEvent<T> {
+    /** The index the event belongs to. */
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /** The id of the event. */
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /** The source document of the event. */
+    pub fn document(&self) -> Option<&T> {
+        self.source.as_ref()
+    }
+}
+
+impl<T: DeserializeOwned> IsOk for EqlSearchResponse<T> {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
@@ -18,6 +18,27 @@ quick_error! {
         Io(err: IoError) {
             from()
         }
+        /**
+        The response body couldn't be deserialised into the response type.
+
+        The `line` and `column` are where deserialisation failed.
+        The `raw_body` is only included if it's no larger than the limit set by `Parse::capture_body_on_error_limit`.
+        */
+        Body { message: String, line: usize, column: usize, raw_body: Option<String> } {
+            description("response body parse error")
+            display("response body parse error: '{}' on line: {}, col: {}", message, line, column)
+        }
+    }
+}
+
+impl ParseResponseError {
+    pub(crate) fn body(err: JsonError, raw_body: Option<String>) -> Self {
+        ParseResponseError::Body {
+            message: err.to_string(),
+            line: err.line(),
+            column: err.column(),
+            raw_body: raw_body,
+        }
     }
 }
 
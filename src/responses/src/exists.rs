@@ -0,0 +1,91 @@
+/*!
+Response types for [document exists requests](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-get.html).
+*/
+
+use std::collections::BTreeMap;
+use std::collections::btree_map::Iter;
+use serde::de::{Deserialize, Deserializer};
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/** Response for a [document exists request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-get.html).
+
+The request is sent with the `HEAD` method, so the response doesn't have a body.
+A status of `200` means the document exists and `404` means it doesn't.
+*/
+#[derive(Deserialize, Debug)]
+pub struct ExistsResponse {
+    exists: bool,
+}
+
+impl ExistsResponse {
+    /** Whether or not the document exists. */
+    pub fn exists(&self) -> bool {
+        self.exists
+    }
+}
+
+/** Response for a bulk document exists request.
+
+The request is sent as a [multi get request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-multi-get.html) without sources, and only the `found` field of each document is read.
+*/
+#[derive(Debug)]
+pub struct BulkExistsResponse {
+    docs: BTreeMap<String, bool>,
+}
+
+impl BulkExistsResponse {
+    /** Whether or not the document with the given id exists, or `None` if the id wasn't requested. */
+    pub fn exists(&self, id: &str) -> Option<bool> {
+        self.docs.get(id).cloned()
+    }
+
+    /** Iterate over the ids and whether or not their documents exist. */
+    pub fn iter(&self) -> Iter<String, bool> {
+        self.docs.iter()
+    }
+}
+
+impl<'de> Deserialize<'de> for BulkExistsResponse {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct MgetDocs {
+            docs: Vec<MgetDoc>,
+        }
+
+        #[derive(Deserialize)]
+        struct MgetDoc {
+            #[serde(rename = "_id")] id: String,
+            #[serde(default)] found: bool,
+        }
+
+        let mget = MgetDocs::deserialize(deserializer)?;
+
+        Ok(BulkExistsResponse {
+            docs: mget.docs.into_iter().map(|doc| (doc.id, doc.found)).collect(),
+        })
+    }
+}
+
+impl IsOk for ExistsResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(json!({ "exists": true }))),
+            404 => Ok(MaybeOkResponse::ok(json!({ "exists": false }))),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+impl IsOk for BulkExistsResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
@@ -0,0 +1,95 @@
+/*!
+Response types for an [explain request](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-explain.html).
+*/
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+use std::slice::Iter;
+
+/** Response for an [explain request](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-explain.html). */
+#[derive(Deserialize, Debug)]
+pub struct ExplainResponse {
+    #[serde(rename = "_index")] index: String,
+    #[serde(rename = "_type")] ty: String,
+    #[serde(rename = "_id")] id: String,
+    matched: bool,
+    explanation: Option<ExplanationDetail>,
+}
+
+impl ExplainResponse {
+    /** Whether or not the query matched the document. */
+    pub fn matched(&self) -> bool {
+        self.matched
+    }
+
+    /** 
+    The root of the score explanation tree.
+    
+    This will be `None` if the document doesn't exist.
+    */
+    pub fn explanation(&self) -> Option<&ExplanationDetail> {
+        self.explanation.as_ref()
+    }
+
+    /** The index for the document. */
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /** The type of the document. */
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    /** The id of the document. */
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+/** A single node in the score explanation tree. */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct ExplanationDetail {
+    value: f64,
+    description: String,
+    #[serde(default)] details: Vec<ExplanationDetail>,
+}
+
+impl ExplanationDetail {
+    /** The score contributed by this node. */
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /** A description of how the value was calculated. */
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /** Iterate over the nodes that contributed to this node's value. */
+    pub fn details(&self) -> Iter<ExplanationDetail> {
+        self.details.iter()
+    }
+}
+
+impl IsOk for ExplainResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            404 => {
+                // If we get a 404, it could be an IndexNotFound error or ok
+                // Check if the response contains a root 'error' node
+                let (maybe_err, body) = body.body()?;
+
+                let is_ok = maybe_err
+                    .as_object()
+                    .and_then(|maybe_err| maybe_err.get("error"))
+                    .is_none();
+
+                Ok(MaybeOkResponse::new(is_ok, body))
+            }
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
@@ -0,0 +1,85 @@
+/*!
+Response types for a [field capabilities request](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-field-caps.html).
+*/
+
+use std::collections::BTreeMap;
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/** Response for a [field capabilities request](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-field-caps.html). */
+#[derive(Deserialize, Debug)]
+pub struct FieldCapsResponse {
+    fields: BTreeMap<String, BTreeMap<String, FieldCapability>>,
+}
+
+impl FieldCapsResponse {
+    /** Get the capabilities of a field, keyed by the field type.
+
+    A field may have more than one type if it's mapped differently across the requested indices.
+    */
+    pub fn field(&self, name: &str) -> Option<&BTreeMap<String, FieldCapability>> {
+        self.fields.get(name)
+    }
+
+    /** Get a reference to all fields and their capabilities. */
+    pub fn fields(&self) -> &BTreeMap<String, BTreeMap<String, FieldCapability>> {
+        &self.fields
+    }
+}
+
+/** The capabilities of a field for a single mapped type. */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct FieldCapability {
+    #[serde(rename = "type")]
+    type_: String,
+    searchable: bool,
+    aggregatable: bool,
+    indices: Option<Vec<String>>,
+    non_searchable_indices: Option<Vec<String>>,
+    non_aggregatable_indices: Option<Vec<String>>,
+}
+
+impl FieldCapability {
+    /** The mapped type of the field. */
+    pub fn type_(&self) -> &str {
+        &self.type_
+    }
+
+    /** Whether the field is indexed for search on all indices. */
+    pub fn searchable(&self) -> bool {
+        self.searchable
+    }
+
+    /** Whether the field can be aggregated on all indices. */
+    pub fn aggregatable(&self) -> bool {
+        self.aggregatable
+    }
+
+    /** The indices the field has this type in.
+
+    This is `None` if all requested indices map the field to the same type.
+    */
+    pub fn indices(&self) -> Option<&[String]> {
+        self.indices.as_ref().map(|indices| &indices[..])
+    }
+
+    /** The indices the field isn't searchable in, if it's only searchable in some. */
+    pub fn non_searchable_indices(&self) -> Option<&[String]> {
+        self.non_searchable_indices.as_ref().map(|indices| &indices[..])
+    }
+
+    /** The indices the field isn't aggregatable in, if it's only aggregatable in some. */
+    pub fn non_aggregatable_indices(&self) -> Option<&[String]> {
+        self.non_aggregatable_indices.as_ref().map(|indices| &indices[..])
+    }
+}
+
+impl IsOk for FieldCapsResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
@@ -3,13 +3,18 @@ Response types for a [get document request](https://www.elastic.co/guide/en/elas
 */
 
 use serde::de::DeserializeOwned;
+use serde_json::Value;
 
 use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
 use error::*;
 
-/** Response for a [get document request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-get.html). */
+/** Response for a [get document request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-get.html).
+
+The `_source` is deserialised into `T`, which defaults to `serde_json::Value`.
+If the document wasn't found then there's no source.
+*/
 #[derive(Deserialize, Debug)]
-pub struct GetResponse<T> {
+pub struct GetResponse<T = Value> {
     #[serde(rename = "_index")] index: String,
     #[serde(rename = "_type")] ty: String,
     #[serde(rename = "_id")] id: String,
@@ -30,11 +35,29 @@ impl<T> GetResponse<T> {
         self.source
     }
 
+    /**
+    Convert the response into the source document.
+
+    This is the same as `into_document`.
+    */
+    pub fn into_source(self) -> Option<T> {
+        self.source
+    }
+
     /** Whether or not a matching document was found. */
     pub fn found(&self) -> bool {
         self.found
     }
 
+    /**
+    Whether or not a matching document exists.
+
+    This is the same as `found`.
+    */
+    pub fn exists(&self) -> bool {
+        self.found
+    }
+
     /** The index for the document. */
     pub fn index(&self) -> &str {
         &self.index
@@ -54,6 +77,11 @@ impl<T> GetResponse<T> {
     pub fn version(&self) -> Option<u32> {
         self.version.clone()
     }
+
+    /** The routing value the document was indexed with, if it was given one. */
+    pub fn routing(&self) -> Option<&str> {
+        self.routing.as_ref().map(|routing| routing.as_ref())
+    }
 }
 
 impl<T: DeserializeOwned> IsOk for GetResponse<T> {
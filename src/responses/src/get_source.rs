@@ -0,0 +1,53 @@
+/*!
+Response types for a [get document source request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-get.html#_source).
+*/
+
+use serde::de::{Deserialize, Deserializer};
+use serde_json::Value;
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/** Response for a [get document source request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-get.html#_source).
+
+The body is just the source document, without the metadata returned by a get request.
+The document is deserialised into `T`, which defaults to `serde_json::Value`.
+If the document wasn't found then the response is an API error.
+*/
+#[derive(Debug)]
+pub struct GetSourceResponse<T = Value> {
+    source: T,
+}
+
+impl<T> GetSourceResponse<T> {
+    /** Get a reference to the source document. */
+    pub fn document(&self) -> &T {
+        &self.source
+    }
+
+    /** Convert the response into the source document. */
+    pub fn into_document(self) -> T {
+        self.source
+    }
+}
+
+impl<'de, T> Deserialize<'de> for GetSourceResponse<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(|source| GetSourceResponse { source: source })
+    }
+}
+
+impl<T> IsOk for GetSourceResponse<T> {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
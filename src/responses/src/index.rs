@@ -2,7 +2,7 @@
 Response types for an [index document request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-index_.html).
 */
 
-use common::Shards;
+use common::{Shards, WriteResult};
 use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
 use error::*;
 
@@ -13,7 +13,10 @@ pub struct IndexResponse {
     #[serde(rename = "_type")] ty: String,
     #[serde(rename = "_id")] id: String,
     #[serde(rename = "_version")] version: Option<u32>,
-    created: bool,
+    #[serde(rename = "_seq_no")] seq_no: Option<u64>,
+    #[serde(rename = "_primary_term")] primary_term: Option<u64>,
+    #[serde(default)] created: bool,
+    result: Option<WriteResult>,
     #[serde(rename = "_shards")] shards: Shards,
 }
 
@@ -25,7 +28,34 @@ impl IndexResponse {
 
     /** Whether or not a matching document was created. */
     pub fn created(&self) -> bool {
-        self.created
+        self.created || self.result == Some(WriteResult::Created)
+    }
+
+    /**
+    Whether or not a new document was created.
+
+    This is the same as `created`.
+    */
+    pub fn was_created(&self) -> bool {
+        self.created()
+    }
+
+    /**
+    Whether or not an existing document was updated.
+
+    Versions of Elasticsearch before `5.0` don't return a result, so they're never updated.
+    */
+    pub fn was_updated(&self) -> bool {
+        self.result == Some(WriteResult::Updated)
+    }
+
+    /**
+    Whether the document was created or updated.
+
+    Versions of Elasticsearch before `5.0` don't return a result.
+    */
+    pub fn result(&self) -> Option<WriteResult> {
+        self.result
     }
 
     /** The index for the document. */
@@ -47,6 +77,25 @@ impl IndexResponse {
     pub fn version(&self) -> Option<u32> {
         self.version.clone()
     }
+
+    /**
+    The sequence number of the write.
+
+    Use it with the primary term to make sure the document hasn't changed before writing it again.
+    Versions of Elasticsearch before `6.0` don't return a sequence number.
+    */
+    pub fn seq_no(&self) -> Option<u64> {
+        self.seq_no
+    }
+
+    /**
+    The primary term of the write.
+
+    Versions of Elasticsearch before `6.0` don't return a primary term.
+    */
+    pub fn primary_term(&self) -> Option<u64> {
+        self.primary_term
+    }
 }
 
 impl IsOk for IndexResponse {
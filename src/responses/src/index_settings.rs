@@ -0,0 +1,63 @@
+/*!
+Response types for a [get index settings request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-settings.html).
+*/
+
+use std::collections::BTreeMap;
+use std::collections::btree_map::Iter;
+use serde_json::Value;
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/** Response for a [get index settings request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-settings.html).
+
+Settings are keyed by index name.
+*/
+#[derive(Deserialize, Debug)]
+pub struct IndexSettingsResponse {
+    #[serde(flatten)] indices: BTreeMap<String, IndexSettings>,
+}
+
+impl IndexSettingsResponse {
+    /** Get the settings for an index. */
+    pub fn index(&self, index: &str) -> Option<&IndexSettings> {
+        self.indices.get(index)
+    }
+
+    /** Iterate over the indices and their settings. */
+    pub fn indices(&self) -> Iter<String, IndexSettings> {
+        self.indices.iter()
+    }
+}
+
+/** The settings for a single index. */
+#[derive(Deserialize, Debug)]
+pub struct IndexSettings {
+    settings: Value,
+}
+
+impl IndexSettings {
+    /** 
+    The full settings tree.
+
+    Settings are returned as nested objects, like `{ "index": { "number_of_shards": "5" } }`.
+    Elasticsearch returns setting values as strings.
+    */
+    pub fn settings(&self) -> &Value {
+        &self.settings
+    }
+
+    /** The settings under `index`, like `number_of_shards` and `refresh_interval`. */
+    pub fn index_settings(&self) -> Option<&Value> {
+        self.settings.get("index")
+    }
+}
+
+impl IsOk for IndexSettingsResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
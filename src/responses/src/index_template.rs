@@ -0,0 +1,82 @@
+/*!
+Response types for a [get index template request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html).
+*/
+
+use std::collections::BTreeMap;
+use std::collections::btree_map::Iter;
+use serde_json::Value;
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/** Response for a [get index template request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-templates.html).
+
+Templates are keyed by their name.
+*/
+#[derive(Deserialize, Debug)]
+pub struct IndexTemplateResponse {
+    #[serde(flatten)] templates: BTreeMap<String, IndexTemplate>,
+}
+
+impl IndexTemplateResponse {
+    /** Get a template by name. */
+    pub fn template(&self, name: &str) -> Option<&IndexTemplate> {
+        self.templates.get(name)
+    }
+
+    /** Iterate over the templates and their names. */
+    pub fn templates(&self) -> Iter<String, IndexTemplate> {
+        self.templates.iter()
+    }
+
+    /** Get the names of the templates, in sorted order. */
+    pub fn template_names(&self) -> Vec<&str> {
+        self.templates.keys().map(|name| name.as_ref()).collect()
+    }
+}
+
+/** The metadata for an index template. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct IndexTemplate {
+    #[serde(default)] index_patterns: Vec<String>,
+    #[serde(default)] order: i32,
+    #[serde(default)] settings: Value,
+    #[serde(default)] mappings: Value,
+    #[serde(default)] aliases: Value,
+}
+
+impl IndexTemplate {
+    /** The index name patterns the template applies to. */
+    pub fn index_patterns(&self) -> &[String] {
+        &self.index_patterns
+    }
+
+    /** The order the template is merged in. */
+    pub fn order(&self) -> i32 {
+        self.order
+    }
+
+    /** The index settings. */
+    pub fn settings(&self) -> &Value {
+        &self.settings
+    }
+
+    /** The type mappings. */
+    pub fn mappings(&self) -> &Value {
+        &self.mappings
+    }
+
+    /** The aliases added to matching indices. */
+    pub fn aliases(&self) -> &Value {
+        &self.aliases
+    }
+}
+
+impl IsOk for IndexTemplateResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
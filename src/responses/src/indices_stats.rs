@@ -0,0 +1,113 @@
+/*!
+Response types for an [indices stats request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-stats.html).
+*/
+
+use std::collections::BTreeMap;
+use std::collections::btree_map::Iter;
+
+use common::Shards;
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/** Response for an [indices stats request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-stats.html). */
+#[derive(Deserialize, Debug)]
+pub struct IndicesStatsResponse {
+    #[serde(rename = "_shards")] shards: Shards,
+    #[serde(rename = "_all")] all: IndexStats,
+    #[serde(default)] indices: BTreeMap<String, IndexStats>,
+}
+
+impl IndicesStatsResponse {
+    /** Shards metadata for the request. */
+    pub fn shards(&self) -> &Shards {
+        &self.shards
+    }
+
+    /** The stats aggregated over all of the requested indices. */
+    pub fn all(&self) -> &IndexStats {
+        &self.all
+    }
+
+    /** Get the stats for an index. */
+    pub fn index(&self, index: &str) -> Option<&IndexStats> {
+        self.indices.get(index)
+    }
+
+    /** Iterate over the indices and their stats. */
+    pub fn indices(&self) -> Iter<String, IndexStats> {
+        self.indices.iter()
+    }
+
+    /**
+    The number of documents across all of the requested indices.
+
+    Only primary shards are counted, so documents aren't counted again for each replica.
+    Nested documents are counted individually.
+    */
+    pub fn total_doc_count(&self) -> u64 {
+        self.indices.values().map(|index| index.primaries().doc_count()).sum()
+    }
+}
+
+/** The stats for a single index. */
+#[derive(Deserialize, Debug)]
+pub struct IndexStats {
+    primaries: Stats,
+    total: Stats,
+}
+
+impl IndexStats {
+    /** The stats for the primary shards. */
+    pub fn primaries(&self) -> &Stats {
+        &self.primaries
+    }
+
+    /** The stats for the primary and replica shards. */
+    pub fn total(&self) -> &Stats {
+        &self.total
+    }
+}
+
+/** A set of stats for some shards. */
+#[derive(Deserialize, Debug)]
+pub struct Stats {
+    docs: Option<DocsStats>,
+    store: Option<StoreStats>,
+}
+
+impl Stats {
+    /** The number of documents, or `0` if document stats weren't requested. */
+    pub fn doc_count(&self) -> u64 {
+        self.docs.as_ref().map(|docs| docs.count).unwrap_or(0)
+    }
+
+    /** The number of deleted documents that haven't been merged away yet. */
+    pub fn deleted_doc_count(&self) -> u64 {
+        self.docs.as_ref().map(|docs| docs.deleted).unwrap_or(0)
+    }
+
+    /** The size of the store in bytes, if store stats were requested. */
+    pub fn store_size_in_bytes(&self) -> Option<u64> {
+        self.store.as_ref().map(|store| store.size_in_bytes)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct DocsStats {
+    count: u64,
+    deleted: u64,
+}
+
+#[derive(Deserialize, Debug)]
+struct StoreStats {
+    size_in_bytes: u64,
+}
+
+impl IsOk for IndicesStatsResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
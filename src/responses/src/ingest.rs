@@ -0,0 +1,163 @@
+/*!
+Response types for [ingest pipeline requests](https://www.elastic.co/guide/en/elasticsearch/reference/current/ingest-apis.html).
+*/
+
+use std::collections::BTreeMap;
+use std::slice::Iter;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/** Response for a [get pipeline request](https://www.elastic.co/guide/en/elasticsearch/reference/current/get-pipeline-api.html).
+
+Pipelines are keyed by their id.
+*/
+#[derive(Deserialize, Debug)]
+pub struct GetPipelineResponse {
+    #[serde(flatten)] pipelines: BTreeMap<String, Pipeline>,
+}
+
+impl GetPipelineResponse {
+    /** Get a pipeline by id. */
+    pub fn pipeline(&self, id: &str) -> Option<&Pipeline> {
+        self.pipelines.get(id)
+    }
+
+    /** Get a reference to all pipelines. */
+    pub fn pipelines(&self) -> &BTreeMap<String, Pipeline> {
+        &self.pipelines
+    }
+}
+
+/** The definition of an ingest pipeline. */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Pipeline {
+    description: Option<String>,
+    #[serde(default)] processors: Vec<Value>,
+}
+
+impl Pipeline {
+    /** The description of the pipeline. */
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_ref().map(|description| description.as_ref())
+    }
+
+    /** The processors the pipeline runs, in order. */
+    pub fn processors(&self) -> &[Value] {
+        &self.processors
+    }
+}
+
+impl IsOk for GetPipelineResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            404 => {
+                // A missing pipeline is returned as an empty object
+                // Check if the response contains a root 'error' node
+                let (maybe_err, body) = body.body()?;
+
+                let is_ok = maybe_err
+                    .as_object()
+                    .and_then(|maybe_err| maybe_err.get("error"))
+                    .is_none();
+
+                Ok(MaybeOkResponse::new(is_ok, body))
+            }
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+/** Response for a [simulate pipeline request](https://www.elastic.co/guide/en/elasticsearch/reference/current/simulate-pipeline-api.html). */
+#[derive(Deserialize, Debug)]
+pub struct SimulatePipelineResponse<T> {
+    docs: Vec<SimulatedDocument<T>>,
+}
+
+impl<T> SimulatePipelineResponse<T> {
+    /** Iterate over the results for each simulated document, in the order they were sent. */
+    pub fn docs(&self) -> Iter<SimulatedDocument<T>> {
+        self.docs.iter()
+    }
+}
+
+/** The result of running a single document through a pipeline. */
+#[derive(Deserialize, Debug)]
+pub struct SimulatedDocument<T> {
+    doc: Option<IngestDocument<T>>,
+    error: Option<Value>,
+}
+
+impl<T> SimulatedDocument<T> {
+    /** Get the transformed document, if the pipeline succeeded. */
+    pub fn doc(&self) -> Option<&IngestDocument<T>> {
+        self.doc.as_ref()
+    }
+
+    /** Get the error, if the pipeline failed. */
+    pub fn error(&self) -> Option<&Value> {
+        self.error.as_ref()
+    }
+}
+
+/** A document that has been transformed by a pipeline. */
+#[derive(Deserialize, Debug)]
+pub struct IngestDocument<T> {
+    #[serde(rename = "_index")] index: String,
+    #[serde(rename = "_type")] ty: String,
+    #[serde(rename = "_id")] id: String,
+    #[serde(rename = "_source")] source: T,
+    #[serde(rename = "_ingest")] ingest: Option<IngestMetadata>,
+}
+
+impl<T> IngestDocument<T> {
+    /** Get a reference to the transformed source document. */
+    pub fn document(&self) -> &T {
+        &self.source
+    }
+
+    /** The index for the document. */
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /** The type of the document. */
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    /** The id of the document. */
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /** The ingest metadata for the document. */
+    pub fn ingest(&self) -> Option<&IngestMetadata> {
+        self.ingest.as_ref()
+    }
+}
+
+/** Metadata added to a document while it was being ingested. */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct IngestMetadata {
+    timestamp: String,
+}
+
+impl IngestMetadata {
+    /** The time the document was ingested. */
+    pub fn timestamp(&self) -> &str {
+        &self.timestamp
+    }
+}
+
+impl<T: DeserializeOwned> IsOk for SimulatePipelineResponse<T> {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
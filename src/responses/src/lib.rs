@@ -144,11 +144,42 @@ mod common;
 mod command;
 mod ping;
 mod get;
+mod get_source;
 mod delete;
 mod update;
 pub mod search;
 pub mod bulk;
 mod index;
+mod explain;
+mod suggest;
+mod render_search_template;
+mod field_caps;
+mod count;
+mod index_template;
+mod mapping;
+mod ingest;
+mod snapshot;
+mod cat;
+mod rollover;
+mod index_settings;
+mod validate_query;
+mod termvectors;
+mod profile;
+mod cluster_health;
+mod cluster_reroute;
+mod indices_stats;
+mod alias;
+mod nodes_stats;
+mod nodes_info;
+mod tasks;
+mod script;
+mod reindex;
+mod sql;
+mod eql;
+mod percolate;
+mod analyze;
+mod recovery;
+mod exists;
 
 mod indices_exists;
 
@@ -156,11 +187,42 @@ pub use self::common::*;
 pub use self::command::*;
 pub use self::ping::*;
 pub use self::get::*;
+pub use self::get_source::*;
 pub use self::delete::*;
 pub use self::update::*;
-pub use self::search::SearchResponse;
+pub use self::search::{SearchResponse, SearchResponseRaw};
 pub use self::bulk::{BulkErrorsResponse, BulkResponse};
 pub use self::index::*;
+pub use self::explain::*;
+pub use self::suggest::*;
+pub use self::render_search_template::*;
+pub use self::field_caps::*;
+pub use self::count::*;
+pub use self::index_template::*;
+pub use self::mapping::*;
+pub use self::ingest::*;
+pub use self::snapshot::*;
+pub use self::cat::*;
+pub use self::rollover::*;
+pub use self::index_settings::*;
+pub use self::validate_query::*;
+pub use self::termvectors::*;
+pub use self::profile::*;
+pub use self::cluster_health::*;
+pub use self::cluster_reroute::*;
+pub use self::indices_stats::*;
+pub use self::alias::*;
+pub use self::nodes_stats::*;
+pub use self::nodes_info::*;
+pub use self::tasks::*;
+pub use self::script::*;
+pub use self::reindex::*;
+pub use self::sql::*;
+pub use self::eql::*;
+pub use self::percolate::*;
+pub use self::analyze::*;
+pub use self::recovery::*;
+pub use self::exists::*;
 
 pub use self::indices_exists::*;
 
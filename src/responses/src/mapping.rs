@@ -0,0 +1,118 @@
+/*!
+Response types for a [get mapping request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-mapping.html).
+*/
+
+use std::collections::BTreeMap;
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/** Response for a [get mapping request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-mapping.html).
+
+Mappings are keyed by the name of their index.
+*/
+#[derive(Deserialize, Debug)]
+pub struct GetMappingResponse {
+    #[serde(flatten)] indices: BTreeMap<String, IndexMappings>,
+}
+
+impl GetMappingResponse {
+    /** Get the mappings for an index. */
+    pub fn index(&self, index: &str) -> Option<&IndexMappings> {
+        self.indices.get(index)
+    }
+
+    /** Get a reference to the mappings for all indices. */
+    pub fn indices(&self) -> &BTreeMap<String, IndexMappings> {
+        &self.indices
+    }
+}
+
+/** The type mappings for a single index. */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct IndexMappings {
+    #[serde(default)] mappings: BTreeMap<String, TypeMapping>,
+}
+
+impl IndexMappings {
+    /** Get the mapping for a document type. */
+    pub fn ty(&self, ty: &str) -> Option<&TypeMapping> {
+        self.mappings.get(ty)
+    }
+
+    /** Get a reference to the mappings for all document types. */
+    pub fn mappings(&self) -> &BTreeMap<String, TypeMapping> {
+        &self.mappings
+    }
+}
+
+/** The mapping for a single document type. */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct TypeMapping {
+    #[serde(default)] properties: BTreeMap<String, MappingProperty>,
+}
+
+impl TypeMapping {
+    /** Get a top-level property by name. */
+    pub fn property(&self, name: &str) -> Option<&MappingProperty> {
+        self.properties.get(name)
+    }
+
+    /** Get a reference to the top-level properties. */
+    pub fn properties(&self) -> &BTreeMap<String, MappingProperty> {
+        &self.properties
+    }
+}
+
+/** The mapping for a single property.
+
+Object properties have their own nested `properties`.
+Other properties may be indexed in more than one way using `fields`.
+*/
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct MappingProperty {
+    #[serde(rename = "type")] type_: Option<String>,
+    #[serde(default)] fields: BTreeMap<String, MappingProperty>,
+    #[serde(default)] properties: BTreeMap<String, MappingProperty>,
+    analyzer: Option<String>,
+    index: Option<bool>,
+}
+
+impl MappingProperty {
+    /** The mapped type of the property.
+
+    This is `None` for object properties, which don't include their type.
+    */
+    pub fn type_(&self) -> Option<&str> {
+        self.type_.as_ref().map(|ty| ty.as_ref())
+    }
+
+    /** Get a reference to the alternative fields this property is indexed as. */
+    pub fn fields(&self) -> &BTreeMap<String, MappingProperty> {
+        &self.fields
+    }
+
+    /** Get a reference to the nested properties of an object property. */
+    pub fn properties(&self) -> &BTreeMap<String, MappingProperty> {
+        &self.properties
+    }
+
+    /** The analyzer used for a text property. */
+    pub fn analyzer(&self) -> Option<&str> {
+        self.analyzer.as_ref().map(|analyzer| analyzer.as_ref())
+    }
+
+    /** Whether the property is indexed, if it was explicitly set. */
+    pub fn index(&self) -> Option<bool> {
+        self.index
+    }
+}
+
+impl IsOk for GetMappingResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+/*!
+Response types for a [nodes info request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-nodes-info.html).
+*/
+
+use std::collections::BTreeMap;
+use std::collections::btree_map::Iter;
+use serde_json::Value;
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/** Response for a [nodes info request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-nodes-info.html).
+
+Info is keyed by node id.
+*/
+#[derive(Deserialize, Debug)]
+pub struct NodesInfoResponse {
+    cluster_name: String,
+    #[serde(default)] nodes: BTreeMap<String, NodeInfo>,
+}
+
+impl NodesInfoResponse {
+    /** The name of the cluster. */
+    pub fn cluster_name(&self) -> &str {
+        &self.cluster_name
+    }
+
+    /** Get the info for a node by its id. */
+    pub fn node(&self, id: &str) -> Option<&NodeInfo> {
+        self.nodes.get(id)
+    }
+
+    /** Iterate over the node ids and their info. */
+    pub fn nodes(&self) -> Iter<String, NodeInfo> {
+        self.nodes.iter()
+    }
+}
+
+/** The info for a single node. */
+#[derive(Deserialize, Debug)]
+pub struct NodeInfo {
+    name: String,
+    transport_address: String,
+    host: Option<String>,
+    ip: Option<String>,
+    version: Option<String>,
+    #[serde(default)] roles: Vec<String>,
+    http: Option<NodeHttpInfo>,
+    settings: Option<Value>,
+}
+
+impl NodeInfo {
+    /** The name of the node. */
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /** The address other nodes use to talk to this one, like `10.0.0.1:9300`. */
+    pub fn transport_address(&self) -> &str {
+        &self.transport_address
+    }
+
+    /** The host name of the node. */
+    pub fn host(&self) -> Option<&str> {
+        self.host.as_ref().map(|host| host.as_ref())
+    }
+
+    /** The ip address of the node. */
+    pub fn ip(&self) -> Option<&str> {
+        self.ip.as_ref().map(|ip| ip.as_ref())
+    }
+
+    /** The version of Elasticsearch running on the node. */
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_ref().map(|version| version.as_ref())
+    }
+
+    /** The roles of the node, like `master`, `data` and `ingest`. */
+    pub fn roles(&self) -> &[String] {
+        &self.roles
+    }
+
+    /** Whether or not the node has the given role. */
+    pub fn has_role(&self, role: &str) -> bool {
+        self.roles.iter().any(|r| r == role)
+    }
+
+    /**
+    The address clients should use to send http requests to the node, like `10.0.0.1:9200`.
+
+    This is only returned if http info was requested and the node has http enabled.
+    */
+    pub fn http_publish_address(&self) -> Option<&str> {
+        self.http.as_ref().map(|http| http.publish_address.as_ref())
+    }
+
+    /** The settings of the node, if settings info was requested. */
+    pub fn settings(&self) -> Option<&Value> {
+        self.settings.as_ref()
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct NodeHttpInfo {
+    publish_address: String,
+}
+
+impl IsOk for NodesInfoResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
@@ -0,0 +1,175 @@
+/*!
+Response types for a [nodes stats request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-nodes-stats.html).
+*/
+
+use std::collections::BTreeMap;
+use std::collections::btree_map::Iter;
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/** Response for a [nodes stats request](https://www.elastic.co/guide/en/elasticsearch/reference/current/cluster-nodes-stats.html).
+
+Stats are keyed by node id.
+*/
+#[derive(Deserialize, Debug)]
+pub struct NodesStatsResponse {
+    cluster_name: String,
+    #[serde(default)] nodes: BTreeMap<String, NodeStats>,
+}
+
+impl NodesStatsResponse {
+    /** The name of the cluster. */
+    pub fn cluster_name(&self) -> &str {
+        &self.cluster_name
+    }
+
+    /** Get the stats for a node by its id. */
+    pub fn node(&self, id: &str) -> Option<&NodeStats> {
+        self.nodes.get(id)
+    }
+
+    /** Iterate over the node ids and their stats. */
+    pub fn nodes(&self) -> Iter<String, NodeStats> {
+        self.nodes.iter()
+    }
+
+    /**
+    The node using the most jvm heap, in bytes.
+
+    Nodes without jvm stats are ignored.
+    Returns `None` if there aren't any nodes with jvm stats.
+    */
+    pub fn node_with_highest_heap_usage(&self) -> Option<&NodeStats> {
+        self.nodes
+            .values()
+            .filter(|node| node.jvm.is_some())
+            .max_by_key(|node| node.heap_used_in_bytes())
+    }
+}
+
+/** The stats for a single node. */
+#[derive(Deserialize, Debug)]
+pub struct NodeStats {
+    name: String,
+    host: Option<String>,
+    jvm: Option<JvmStats>,
+    os: Option<OsStats>,
+    #[serde(default)] thread_pool: BTreeMap<String, ThreadPoolStats>,
+}
+
+impl NodeStats {
+    /** The name of the node. */
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /** The host name of the node. */
+    pub fn host(&self) -> Option<&str> {
+        self.host.as_ref().map(|host| host.as_ref())
+    }
+
+    /** The jvm heap in use, in bytes, if jvm stats were requested. */
+    pub fn heap_used_in_bytes(&self) -> Option<u64> {
+        self.jvm.as_ref().map(|jvm| jvm.mem.heap_used_in_bytes)
+    }
+
+    /** The maximum jvm heap, in bytes, if jvm stats were requested. */
+    pub fn heap_max_in_bytes(&self) -> Option<u64> {
+        self.jvm.as_ref().map(|jvm| jvm.mem.heap_max_in_bytes)
+    }
+
+    /** The percentage of the maximum jvm heap in use, if jvm stats were requested. */
+    pub fn heap_used_percent(&self) -> Option<u32> {
+        self.jvm.as_ref().map(|jvm| jvm.mem.heap_used_percent)
+    }
+
+    /** The recent cpu usage of the whole system as a percentage, if os stats were requested. */
+    pub fn cpu_percent(&self) -> Option<u32> {
+        self.os.as_ref().and_then(|os| os.cpu.as_ref()).map(|cpu| cpu.percent)
+    }
+
+    /** Get the stats for a thread pool, like `search` or `bulk`. */
+    pub fn thread_pool(&self, name: &str) -> Option<&ThreadPoolStats> {
+        self.thread_pool.get(name)
+    }
+
+    /** Iterate over the thread pools and their stats, if thread pool stats were requested. */
+    pub fn thread_pools(&self) -> Iter<String, ThreadPoolStats> {
+        self.thread_pool.iter()
+    }
+}
+
+/** The stats for a single thread pool on a node. */
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct ThreadPoolStats {
+    threads: u32,
+    queue: u32,
+    active: u32,
+    rejected: u64,
+    largest: u32,
+    completed: u64,
+}
+
+impl ThreadPoolStats {
+    /** The number of threads in the pool. */
+    pub fn threads(&self) -> u32 {
+        self.threads
+    }
+
+    /** The number of tasks waiting in the queue for a thread. */
+    pub fn queue(&self) -> u32 {
+        self.queue
+    }
+
+    /** The number of threads running a task. */
+    pub fn active(&self) -> u32 {
+        self.active
+    }
+
+    /** The number of tasks rejected because the queue was full. */
+    pub fn rejected(&self) -> u64 {
+        self.rejected
+    }
+
+    /** The largest number of threads the pool has had. */
+    pub fn largest(&self) -> u32 {
+        self.largest
+    }
+
+    /** The number of tasks the pool has completed. */
+    pub fn completed(&self) -> u64 {
+        self.completed
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct JvmStats {
+    mem: JvmMemStats,
+}
+
+#[derive(Deserialize, Debug)]
+struct JvmMemStats {
+    heap_used_in_bytes: u64,
+    heap_used_percent: u32,
+    heap_max_in_bytes: u64,
+}
+
+#[derive(Deserialize, Debug)]
+struct OsStats {
+    cpu: Option<OsCpuStats>,
+}
+
+#[derive(Deserialize, Debug)]
+struct OsCpuStats {
+    percent: u32,
+}
+
+impl IsOk for NodesStatsResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
@@ -9,8 +9,12 @@ use serde_json::{self, Value};
 
 use error::*;
 
+/** The default value for `Parse::capture_body_on_error_limit`, in bytes. */
+pub const DEFAULT_CAPTURE_BODY_ON_ERROR_LIMIT: usize = 16 * 1024;
+
 /** A parser that separates taking a response type from the readable body type. */
 pub struct Parse<T> {
+    capture_body_on_error_limit: usize,
     _marker: PhantomData<T>,
 }
 
@@ -77,28 +81,41 @@ let get_response = parse().from_slice(response_status, response_body);
 */
 pub fn parse<T: IsOk + DeserializeOwned>() -> Parse<T> {
     Parse {
+        capture_body_on_error_limit: DEFAULT_CAPTURE_BODY_ON_ERROR_LIMIT,
         _marker: PhantomData,
     }
 }
 
 impl<T: IsOk + DeserializeOwned> Parse<T> {
+    /**
+    Set the largest response body, in bytes, that's captured when it can't be deserialised.
+
+    Bodies that are no larger than the limit are included in a `ParseResponseError::Body` error.
+    Larger bodies are left out so a bad response can't use an unbounded amount of memory.
+    The default limit is `DEFAULT_CAPTURE_BODY_ON_ERROR_LIMIT`.
+    */
+    pub fn capture_body_on_error_limit(mut self, limit: usize) -> Self {
+        self.capture_body_on_error_limit = limit;
+        self
+    }
+
     /** Try parse a contiguous slice of bytes into a concrete response. */
     pub fn from_slice<B: AsRef<[u8]>, H: Into<HttpResponseHead>>(self, head: H, body: B) -> Result<T, ResponseError> {
-        from_body(head.into(), SliceBody(body))
+        from_body(head.into(), SliceBody(body), self.capture_body_on_error_limit)
     }
 
     /** Try parse an arbitrary reader into a concrete response. */
     pub fn from_reader<B: Read, H: Into<HttpResponseHead>>(self, head: H, body: B) -> Result<T, ResponseError> {
-        from_body(head.into(), ReadBody(body))
+        from_body(head.into(), ReadBody(body), self.capture_body_on_error_limit)
     }
 }
 
-fn from_body<B: ResponseBody, T: IsOk + DeserializeOwned>(head: HttpResponseHead, body: B) -> Result<T, ResponseError> {
+fn from_body<B: ResponseBody, T: IsOk + DeserializeOwned>(head: HttpResponseHead, body: B, capture_limit: usize) -> Result<T, ResponseError> {
     let maybe = T::is_ok(head, Unbuffered(body))?;
 
     match maybe.ok {
         true => {
-            let ok = maybe.res.parse_ok()?;
+            let ok = maybe.res.parse_ok(capture_limit)?;
             Ok(ok)
         }
         false => {
@@ -137,8 +154,12 @@ where
     /** Buffer the response body to a json value and return a new buffered representation. */
     fn body(self) -> Result<(Value, Self::Buffered), ParseResponseError>;
 
-    /** Parse the body as a success result. */
-    fn parse_ok<T: DeserializeOwned>(self) -> Result<T, ParseResponseError>;
+    /**
+    Parse the body as a success result.
+
+    If the body can't be deserialised then the error includes the raw body if it's no larger than `capture_limit` bytes.
+    */
+    fn parse_ok<T: DeserializeOwned>(self, capture_limit: usize) -> Result<T, ParseResponseError>;
 
     /** Parse the body as an API error. */
     fn parse_err(self) -> Result<ApiError, ParseResponseError>;
@@ -158,8 +179,20 @@ impl<B: Read> ResponseBody for ReadBody<B> {
         Ok((body, SliceBody(buf)))
     }
 
-    fn parse_ok<T: DeserializeOwned>(self) -> Result<T, ParseResponseError> {
-        serde_json::from_reader(self.0).map_err(|e| e.into())
+    fn parse_ok<T: DeserializeOwned>(mut self, capture_limit: usize) -> Result<T, ParseResponseError> {
+        if capture_limit == 0 {
+            return parse_reader(self.0);
+        }
+
+        // Only buffer up to the capture limit, larger bodies are streamed without being captured
+        let mut buf = Vec::new();
+        (&mut self.0).take(capture_limit as u64 + 1).read_to_end(&mut buf)?;
+
+        if buf.len() <= capture_limit {
+            parse_slice(&buf, capture_limit)
+        } else {
+            parse_reader(Cursor::new(buf).chain(self.0))
+        }
     }
 
     fn parse_err(self) -> Result<ApiError, ParseResponseError> {
@@ -180,8 +213,8 @@ impl<B: AsRef<[u8]>> ResponseBody for SliceBody<B> {
         Ok((body, SliceBody(buf)))
     }
 
-    fn parse_ok<T: DeserializeOwned>(self) -> Result<T, ParseResponseError> {
-        serde_json::from_slice(self.0.as_ref()).map_err(|e| e.into())
+    fn parse_ok<T: DeserializeOwned>(self, capture_limit: usize) -> Result<T, ParseResponseError> {
+        parse_slice(self.0.as_ref(), capture_limit)
     }
 
     fn parse_err(self) -> Result<ApiError, ParseResponseError> {
@@ -198,8 +231,13 @@ impl ResponseBody for Value {
         Ok((self, value))
     }
 
-    fn parse_ok<T: DeserializeOwned>(self) -> Result<T, ParseResponseError> {
-        serde_json::from_value(self).map_err(|e| e.into())
+    fn parse_ok<T: DeserializeOwned>(self, capture_limit: usize) -> Result<T, ParseResponseError> {
+        T::deserialize(&self).map_err(|err| {
+            let raw_body = self.to_string();
+            let raw_body = if raw_body.len() <= capture_limit { Some(raw_body) } else { None };
+
+            ParseResponseError::body(err, raw_body)
+        })
     }
 
     fn parse_err(self) -> Result<ApiError, ParseResponseError> {
@@ -207,6 +245,28 @@ impl ResponseBody for Value {
     }
 }
 
+fn parse_slice<T: DeserializeOwned>(buf: &[u8], capture_limit: usize) -> Result<T, ParseResponseError> {
+    serde_json::from_slice(buf).map_err(|err| {
+        let raw_body = if buf.len() <= capture_limit {
+            Some(String::from_utf8_lossy(buf).into_owned())
+        } else {
+            None
+        };
+
+        ParseResponseError::body(err, raw_body)
+    })
+}
+
+fn parse_reader<T: DeserializeOwned, R: Read>(body: R) -> Result<T, ParseResponseError> {
+    serde_json::from_reader(body).map_err(|err| {
+        if err.is_io() {
+            ParseResponseError::Io(err.into())
+        } else {
+            ParseResponseError::body(err, None)
+        }
+    })
+}
+
 /**
 Convert a response message into a either a success
 or failure result.
@@ -351,11 +411,11 @@ impl<B> MaybeBufferedResponse<B>
 where
     B: ResponseBody,
 {
-    fn parse_ok<T: DeserializeOwned>(self) -> Result<T, ParseResponseError> {
+    fn parse_ok<T: DeserializeOwned>(self, capture_limit: usize) -> Result<T, ParseResponseError> {
         match self {
-            MaybeBufferedResponse::Unbuffered(b) => b.parse_ok(),
-            MaybeBufferedResponse::Buffered(b) => b.parse_ok(),
-            MaybeBufferedResponse::Value(b) => b.parse_ok(),
+            MaybeBufferedResponse::Unbuffered(b) => b.parse_ok(capture_limit),
+            MaybeBufferedResponse::Buffered(b) => b.parse_ok(capture_limit),
+            MaybeBufferedResponse::Value(b) => b.parse_ok(capture_limit),
         }
     }
 
@@ -0,0 +1,44 @@
+/*!
+Response types for a search request with a [percolate query](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-percolate-query.html).
+*/
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use search::SearchResponse;
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/**
+Response for a search request with a [percolate query](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-percolate-query.html).
+
+A percolate query is sent as a regular search, so the body is a `SearchResponse` where each hit is a stored query that matched the document.
+*/
+#[derive(Deserialize, Debug)]
+pub struct PercolateQueryResponse<T = Value>(SearchResponse<T>);
+
+impl<T> PercolateQueryResponse<T> {
+    /** Get a reference to the underlying search response. */
+    pub fn search(&self) -> &SearchResponse<T> {
+        &self.0
+    }
+
+    /** Convert the response into the underlying search response. */
+    pub fn into_search(self) -> SearchResponse<T> {
+        self.0
+    }
+
+    /** Get the ids of the stored queries that matched the document. */
+    pub fn matched_query_ids(&self) -> Vec<&str> {
+        self.0.hits().map(|hit| hit.id()).collect()
+    }
+}
+
+impl<T: DeserializeOwned> IsOk for PercolateQueryResponse<T> {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
@@ -0,0 +1,177 @@
+/*!
+Response types for a [profiled search request](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-profile.html).
+*/
+
+use std::collections::BTreeMap;
+
+/** 
+The profile of a search request.
+
+Profiles are only returned when the search body sets `"profile": true`.
+*/
+#[derive(Deserialize, Debug, Clone)]
+pub struct ProfileResult {
+    shards: Vec<ShardProfile>,
+}
+
+impl ProfileResult {
+    /** The profile for each shard that took part in the search. */
+    pub fn shards(&self) -> &[ShardProfile] {
+        &self.shards
+    }
+}
+
+/** The profile of a search on a single shard. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct ShardProfile {
+    id: String,
+    searches: Vec<SearchProfile>,
+    #[serde(default)] aggregations: Vec<AggregationProfile>,
+}
+
+impl ShardProfile {
+    /** The id of the shard, like `[nodeId][indexName][shardId]`. */
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /** The profiles for the queries run on the shard. */
+    pub fn searches(&self) -> &[SearchProfile] {
+        &self.searches
+    }
+
+    /** The profiles for the aggregations run on the shard. */
+    pub fn aggregations(&self) -> &[AggregationProfile] {
+        &self.aggregations
+    }
+}
+
+/** The profile of a single query run on a shard. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct SearchProfile {
+    query: Vec<QueryProfile>,
+    rewrite_time: u64,
+    #[serde(default)] collector: Vec<CollectorProfile>,
+}
+
+impl SearchProfile {
+    /** The profiles for the Lucene queries the search query was rewritten into. */
+    pub fn query(&self) -> &[QueryProfile] {
+        &self.query
+    }
+
+    /** The time spent rewriting the query in nanoseconds. */
+    pub fn rewrite_time(&self) -> u64 {
+        self.rewrite_time
+    }
+
+    /** The profiles for the Lucene collectors that gathered the hits. */
+    pub fn collector(&self) -> &[CollectorProfile] {
+        &self.collector
+    }
+}
+
+/** The profile of a single Lucene query and the queries it contains. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct QueryProfile {
+    #[serde(rename = "type")] ty: String,
+    description: String,
+    time_in_nanos: u64,
+    #[serde(default)] breakdown: BTreeMap<String, u64>,
+    #[serde(default)] children: Vec<QueryProfile>,
+}
+
+impl QueryProfile {
+    /** The Lucene query type, like `BooleanQuery`. */
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    /** The Lucene explanation of the query. */
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /** The time spent on this query and its children in nanoseconds. */
+    pub fn time_in_nanos(&self) -> u64 {
+        self.time_in_nanos
+    }
+
+    /** The time spent on each low-level Lucene operation in nanoseconds, keyed by operation. */
+    pub fn breakdown(&self) -> &BTreeMap<String, u64> {
+        &self.breakdown
+    }
+
+    /** The profiles for the queries this query contains. */
+    pub fn children(&self) -> &[QueryProfile] {
+        &self.children
+    }
+}
+
+/** The profile of a single Lucene collector and the collectors it wraps. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct CollectorProfile {
+    name: String,
+    reason: String,
+    time_in_nanos: u64,
+    #[serde(default)] children: Vec<CollectorProfile>,
+}
+
+impl CollectorProfile {
+    /** The Lucene collector class name, like `SimpleTopScoreDocCollector`. */
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /** A description of why the collector was used, like `search_top_hits`. */
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    /** The time spent on this collector and its children in nanoseconds. */
+    pub fn time_in_nanos(&self) -> u64 {
+        self.time_in_nanos
+    }
+
+    /** The profiles for the collectors this collector wraps. */
+    pub fn children(&self) -> &[CollectorProfile] {
+        &self.children
+    }
+}
+
+/** The profile of a single aggregation and its sub-aggregations. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct AggregationProfile {
+    #[serde(rename = "type")] ty: String,
+    description: String,
+    time_in_nanos: u64,
+    #[serde(default)] breakdown: BTreeMap<String, u64>,
+    #[serde(default)] children: Vec<AggregationProfile>,
+}
+
+impl AggregationProfile {
+    /** The aggregator class name, like `GlobalOrdinalsStringTermsAggregator`. */
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    /** The name of the aggregation in the request. */
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /** The time spent on this aggregation and its children in nanoseconds. */
+    pub fn time_in_nanos(&self) -> u64 {
+        self.time_in_nanos
+    }
+
+    /** The time spent on each low-level operation in nanoseconds, keyed by operation. */
+    pub fn breakdown(&self) -> &BTreeMap<String, u64> {
+        &self.breakdown
+    }
+
+    /** The profiles for the sub-aggregations of this aggregation. */
+    pub fn children(&self) -> &[AggregationProfile] {
+        &self.children
+    }
+}
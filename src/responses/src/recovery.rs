@@ -0,0 +1,96 @@
+/*!
+Response types for an [indices recovery request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-recovery.html).
+*/
+
+use std::collections::BTreeMap;
+use std::collections::btree_map::Iter;
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/** Response for an [indices recovery request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-recovery.html).
+
+Shard recoveries are keyed by index name.
+*/
+#[derive(Deserialize, Debug)]
+pub struct IndicesRecoveryResponse {
+    #[serde(flatten)] indices: BTreeMap<String, IndexRecovery>,
+}
+
+impl IndicesRecoveryResponse {
+    /** Get the shard recoveries for an index. */
+    pub fn index(&self, index: &str) -> Option<&[ShardRecovery]> {
+        self.indices.get(index).map(|index| index.shards())
+    }
+
+    /** Iterate over the indices and their shard recoveries. */
+    pub fn indices(&self) -> Iter<String, IndexRecovery> {
+        self.indices.iter()
+    }
+
+    /** Whether or not every shard in every index has finished recovering. */
+    pub fn all_complete(&self) -> bool {
+        self.indices
+            .values()
+            .flat_map(|index| index.shards.iter())
+            .all(|shard| shard.is_done())
+    }
+}
+
+/** The shard recoveries for a single index. */
+#[derive(Deserialize, Debug)]
+pub struct IndexRecovery {
+    #[serde(default)] shards: Vec<ShardRecovery>,
+}
+
+impl IndexRecovery {
+    /** The recoveries for each shard in the index. */
+    pub fn shards(&self) -> &[ShardRecovery] {
+        &self.shards
+    }
+}
+
+/** The recovery of a single shard. */
+#[derive(Deserialize, Debug)]
+pub struct ShardRecovery {
+    id: u32,
+    #[serde(rename = "type")] ty: String,
+    stage: String,
+    primary: bool,
+}
+
+impl ShardRecovery {
+    /** The number of the shard. */
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /** The type of recovery, like `SNAPSHOT` or `PEER`. */
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    /** The stage of the recovery, like `INDEX` or `DONE`. */
+    pub fn stage(&self) -> &str {
+        &self.stage
+    }
+
+    /** Whether or not the shard is a primary. */
+    pub fn is_primary(&self) -> bool {
+        self.primary
+    }
+
+    /** Whether or not the recovery has finished. */
+    pub fn is_done(&self) -> bool {
+        self.stage == "DONE"
+    }
+}
+
+impl IsOk for IndicesRecoveryResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
@@ -0,0 +1,107 @@
+/*!
+Response types for a [reindex request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-reindex.html).
+*/
+
+use serde_json::Value;
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/** Response for a [reindex request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-reindex.html). */
+#[derive(Deserialize, Debug)]
+pub struct ReindexResponse {
+    took: u64,
+    #[serde(default)] timed_out: bool,
+    total: u64,
+    #[serde(default)] created: u64,
+    #[serde(default)] updated: u64,
+    #[serde(default)] deleted: u64,
+    #[serde(default)] batches: u64,
+    #[serde(default)] version_conflicts: u64,
+    #[serde(default)] noops: u64,
+    #[serde(default)] failures: Vec<Value>,
+}
+
+impl ReindexResponse {
+    /** Time in milliseconds it took for Elasticsearch to process the request. */
+    pub fn took(&self) -> u64 {
+        self.took
+    }
+
+    /** Whether or not any of the requests run during the reindex timed out. */
+    pub fn timed_out(&self) -> bool {
+        self.timed_out
+    }
+
+    /** The number of documents that were processed. */
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /** The number of documents that were created in the destination index. */
+    pub fn created(&self) -> u64 {
+        self.created
+    }
+
+    /** The number of documents that were updated in the destination index. */
+    pub fn updated(&self) -> u64 {
+        self.updated
+    }
+
+    /** The number of documents that were deleted by a script. */
+    pub fn deleted(&self) -> u64 {
+        self.deleted
+    }
+
+    /** The number of scroll batches that were pulled from the source index. */
+    pub fn batches(&self) -> u64 {
+        self.batches
+    }
+
+    /** The number of version conflicts. */
+    pub fn version_conflicts(&self) -> u64 {
+        self.version_conflicts
+    }
+
+    /** The number of documents that were skipped by a script. */
+    pub fn noops(&self) -> u64 {
+        self.noops
+    }
+
+    /**
+    The failures that stopped the reindex.
+
+    Each failure is an object with properties like `index`, `id` and `cause`.
+    */
+    pub fn failures(&self) -> &[Value] {
+        &self.failures
+    }
+
+    /** Whether or not the reindex was stopped by any failures. */
+    pub fn had_failures(&self) -> bool {
+        !self.failures.is_empty()
+    }
+
+    /**
+    The fraction of processed documents that were written to the destination index, between `0.0` and `1.0`.
+
+    Both created and updated documents count as written.
+    A reindex that didn't process any documents has a success rate of `1.0`.
+    */
+    pub fn success_rate(&self) -> f64 {
+        if self.total == 0 {
+            1.0
+        } else {
+            (self.created + self.updated) as f64 / self.total as f64
+        }
+    }
+}
+
+impl IsOk for ReindexResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+/*!
+Response types for a [render search template request](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-template.html).
+*/
+
+use serde_json::Value;
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/** Response for a [render search template request](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-template.html). */
+#[derive(Deserialize, Debug)]
+pub struct RenderSearchTemplateResponse {
+    template_output: Value,
+}
+
+impl RenderSearchTemplateResponse {
+    /** Get a reference to the rendered search body. */
+    pub fn template_output(&self) -> &Value {
+        &self.template_output
+    }
+
+    /** Convert the response into the rendered search body. */
+    pub fn into_template_output(self) -> Value {
+        self.template_output
+    }
+}
+
+impl IsOk for RenderSearchTemplateResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+/*!
+Response types for a [rollover index request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-rollover-index.html).
+*/
+
+use std::collections::BTreeMap;
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/** Response for a [rollover index request](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-rollover-index.html). */
+#[derive(Deserialize, Debug)]
+pub struct RolloverResponse {
+    #[serde(default)] acknowledged: bool,
+    #[serde(default)] shards_acknowledged: bool,
+    old_index: String,
+    new_index: String,
+    rolled_over: bool,
+    dry_run: bool,
+    #[serde(default)] conditions: BTreeMap<String, bool>,
+}
+
+impl RolloverResponse {
+    /** 
+    Whether or not the request was acknowledged.
+    
+    This doesn't necessarily mean the request has been fully processed.
+    */
+    pub fn acknowledged(&self) -> bool {
+        self.acknowledged
+    }
+
+    /** Whether or not the shards of the new index were started before the request timed out. */
+    pub fn shards_acknowledged(&self) -> bool {
+        self.shards_acknowledged
+    }
+
+    /** The index the alias pointed to before the rollover. */
+    pub fn old_index(&self) -> &str {
+        &self.old_index
+    }
+
+    /** The index the alias points to after the rollover. */
+    pub fn new_index(&self) -> &str {
+        &self.new_index
+    }
+
+    /** Whether or not the alias was rolled over to the new index. */
+    pub fn rolled_over(&self) -> bool {
+        self.rolled_over
+    }
+
+    /** Whether or not the request only checked the conditions. */
+    pub fn dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /** 
+    The conditions that were checked and whether or not each one was met.
+
+    Conditions are keyed by their description, like `[max_docs: 1000]`.
+    */
+    pub fn conditions(&self) -> &BTreeMap<String, bool> {
+        &self.conditions
+    }
+}
+
+impl IsOk for RolloverResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
@@ -0,0 +1,78 @@
+/*!
+Response types for a [get stored script request](https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-scripting-using.html#modules-scripting-stored-scripts).
+*/
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/** Response for a [get stored script request](https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-scripting-using.html#modules-scripting-stored-scripts).
+
+If the script wasn't found then there's no script.
+*/
+#[derive(Deserialize, Debug)]
+pub struct GetScriptResponse {
+    #[serde(rename = "_id")] id: String,
+    found: bool,
+    script: Option<StoredScript>,
+}
+
+impl GetScriptResponse {
+    /** The id of the script. */
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /** Whether or not the script was found. */
+    pub fn found(&self) -> bool {
+        self.found
+    }
+
+    /** Get a reference to the stored script. */
+    pub fn script(&self) -> Option<&StoredScript> {
+        self.script.as_ref()
+    }
+
+    /** Convert the response into the stored script. */
+    pub fn into_script(self) -> Option<StoredScript> {
+        self.script
+    }
+}
+
+/** A stored script. */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct StoredScript {
+    lang: String,
+    source: String,
+}
+
+impl StoredScript {
+    /** The language of the script, like `painless` or `mustache`. */
+    pub fn lang(&self) -> &str {
+        &self.lang
+    }
+
+    /** The source of the script. */
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+}
+
+impl IsOk for GetScriptResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            404 => {
+                // A missing script is returned with `found: false` instead of an error
+                let (maybe_err, body) = body.body()?;
+
+                let is_ok = maybe_err
+                    .as_object()
+                    .and_then(|maybe_err| maybe_err.get("error"))
+                    .is_none();
+
+                Ok(MaybeOkResponse::new(is_ok, body))
+            }
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
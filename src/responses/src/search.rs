@@ -3,9 +3,12 @@ Response types for a [search request](https://www.elastic.co/guide/en/elasticsea
 */
 
 use serde::de::DeserializeOwned;
-use serde_json::{Map, Value};
+use serde_json::{self, Map, Value};
+use serde_json::value::RawValue;
 
 use common::Shards;
+use suggest::Suggestion;
+use profile::ProfileResult;
 use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
 use error::*;
 
@@ -13,6 +16,7 @@ use std::borrow::Cow;
 use std::collections::BTreeMap;
 use std::slice::Iter;
 use std::vec::IntoIter;
+use std::time::Duration;
 
 /** 
 Response for a [search request][search-req].
@@ -48,20 +52,62 @@ for hit in response.hits() {
 # }
 ```
 
+The `_source` of each hit is deserialised into `T`.
+If `T` isn't given then it defaults to `serde_json::Value`, so `SearchResponse` on its own can be used for untyped documents.
+
 [search-req]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-search.html
 [metric-aggs]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics.html
 [stats-aggs]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-stats-aggregation.html
 */
 #[derive(Deserialize, Debug)]
-pub struct SearchResponse<T> {
+pub struct SearchResponse<T = Value> {
     took: u64,
     timed_out: bool,
     #[serde(rename = "_shards")] shards: Shards,
     hits: HitsWrapper<T>,
     aggregations: Option<AggsWrapper>,
+    suggest: Option<BTreeMap<String, Vec<Suggestion>>>,
+    profile: Option<ProfileResult>,
     status: Option<u16>,
 }
 
+/**
+A search response where the `_source` of each hit is left as raw json.
+
+Sources are only deserialised when `Hit::parse_source` is called, so responses that are mostly
+inspected for metadata like ids and scores, or have their sources passed through unchanged,
+don't pay for building a `Value` for every document.
+
+# Examples
+
+Parse the source of the hits with a high enough score:
+
+```no_run
+# #[macro_use] extern crate serde_derive;
+# extern crate serde;
+# extern crate elastic_responses;
+# use elastic_responses::SearchResponseRaw;
+# fn do_request() -> SearchResponseRaw { unimplemented!() }
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<::std::error::Error>> {
+#[derive(Deserialize)]
+struct Tweet {
+    message: String,
+}
+
+let response: SearchResponseRaw = do_request();
+
+for hit in response.hits().filter(|hit| hit.score().unwrap_or(0.0) > 0.5) {
+    if let Some(tweet) = hit.parse_source::<Tweet>()? {
+        println!("{}: {}", hit.id(), tweet.message);
+    }
+}
+# Ok(())
+# }
+```
+*/
+pub type SearchResponseRaw = SearchResponse<Box<RawValue>>;
+
 /** Struct to hold the search's Hits, serializable to type `T` or `serde_json::Value`. */
 #[derive(Deserialize, Debug)]
 struct HitsWrapper<T> {
@@ -76,6 +122,20 @@ impl<T> SearchResponse<T> {
         self.took
     }
 
+    /**
+    Time in milliseconds it took for Elasticsearch to process the request.
+
+    This is the same as `took`.
+    */
+    pub fn took_ms(&self) -> u64 {
+        self.took
+    }
+
+    /** Time it took for Elasticsearch to process the request. */
+    pub fn took_duration(&self) -> Duration {
+        Duration::from_millis(self.took)
+    }
+
     /** Whether or not the request timed out before completing. */
     pub fn timed_out(&self) -> bool {
         self.timed_out
@@ -106,6 +166,16 @@ impl<T> SearchResponse<T> {
         Hits::new(&self.hits)
     }
 
+    /**
+    Get the sort values of the last hit.
+
+    These can be passed as the `search_after` property of the next search request to get the next page of hits.
+    This is `None` if there are no hits or the request wasn't sorted.
+    */
+    pub fn last_sort_values(&self) -> Option<&[Value]> {
+        self.hits.inner.last().and_then(|hit| hit.sort())
+    }
+
     /** Convert the response into an iterator that consumes the hits. */
     pub fn into_hits(self) -> IntoHits<T> {
         IntoHits::new(self.hits)
@@ -140,6 +210,55 @@ impl<T> SearchResponse<T> {
     pub fn aggs_raw(&self) -> Option<&Value> {
         self.aggregations.as_ref().map(|wrapper| &wrapper.0)
     }
+
+    /**
+    Get the entries for a named suggestion included in the search request.
+    */
+    pub fn suggestion(&self, name: &str) -> Option<&[Suggestion]> {
+        self.suggest
+            .as_ref()
+            .and_then(|suggest| suggest.get(name))
+            .map(|entries| entries.as_slice())
+    }
+
+    /**
+    Get the profile for the search.
+
+    The profile is only included if the search body sets `"profile": true`.
+    */
+    pub fn profile(&self) -> Option<&ProfileResult> {
+        self.profile.as_ref()
+    }
+}
+
+impl<T: DeserializeOwned> SearchResponse<T> {
+    /**
+    Parse a search response from a json string.
+
+    This is a shortcut for tests and samples that have a response body on hand.
+    The body is deserialised directly, so an error body from Elasticsearch is returned as a `serde_json::Error` rather than an `ApiError`.
+    Use `parse` to read responses from Elasticsearch.
+
+    # Examples
+
+    ```
+    # extern crate elastic_responses;
+    # use elastic_responses::SearchResponse;
+    # fn main() {
+    let response: SearchResponse = SearchResponse::from_json(r#"{
+        "took": 1,
+        "timed_out": false,
+        "_shards": { "total": 1, "successful": 1, "failed": 0 },
+        "hits": { "total": 0, "max_score": null, "hits": [] }
+    }"#).unwrap();
+
+    assert_eq!(0, response.total());
+    # }
+    ```
+    */
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
 }
 
 impl<T: DeserializeOwned> IsOk for SearchResponse<T> {
@@ -240,14 +359,20 @@ impl<T> Iterator for IntoDocuments<T> {
 pub struct Hit<T> {
     #[serde(rename = "_index")] index: String,
     #[serde(rename = "_type")] ty: String,
+    #[serde(rename = "_id")] id: String,
     #[serde(rename = "_version")] version: Option<u32>,
     #[serde(rename = "_score")] score: Option<f32>,
     #[serde(rename = "_source")] source: Option<T>,
     #[serde(rename = "_routing")] routing: Option<String>,
+    sort: Option<Vec<Value>>,
 }
 
 impl<T> Hit<T> {
-    /** Get a reference to the source document. */
+    /** 
+    Get a reference to the source document.
+
+    This is `None` if the hit has no `_source`, like when `_source` is disabled for the index or excluded by the request.
+    */
     pub fn document(&self) -> Option<&T> {
         self.source.as_ref()
     }
@@ -267,6 +392,11 @@ impl<T> Hit<T> {
         &self.ty
     }
 
+    /** The id of the hit. */
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
     /** The version of the hit. */
     pub fn version(&self) -> Option<u32> {
         self.version.clone()
@@ -276,6 +406,30 @@ impl<T> Hit<T> {
     pub fn score(&self) -> Option<f32> {
         self.score.clone()
     }
+
+    /** The routing value the hit was indexed with, if it was given one. */
+    pub fn routing(&self) -> Option<&str> {
+        self.routing.as_ref().map(|routing| routing.as_ref())
+    }
+
+    /** The values the hit was sorted by, if the request was sorted. */
+    pub fn sort(&self) -> Option<&[Value]> {
+        self.sort.as_ref().map(|sort| sort.as_ref())
+    }
+}
+
+impl Hit<Box<RawValue>> {
+    /**
+    Deserialise the raw source document into `T`.
+
+    This is `Ok(None)` if the hit has no `_source`.
+    */
+    pub fn parse_source<T: DeserializeOwned>(&self) -> Result<Option<T>, serde_json::Error> {
+        match self.source {
+            Some(ref source) => serde_json::from_str(source.get()).map(Some),
+            None => Ok(None),
+        }
+    }
 }
 
 /** Type Struct to hold a generic `serde_json::Value` tree of the aggregation results. */
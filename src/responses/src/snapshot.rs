@@ -0,0 +1,205 @@
+/*!
+Response types for [snapshot and restore requests](https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html).
+*/
+
+use std::slice::Iter;
+use serde_json::Value;
+
+use common::Shards;
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/** Response for a [create snapshot request](https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_snapshot).
+
+The snapshot is only included if the request waited for it to complete.
+*/
+#[derive(Deserialize, Debug)]
+pub struct SnapshotResponse {
+    #[serde(default)] accepted: bool,
+    snapshot: Option<Snapshot>,
+}
+
+impl SnapshotResponse {
+    /** Whether or not the snapshot was started without waiting for it to complete. */
+    pub fn accepted(&self) -> bool {
+        self.accepted
+    }
+
+    /** Get the completed snapshot. */
+    pub fn snapshot(&self) -> Option<&Snapshot> {
+        self.snapshot.as_ref()
+    }
+}
+
+/** Response for a [get snapshot request](https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_snapshot). */
+#[derive(Deserialize, Debug)]
+pub struct GetSnapshotResponse {
+    snapshots: Vec<Snapshot>,
+}
+
+impl GetSnapshotResponse {
+    /** Iterate over the snapshots. */
+    pub fn snapshots(&self) -> Iter<Snapshot> {
+        self.snapshots.iter()
+    }
+}
+
+/** Response for a [restore snapshot request](https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-snapshots.html#_restore).
+
+The restored snapshot is only included if the request waited for it to complete.
+*/
+#[derive(Deserialize, Debug)]
+pub struct RestoreSnapshotResponse {
+    #[serde(default)] accepted: bool,
+    snapshot: Option<RestoredSnapshot>,
+}
+
+impl RestoreSnapshotResponse {
+    /** Whether or not the restore was started without waiting for it to complete. */
+    pub fn accepted(&self) -> bool {
+        self.accepted
+    }
+
+    /** Get the restored snapshot. */
+    pub fn snapshot(&self) -> Option<&RestoredSnapshot> {
+        self.snapshot.as_ref()
+    }
+}
+
+/** The details of a snapshot. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct Snapshot {
+    snapshot: String,
+    uuid: Option<String>,
+    #[serde(default)] indices: Vec<String>,
+    state: Option<SnapshotState>,
+    start_time_in_millis: Option<u64>,
+    end_time_in_millis: Option<u64>,
+    duration_in_millis: Option<u64>,
+    #[serde(default)] failures: Vec<Value>,
+    shards: Option<Shards>,
+}
+
+impl Snapshot {
+    /** The name of the snapshot. */
+    pub fn name(&self) -> &str {
+        &self.snapshot
+    }
+
+    /** The unique id of the snapshot. */
+    pub fn uuid(&self) -> Option<&str> {
+        self.uuid.as_ref().map(|uuid| uuid.as_ref())
+    }
+
+    /** The indices included in the snapshot. */
+    pub fn indices(&self) -> &[String] {
+        &self.indices
+    }
+
+    /** The state of the snapshot. */
+    pub fn state(&self) -> Option<SnapshotState> {
+        self.state
+    }
+
+    /** Whether or not the snapshot finished and all shards were stored successfully. */
+    pub fn is_success(&self) -> bool {
+        self.state == Some(SnapshotState::Success)
+    }
+
+    /** Whether or not the snapshot finished but some shards couldn't be stored. */
+    pub fn is_partial(&self) -> bool {
+        self.state == Some(SnapshotState::Partial)
+    }
+
+    /** The time the snapshot started, in milliseconds since the epoch. */
+    pub fn start_time_in_millis(&self) -> Option<u64> {
+        self.start_time_in_millis
+    }
+
+    /** The time the snapshot ended, in milliseconds since the epoch. */
+    pub fn end_time_in_millis(&self) -> Option<u64> {
+        self.end_time_in_millis
+    }
+
+    /** How long the snapshot took, in milliseconds. */
+    pub fn duration_in_millis(&self) -> Option<u64> {
+        self.duration_in_millis
+    }
+
+    /** The shard failures for the snapshot. */
+    pub fn failures(&self) -> &[Value] {
+        &self.failures
+    }
+
+    /** Shards metadata for the snapshot. */
+    pub fn shards(&self) -> Option<&Shards> {
+        self.shards.as_ref()
+    }
+}
+
+/** The details of a restored snapshot. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct RestoredSnapshot {
+    snapshot: String,
+    #[serde(default)] indices: Vec<String>,
+    shards: Shards,
+}
+
+impl RestoredSnapshot {
+    /** The name of the restored snapshot. */
+    pub fn name(&self) -> &str {
+        &self.snapshot
+    }
+
+    /** The indices that were restored. */
+    pub fn indices(&self) -> &[String] {
+        &self.indices
+    }
+
+    /** Shards metadata for the restore. */
+    pub fn shards(&self) -> &Shards {
+        &self.shards
+    }
+}
+
+/** The state of a snapshot. */
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum SnapshotState {
+    /** The snapshot is currently running. */
+    #[serde(rename = "IN_PROGRESS")] InProgress,
+    /** The snapshot finished and all shards were stored successfully. */
+    #[serde(rename = "SUCCESS")] Success,
+    /** The snapshot finished with an error and failed to store any data. */
+    #[serde(rename = "FAILED")] Failed,
+    /** The snapshot finished but some shards couldn't be stored. */
+    #[serde(rename = "PARTIAL")] Partial,
+    /** The snapshot was created with an old version of Elasticsearch and can't be restored. */
+    #[serde(rename = "INCOMPATIBLE")] Incompatible,
+}
+
+impl IsOk for SnapshotResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+impl IsOk for GetSnapshotResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+impl IsOk for RestoreSnapshotResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+/*!
+Response types for a [sql request](https://www.elastic.co/guide/en/elasticsearch/reference/current/sql-rest.html).
+*/
+
+use std::collections::HashMap;
+use std::slice::Iter;
+use serde_json::Value;
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/** Response for a [sql request](https://www.elastic.co/guide/en/elasticsearch/reference/current/sql-rest.html). */
+#[derive(Deserialize, Debug)]
+pub struct SqlQueryResponse {
+    #[serde(default)] columns: Vec<SqlColumn>,
+    #[serde(default)] rows: Vec<Vec<Value>>,
+    cursor: Option<String>,
+}
+
+impl SqlQueryResponse {
+    /**
+    The columns in the result.
+
+    Columns are only returned for the first page of results.
+    */
+    pub fn columns(&self) -> Iter<SqlColumn> {
+        self.columns.iter()
+    }
+
+    /** The rows in the result, with values in the same order as the columns. */
+    pub fn rows(&self) -> Iter<Vec<Value>> {
+        self.rows.iter()
+    }
+
+    /** A cursor for fetching the next page of results, if there is one. */
+    pub fn cursor(&self) -> Option<&str> {
+        self.cursor.as_ref().map(|cursor| cursor.as_ref())
+    }
+
+    /**
+    Convert the rows into maps of column names to values.
+
+    Values without a matching column are dropped.
+    */
+    pub fn into_records(self) -> Vec<HashMap<String, Value>> {
+        let columns = self.columns;
+
+        self.rows
+            .into_iter()
+            .map(|row| {
+                columns
+                    .iter()
+                    .map(|column| column.name.clone())
+                    .zip(row)
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/** A column in a sql response. */
+#[derive(Deserialize, Debug)]
+pub struct SqlColumn {
+    name: String,
+    #[serde(rename = "type")] ty: String,
+}
+
+impl SqlColumn {
+    /** The name of the column. */
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /** The Elasticsearch type of the column, like `keyword` or `long`. */
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+}
+
+impl IsOk for SqlQueryResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
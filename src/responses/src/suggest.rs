@@ -0,0 +1,163 @@
+/*!
+Response types for a [suggest request](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-suggesters.html).
+*/
+
+use serde_json::Value;
+
+use common::Shards;
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+use std::collections::BTreeMap;
+use std::slice::Iter;
+
+/** 
+Response for a [suggest request][suggest-req].
+
+Each named suggestion in the request body has a matching set of entries in the response.
+
+# Examples
+
+Iterate over the options for a named suggestion:
+
+```no_run
+# extern crate elastic_responses;
+# use elastic_responses::SuggestResponse;
+# fn do_request() -> SuggestResponse { unimplemented!() }
+# fn main() {
+let response: SuggestResponse = do_request();
+
+for entry in response.suggestion("my-suggest").unwrap_or(&[]) {
+    for option in entry.options() {
+        println!("{} -> {}", entry.text(), option.text());
+    }
+}
+# }
+```
+
+[suggest-req]: https://www.elastic.co/guide/en/elasticsearch/reference/current/search-suggesters.html
+*/
+#[derive(Deserialize, Debug)]
+pub struct SuggestResponse {
+    #[serde(rename = "_shards")] shards: Option<Shards>,
+    #[serde(flatten)] suggestions: BTreeMap<String, Vec<Suggestion>>,
+}
+
+impl SuggestResponse {
+    /** Shards metadata for the request. */
+    pub fn shards(&self) -> Option<&Shards> {
+        self.shards.as_ref()
+    }
+
+    /** Get the entries for a named suggestion. */
+    pub fn suggestion(&self, name: &str) -> Option<&[Suggestion]> {
+        self.suggestions.get(name).map(|entries| entries.as_slice())
+    }
+
+    /** Iterate over the names of all suggestions in the response. */
+    pub fn names(&self) -> Vec<&str> {
+        self.suggestions.keys().map(|name| name.as_str()).collect()
+    }
+}
+
+impl IsOk for SuggestResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
+
+/** The suggested options for a single span of the suggest text. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct Suggestion {
+    text: String,
+    offset: u64,
+    length: u64,
+    options: Vec<SuggestOption>,
+}
+
+impl Suggestion {
+    /** The span of the suggest text these options are for. */
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /** The offset of the span in the suggest text. */
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /** The length of the span in the suggest text. */
+    pub fn length(&self) -> u64 {
+        self.length
+    }
+
+    /** Iterate over the suggested options. */
+    pub fn options(&self) -> Iter<SuggestOption> {
+        self.options.iter()
+    }
+}
+
+/** 
+A single suggested option.
+
+Term and phrase suggestions only populate the `text`, `score` and `freq` or `highlighted` fields.
+Completion suggestions also carry the metadata and source of the suggested document.
+The source is kept as a raw `Value` so the response doesn't need a generic document type.
+*/
+#[derive(Deserialize, Debug, Clone)]
+pub struct SuggestOption {
+    text: String,
+    score: Option<f64>,
+    freq: Option<u64>,
+    highlighted: Option<String>,
+    #[serde(rename = "_index")] index: Option<String>,
+    #[serde(rename = "_type")] ty: Option<String>,
+    #[serde(rename = "_id")] id: Option<String>,
+    #[serde(rename = "_score")] doc_score: Option<f64>,
+    #[serde(rename = "_source")] source: Option<Value>,
+}
+
+impl SuggestOption {
+    /** The suggested text. */
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /** The score of the option. */
+    pub fn score(&self) -> Option<f64> {
+        self.score.or(self.doc_score)
+    }
+
+    /** The document frequency of the suggested term, for term suggestions. */
+    pub fn freq(&self) -> Option<u64> {
+        self.freq
+    }
+
+    /** The highlighted suggested text, for phrase suggestions. */
+    pub fn highlighted(&self) -> Option<&str> {
+        self.highlighted.as_ref().map(|s| s.as_ref())
+    }
+
+    /** The index of the suggested document, for completion suggestions. */
+    pub fn index(&self) -> Option<&str> {
+        self.index.as_ref().map(|s| s.as_ref())
+    }
+
+    /** The type of the suggested document, for completion suggestions. */
+    pub fn ty(&self) -> Option<&str> {
+        self.ty.as_ref().map(|s| s.as_ref())
+    }
+
+    /** The id of the suggested document, for completion suggestions. */
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_ref().map(|s| s.as_ref())
+    }
+
+    /** The source of the suggested document, for completion suggestions. */
+    pub fn document(&self) -> Option<&Value> {
+        self.source.as_ref()
+    }
+}
@@ -0,0 +1,146 @@
+/*!
+Response types for a [list tasks request](https://www.elastic.co/guide/en/elasticsearch/reference/current/tasks.html).
+*/
+
+use std::collections::BTreeMap;
+use std::collections::btree_map::Iter;
+use serde_json::Value;
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/** Response for a [list tasks request](https://www.elastic.co/guide/en/elasticsearch/reference/current/tasks.html).
+
+Tasks are grouped by the node running them.
+*/
+#[derive(Deserialize, Debug)]
+pub struct TasksListResponse {
+    #[serde(default)] nodes: BTreeMap<String, NodeTasks>,
+}
+
+impl TasksListResponse {
+    /** Get the tasks for a node by its id. */
+    pub fn node(&self, id: &str) -> Option<&NodeTasks> {
+        self.nodes.get(id)
+    }
+
+    /** Iterate over the node ids and their tasks. */
+    pub fn nodes(&self) -> Iter<String, NodeTasks> {
+        self.nodes.iter()
+    }
+
+    /**
+    All of the tasks that haven't completed yet, regardless of the node running them.
+
+    Tasks are ordered by node id and then by task id.
+    */
+    pub fn pending_tasks(&self) -> Vec<&TaskStatus> {
+        self.nodes.values().flat_map(|node| node.tasks.values()).collect()
+    }
+}
+
+/** The tasks running on a single node. */
+#[derive(Deserialize, Debug)]
+pub struct NodeTasks {
+    name: String,
+    host: Option<String>,
+    #[serde(default)] tasks: BTreeMap<String, TaskStatus>,
+}
+
+impl NodeTasks {
+    /** The name of the node. */
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /** The host name of the node. */
+    pub fn host(&self) -> Option<&str> {
+        self.host.as_ref().map(|host| host.as_ref())
+    }
+
+    /** Iterate over the task ids and their status. */
+    pub fn tasks(&self) -> Iter<String, TaskStatus> {
+        self.tasks.iter()
+    }
+}
+
+/** The status of a single task. */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct TaskStatus {
+    node: String,
+    id: u64,
+    #[serde(rename = "type")] ty: String,
+    action: String,
+    description: Option<String>,
+    start_time_in_millis: u64,
+    running_time_in_nanos: u64,
+    #[serde(default)] cancellable: bool,
+    parent_task_id: Option<String>,
+    status: Option<Value>,
+}
+
+impl TaskStatus {
+    /** The id of the node running the task. */
+    pub fn node(&self) -> &str {
+        &self.node
+    }
+
+    /** The id of the task on its node. */
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /** The full task id, like `oTUltX4IQMOUUVeiohTt8A:124`, used to get or cancel the task. */
+    pub fn task_id(&self) -> String {
+        format!("{}:{}", self.node, self.id)
+    }
+
+    /** The type of the task, like `transport`. */
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    /** The action the task is performing, like `indices:data/write/reindex`. */
+    pub fn action(&self) -> &str {
+        &self.action
+    }
+
+    /** A description of the task, if detailed task info was requested. */
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_ref().map(|description| description.as_ref())
+    }
+
+    /** The time the task started, in milliseconds since the epoch. */
+    pub fn start_time_in_millis(&self) -> u64 {
+        self.start_time_in_millis
+    }
+
+    /** How long the task has been running, in nanoseconds. */
+    pub fn running_time_in_nanos(&self) -> u64 {
+        self.running_time_in_nanos
+    }
+
+    /** Whether or not the task can be cancelled. */
+    pub fn cancellable(&self) -> bool {
+        self.cancellable
+    }
+
+    /** The full id of the task that started this one, if there is one. */
+    pub fn parent_task_id(&self) -> Option<&str> {
+        self.parent_task_id.as_ref().map(|id| id.as_ref())
+    }
+
+    /** Task-specific progress, like the number of documents a reindex has processed. */
+    pub fn status(&self) -> Option<&Value> {
+        self.status.as_ref()
+    }
+}
+
+impl IsOk for TasksListResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
@@ -0,0 +1,195 @@
+/*!
+Response types for a [term vectors request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-termvectors.html).
+*/
+
+use std::collections::BTreeMap;
+
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/** Response for a [term vectors request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-termvectors.html).
+
+Term vectors are keyed by field name.
+*/
+#[derive(Deserialize, Debug)]
+pub struct TermVectorsResponse {
+    #[serde(rename = "_index")] index: String,
+    #[serde(rename = "_type")] ty: String,
+    #[serde(rename = "_id")] id: Option<String>,
+    #[serde(rename = "_version")] version: Option<u32>,
+    found: bool,
+    took: Option<u64>,
+    #[serde(default)] term_vectors: BTreeMap<String, FieldTermVectors>,
+}
+
+impl TermVectorsResponse {
+    /** The index for the document. */
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /** The type for the document. */
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    /** 
+    The id for the document.
+
+    Artificial documents that were sent in the request body don't have an id.
+    */
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_ref().map(|id| id.as_ref())
+    }
+
+    /** The version of the document. */
+    pub fn version(&self) -> Option<u32> {
+        self.version
+    }
+
+    /** Whether or not the document was found. */
+    pub fn found(&self) -> bool {
+        self.found
+    }
+
+    /** The time taken to compute the term vectors in milliseconds. */
+    pub fn took(&self) -> Option<u64> {
+        self.took
+    }
+
+    /** Get the term vectors for a field. */
+    pub fn field(&self, field: &str) -> Option<&FieldTermVectors> {
+        self.term_vectors.get(field)
+    }
+
+    /** The term vectors for each field. */
+    pub fn term_vectors(&self) -> &BTreeMap<String, FieldTermVectors> {
+        &self.term_vectors
+    }
+}
+
+/** The term vectors for a single field. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct FieldTermVectors {
+    field_statistics: Option<FieldStatistics>,
+    #[serde(default)] terms: BTreeMap<String, TermEntry>,
+}
+
+impl FieldTermVectors {
+    /** Statistics for the field across the index, if they were requested. */
+    pub fn field_statistics(&self) -> Option<&FieldStatistics> {
+        self.field_statistics.as_ref()
+    }
+
+    /** Get a term in the field. */
+    pub fn term(&self, term: &str) -> Option<&TermEntry> {
+        self.terms.get(term)
+    }
+
+    /** The terms in the field. */
+    pub fn terms(&self) -> &BTreeMap<String, TermEntry> {
+        &self.terms
+    }
+}
+
+/** Statistics for a field across the index. */
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct FieldStatistics {
+    sum_doc_freq: u64,
+    doc_count: u64,
+    sum_ttf: u64,
+}
+
+impl FieldStatistics {
+    /** The sum of the document frequencies of all terms in the field. */
+    pub fn sum_doc_freq(&self) -> u64 {
+        self.sum_doc_freq
+    }
+
+    /** The number of documents that have at least one term in the field. */
+    pub fn doc_count(&self) -> u64 {
+        self.doc_count
+    }
+
+    /** The sum of the total term frequencies of all terms in the field. */
+    pub fn sum_ttf(&self) -> u64 {
+        self.sum_ttf
+    }
+}
+
+/** A single term in a field. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct TermEntry {
+    term_freq: u64,
+    doc_freq: Option<u64>,
+    ttf: Option<u64>,
+    score: Option<f64>,
+    #[serde(default)] tokens: Vec<TermToken>,
+}
+
+impl TermEntry {
+    /** The number of times the term appears in the field of this document. */
+    pub fn term_freq(&self) -> u64 {
+        self.term_freq
+    }
+
+    /** The number of documents that contain the term, if term statistics were requested. */
+    pub fn doc_freq(&self) -> Option<u64> {
+        self.doc_freq
+    }
+
+    /** The number of times the term appears across the index, if term statistics were requested. */
+    pub fn ttf(&self) -> Option<u64> {
+        self.ttf
+    }
+
+    /** The score of the term, if terms were filtered. */
+    pub fn score(&self) -> Option<f64> {
+        self.score
+    }
+
+    /** The positions and offsets of each occurrence of the term. */
+    pub fn tokens(&self) -> &[TermToken] {
+        &self.tokens
+    }
+}
+
+/** A single occurrence of a term. */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct TermToken {
+    position: Option<u32>,
+    start_offset: Option<u32>,
+    end_offset: Option<u32>,
+    payload: Option<String>,
+}
+
+impl TermToken {
+    /** The position of the token, if positions were requested. */
+    pub fn position(&self) -> Option<u32> {
+        self.position
+    }
+
+    /** The character offset the token starts at, if offsets were requested. */
+    pub fn start_offset(&self) -> Option<u32> {
+        self.start_offset
+    }
+
+    /** The character offset the token ends at, if offsets were requested. */
+    pub fn end_offset(&self) -> Option<u32> {
+        self.end_offset
+    }
+
+    /** The base64 encoded payload of the token, if payloads were requested. */
+    pub fn payload(&self) -> Option<&str> {
+        self.payload.as_ref().map(|payload| payload.as_ref())
+    }
+}
+
+impl IsOk for TermVectorsResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
@@ -3,7 +3,7 @@ Response types for a [update document request](https://www.elastic.co/guide/en/e
 */
 
 use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
-use common::DocumentResult;
+use common::WriteResult;
 use error::*;
 
 /** Response for a [update document request](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-update.html). */
@@ -14,14 +14,14 @@ pub struct UpdateResponse {
     #[serde(rename = "_id")] id: String,
     #[serde(rename = "_version")] version: Option<u32>,
     #[serde(rename = "_routing")] routing: Option<String>,
-    result: DocumentResult,
+    result: WriteResult,
 }
 
 impl UpdateResponse {
     /** Whether or not the document was updated. */
     pub fn updated(&self) -> bool {
         match self.result {
-            DocumentResult::Updated => true,
+            WriteResult::Updated => true,
             _ => false,
         }
     }
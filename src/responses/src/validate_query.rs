@@ -0,0 +1,120 @@
+/*!
+Response types for a [validate query request](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-validate.html).
+*/
+
+use serde::{Deserialize, Deserializer};
+
+use common::Shards;
+use parsing::{HttpResponseHead, IsOk, MaybeOkResponse, ResponseBody, Unbuffered};
+use error::*;
+
+/** Response for a [validate query request](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-validate.html). */
+#[derive(Deserialize, Debug)]
+pub struct ValidateQueryResponse {
+    valid: bool,
+    #[serde(rename = "_shards")] shards: Option<Shards>,
+    #[serde(default)] explanations: Vec<ValidationExplanation>,
+}
+
+impl ValidateQueryResponse {
+    /** Whether or not the query is valid. */
+    pub fn valid(&self) -> bool {
+        self.valid
+    }
+
+    /** Shards metadata for the request. */
+    pub fn shards(&self) -> Option<&Shards> {
+        self.shards.as_ref()
+    }
+
+    /** 
+    Explanations for each index the query was validated against.
+
+    Explanations are only returned if the request set `explain`.
+    */
+    pub fn explanations(&self) -> &[ValidationExplanation] {
+        &self.explanations
+    }
+}
+
+/** 
+The result of validating a query against a single index.
+
+A valid query is explained by the query it was rewritten to.
+An invalid query always carries the error that made it invalid.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationExplanation {
+    /** The query is valid for the index. */
+    Valid {
+        /** The index the query was validated against. */
+        index: String,
+        /** The query as it was parsed by Elasticsearch. */
+        explanation: String,
+    },
+    /** The query is invalid for the index. */
+    Invalid {
+        /** The index the query was validated against. */
+        index: String,
+        /** The reason the query is invalid. */
+        error: String,
+    },
+}
+
+impl ValidationExplanation {
+    /** The index the query was validated against. */
+    pub fn index(&self) -> &str {
+        match *self {
+            ValidationExplanation::Valid { ref index, .. } => index,
+            ValidationExplanation::Invalid { ref index, .. } => index,
+        }
+    }
+
+    /** Whether or not the query is valid for the index. */
+    pub fn valid(&self) -> bool {
+        match *self {
+            ValidationExplanation::Valid { .. } => true,
+            ValidationExplanation::Invalid { .. } => false,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ValidationExplanation {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct RawExplanation {
+            #[serde(default)] index: String,
+            valid: bool,
+            #[serde(default)] explanation: String,
+            #[serde(default)] error: String,
+        }
+
+        let raw = RawExplanation::deserialize(deserializer)?;
+
+        let explanation = if raw.valid {
+            ValidationExplanation::Valid {
+                index: raw.index,
+                explanation: raw.explanation,
+            }
+        } else {
+            ValidationExplanation::Invalid {
+                index: raw.index,
+                error: raw.error,
+            }
+        };
+
+        Ok(explanation)
+    }
+}
+
+impl IsOk for ValidateQueryResponse {
+    fn is_ok<B: ResponseBody>(head: HttpResponseHead, body: Unbuffered<B>) -> Result<MaybeOkResponse<B>, ParseResponseError> {
+        match head.status() {
+            200...299 => Ok(MaybeOkResponse::ok(body)),
+            _ => Ok(MaybeOkResponse::err(body)),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_get_alias() {
+    let f = load_file("tests/samples/get_alias.json");
+    let deserialized = parse::<GetAliasResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!(2, deserialized.indices().count());
+    assert_eq!(2, deserialized.index("users").unwrap().len());
+
+    let alias = deserialized.alias("users", "user_12").unwrap();
+
+    assert_eq!(Some(&json!({ "term": { "user_id": 12 } })), alias.filter());
+    assert_eq!(Some("1,2"), alias.search_routing());
+    assert_eq!(Some("2"), alias.index_routing());
+}
+
+#[test]
+fn success_parse_get_alias_without_config() {
+    let f = load_file("tests/samples/get_alias.json");
+    let deserialized = parse::<GetAliasResponse>().from_reader(200, f).unwrap();
+
+    let alias = deserialized.alias("logs-000002", "logs").unwrap();
+
+    assert_eq!(None, alias.filter());
+    assert_eq!(None, alias.search_routing());
+    assert_eq!(None, alias.index_routing());
+
+    assert!(deserialized.alias("logs-000002", "missing").is_none());
+}
@@ -0,0 +1,19 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_analyze() {
+    let f = load_file("tests/samples/analyze.json");
+    let deserialized = parse::<AnalyzeResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!(vec!["quick", "brown", "fox"], deserialized.token_strings());
+
+    let token = deserialized.tokens().last().unwrap();
+    assert_eq!(12, token.start_offset());
+    assert_eq!(15, token.end_offset());
+    assert_eq!("<ALPHANUM>", token.ty());
+    assert_eq!(2, token.position());
+}
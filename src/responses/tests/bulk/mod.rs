@@ -72,6 +72,48 @@ fn success_parse_with_errors() {
     assert_eq!(1, deserialized.iter().filter(Result::is_ok).count());
 }
 
+#[test]
+fn success_parse_with_multiple_errors() {
+    let f = load_file("tests/samples/bulk_multiple_errors.json");
+    let deserialized = parse::<BulkResponse>().from_reader(200, f).unwrap();
+
+    assert!(deserialized.has_errors());
+    assert_eq!(2, deserialized.error_count());
+    assert_eq!(3, deserialized.iter().count());
+}
+
+#[test]
+fn success_parse_with_multiple_errors_failed_items() {
+    let f = load_file("tests/samples/bulk_multiple_errors.json");
+    let deserialized = parse::<BulkResponse>().from_reader(200, f).unwrap();
+
+    let failed: Vec<_> = deserialized.failed_items().collect();
+
+    assert_eq!(2, failed.len());
+    assert_eq!("1", failed[0].id());
+    assert_eq!(409, failed[0].status());
+    assert_eq!("version_conflict_engine_exception", failed[0].error()["type"]);
+    assert_eq!("8", failed[1].id());
+    assert_eq!(404, failed[1].status());
+
+    let successful: Vec<_> = deserialized.successful_items().collect();
+
+    assert_eq!(1, successful.len());
+    assert_eq!("6", successful[0].id());
+
+    assert!((deserialized.error_rate() - 2.0 / 3.0).abs() < 1e-9);
+}
+
+#[test]
+fn success_parse_without_errors_error_count() {
+    let f = load_file("tests/samples/bulk_index.json");
+    let deserialized = parse::<BulkResponse>().from_reader(200, f).unwrap();
+
+    assert!(!deserialized.has_errors());
+    assert_eq!(0, deserialized.error_count());
+    assert_eq!(0.0, deserialized.error_rate());
+}
+
 #[test]
 fn success_parse_with_errors_errors_only() {
     let f = load_file("tests/samples/bulk_error.json");
@@ -0,0 +1,99 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_cat_indices() {
+    let f = load_file("tests/samples/cat_indices.json");
+    let deserialized = parse::<Vec<CatIndicesRow>>().from_reader(200, f).unwrap();
+
+    assert_eq!(2, deserialized.len());
+
+    let open = &deserialized[0];
+    assert_eq!("twitter", open.index());
+    assert_eq!(Some("yellow"), open.health());
+    assert_eq!(Some("open"), open.status());
+    assert_eq!(Some(1), open.pri());
+    assert_eq!(Some(1200), open.docs_count());
+    assert_eq!(Some("88.1kb"), open.store_size());
+
+    let closed = &deserialized[1];
+    assert_eq!(Some("close"), closed.status());
+    assert_eq!(None, closed.health());
+    assert_eq!(None, closed.docs_count());
+}
+
+#[test]
+fn success_parse_cat_indices_filter_by_health() {
+    let f = load_file("tests/samples/cat_indices_mixed_health.json");
+    let deserialized = parse::<CatIndicesResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!(5, deserialized.rows().len());
+
+    let red: Vec<_> = deserialized.filter_by_health(ClusterHealthStatus::Red).iter().map(|row| row.index()).collect();
+    assert_eq!(vec!["logs-000002", "metrics"], red);
+
+    let green = deserialized.filter_by_health(ClusterHealthStatus::Green);
+    assert_eq!(1, green.len());
+    assert_eq!("logs-000001", green[0].index());
+
+    assert_eq!(None, deserialized.rows()[4].health_status());
+}
+
+#[test]
+fn success_parse_cat_nodes() {
+    let f = load_file("tests/samples/cat_nodes.json");
+    let deserialized = parse::<Vec<CatNodesRow>>().from_reader(200, f).unwrap();
+
+    let node = &deserialized[0];
+    assert_eq!("mJw06l1", node.name());
+    assert_eq!(Some(65), node.heap_percent());
+    assert_eq!(Some("mdi"), node.node_role());
+    assert!(node.is_master());
+}
+
+#[test]
+fn success_parse_cat_shards() {
+    let f = load_file("tests/samples/cat_shards.json");
+    let deserialized = parse::<Vec<CatShardsRow>>().from_reader(200, f).unwrap();
+
+    let primary = &deserialized[0];
+    assert!(primary.is_primary());
+    assert_eq!(Some(0), primary.shard());
+    assert_eq!("STARTED", primary.state());
+    assert_eq!(Some(1200), primary.docs());
+
+    let replica = &deserialized[1];
+    assert!(!replica.is_primary());
+    assert_eq!("UNASSIGNED", replica.state());
+    assert_eq!(None, replica.node());
+}
+
+#[test]
+fn success_parse_cat_shards_unassigned() {
+    let f = load_file("tests/samples/cat_shards_mixed.json");
+    let deserialized = parse::<CatShardsResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!(3, deserialized.rows().len());
+
+    let unassigned = deserialized.unassigned_shards();
+    assert_eq!(1, unassigned.len());
+    assert_eq!(Some(1), unassigned[0].shard());
+    assert!(!unassigned[0].is_primary());
+}
+
+#[test]
+fn success_parse_cat_health() {
+    let f = load_file("tests/samples/cat_health.json");
+    let deserialized = parse::<Vec<CatHealthRow>>().from_reader(200, f).unwrap();
+
+    let health = &deserialized[0];
+    assert_eq!("elasticsearch", health.cluster());
+    assert_eq!("green", health.status());
+    assert_eq!(Some(1), health.node_total());
+    assert_eq!(Some(5), health.shards());
+    assert_eq!(Some(0), health.unassign());
+    assert_eq!(Some("100.0%"), health.active_shards_percent());
+}
@@ -0,0 +1,37 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_cluster_health() {
+    let f = load_file("tests/samples/cluster_health.json");
+    let deserialized = parse::<ClusterHealthResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!("testcluster", deserialized.cluster_name());
+    assert_eq!(ClusterHealthStatus::Yellow, deserialized.status());
+    assert!(deserialized.is_healthy());
+    assert!(!deserialized.is_green());
+    assert!(!deserialized.is_red());
+    assert!(!deserialized.timed_out());
+
+    assert_eq!(1, deserialized.number_of_nodes());
+    assert_eq!(5, deserialized.active_primary_shards());
+    assert_eq!(5, deserialized.unassigned_shards());
+}
+
+#[test]
+fn success_parse_cluster_health_red_timed_out() {
+    let f = load_file("tests/samples/cluster_health_red_timed_out.json");
+    let deserialized = parse::<ClusterHealthResponse>().from_reader(408, f).unwrap();
+
+    assert_eq!(ClusterHealthStatus::Red, deserialized.status());
+    assert!(!deserialized.is_healthy());
+    assert!(!deserialized.is_green());
+    assert!(deserialized.is_red());
+    assert!(deserialized.timed_out());
+
+    assert_eq!(2, deserialized.initializing_shards());
+    assert_eq!(1, deserialized.number_of_pending_tasks());
+}
@@ -0,0 +1,36 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_cluster_reroute() {
+    let f = load_file("tests/samples/cluster_reroute.json");
+    let deserialized = parse::<ClusterRerouteResponse>().from_reader(200, f).unwrap();
+
+    assert!(deserialized.acknowledged());
+    assert_eq!(2, deserialized.rerouted_shard_count());
+
+    let routing_table = deserialized.routing_table().unwrap();
+
+    assert_eq!(2, routing_table.indices().count());
+    assert_eq!(8, routing_table.shards().count());
+
+    let relocating = routing_table.shards().find(|shard| shard.state() == "RELOCATING").unwrap();
+
+    assert_eq!("logs-000001", relocating.index());
+    assert_eq!(0, relocating.shard());
+    assert!(relocating.primary());
+    assert_eq!(Some("Bp2MJ8bQQ269uSuoI-U04A"), relocating.relocating_node());
+}
+
+#[test]
+fn success_parse_cluster_reroute_without_state() {
+    let deserialized = parse::<ClusterRerouteResponse>()
+        .from_slice(200, br#"{ "acknowledged": true }"#.as_ref())
+        .unwrap();
+
+    assert!(deserialized.routing_table().is_none());
+    assert_eq!(0, deserialized.rerouted_shard_count());
+}
@@ -11,3 +11,39 @@ fn success_parse_command_response() {
 
     assert!(deserialized.acknowledged());
 }
+
+#[test]
+fn success_parse_acknowledged_response() {
+    let f = load_file("tests/samples/acknowledged_shards.json");
+    let deserialized = parse::<AcknowledgedResponse>().from_reader(200, f).unwrap();
+
+    assert!(deserialized.acknowledged());
+    assert!(deserialized.shards_acknowledged());
+}
+
+#[test]
+fn success_parse_acknowledged_response_without_shards() {
+    let f = load_file("tests/samples/acknowledged.json");
+    let deserialized = parse::<AcknowledgedResponse>().from_reader(200, f).unwrap();
+
+    assert!(deserialized.acknowledged());
+    assert!(!deserialized.shards_acknowledged());
+}
+
+#[test]
+fn success_parse_shards_response() {
+    let f = load_file("tests/samples/refresh.json");
+    let deserialized = parse::<ShardsResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!(10, deserialized.shards().total());
+    assert_eq!(5, deserialized.shards().successful());
+    assert_eq!(0, deserialized.shards().failed());
+}
+
+#[test]
+fn success_parse_forcemerge_response() {
+    let f = load_file("tests/samples/refresh.json");
+    let deserialized = parse::<ForcemergeResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!(10, deserialized.shards().total());
+}
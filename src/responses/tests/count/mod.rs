@@ -0,0 +1,30 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use elastic_responses::error::*;
+use load_file;
+
+#[test]
+fn success_parse_count() {
+    let f = load_file("tests/samples/count.json");
+    let deserialized = parse::<CountResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!(42, deserialized.count());
+    assert_eq!(5, deserialized.shards().total());
+    assert_eq!(5, deserialized.shards().successful());
+    assert_eq!(0, deserialized.shards().failed());
+}
+
+#[test]
+fn error_parse_count_index_not_found() {
+    let f = load_file("tests/samples/error_index_not_found.json");
+    let deserialized = parse::<CountResponse>().from_reader(404, f).unwrap_err();
+
+    let valid = match deserialized {
+        ResponseError::Api(ApiError::IndexNotFound { ref index }) if index == "carrots" => true,
+        _ => false,
+    };
+
+    assert!(valid);
+}
@@ -0,0 +1,21 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_eql_search() {
+    let f = load_file("tests/samples/eql_search.json");
+    let deserialized = parse::<EqlSearchResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!(6, deserialized.took());
+    assert_eq!(
+        vec!["OQmfCaduce8zoHT93o4H", "xLkCaj4EujzdNSxfYLbO", "yDwnGIJouOYGBzP0ZE9n"],
+        deserialized.event_ids()
+    );
+
+    let event = deserialized.events().next().unwrap();
+    assert_eq!("my-index-000001", event.index());
+    assert_eq!(Some(&json!({ "process": { "name": "cmd.exe" } })), event.document());
+}
@@ -0,0 +1,30 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_document_exists() {
+    let deserialized = parse::<ExistsResponse>().from_slice(200, b"").unwrap();
+
+    assert!(deserialized.exists());
+}
+
+#[test]
+fn success_parse_document_not_exists() {
+    let deserialized = parse::<ExistsResponse>().from_slice(404, b"").unwrap();
+
+    assert!(!deserialized.exists());
+}
+
+#[test]
+fn success_parse_bulk_exists() {
+    let f = load_file("tests/samples/bulk_exists.json");
+    let deserialized = parse::<BulkExistsResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!(Some(true), deserialized.exists("1"));
+    assert_eq!(Some(false), deserialized.exists("2"));
+    assert_eq!(None, deserialized.exists("3"));
+    assert_eq!(2, deserialized.iter().count());
+}
@@ -0,0 +1,59 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use elastic_responses::error::*;
+use load_file;
+
+#[test]
+fn success_parse_matched() {
+    let f = load_file("tests/samples/explain_matched.json");
+    let deserialized = parse::<ExplainResponse>().from_reader(200, f).unwrap();
+
+    assert!(deserialized.matched());
+    assert_eq!("testindex", deserialized.index());
+    assert_eq!("testtype", deserialized.ty());
+    assert_eq!("1", deserialized.id());
+
+    let explanation = deserialized.explanation().unwrap();
+
+    assert_eq!(1.55077, explanation.value());
+    assert_eq!("sum of:", explanation.description());
+    assert_eq!(2, explanation.details().count());
+}
+
+#[test]
+fn success_parse_nested_details() {
+    let f = load_file("tests/samples/explain_matched.json");
+    let deserialized = parse::<ExplainResponse>().from_reader(200, f).unwrap();
+
+    let depth = deserialized
+        .explanation()
+        .and_then(|e| e.details().next())
+        .and_then(|e| e.details().next())
+        .map(|e| e.details().count());
+
+    assert_eq!(Some(2), depth);
+}
+
+#[test]
+fn success_parse_not_found() {
+    let f = load_file("tests/samples/explain_not_found.json");
+    let deserialized = parse::<ExplainResponse>().from_reader(404, f).unwrap();
+
+    assert!(!deserialized.matched());
+    assert!(deserialized.explanation().is_none());
+}
+
+#[test]
+fn error_parse_index_not_found() {
+    let f = load_file("tests/samples/error_index_not_found.json");
+    let deserialized = parse::<ExplainResponse>().from_reader(404, f).unwrap_err();
+
+    let valid = match deserialized {
+        ResponseError::Api(ApiError::IndexNotFound { ref index }) if index == "carrots" => true,
+        _ => false,
+    };
+
+    assert!(valid);
+}
@@ -0,0 +1,52 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use elastic_responses::error::*;
+use load_file;
+
+#[test]
+fn success_parse_field_caps() {
+    let f = load_file("tests/samples/field_caps.json");
+    let deserialized = parse::<FieldCapsResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!(vec!["rating", "title"], deserialized.fields().keys().map(|k| k.as_ref()).collect::<Vec<&str>>());
+
+    let title = &deserialized.field("title").unwrap()["text"];
+
+    assert_eq!("text", title.type_());
+    assert!(title.searchable());
+    assert!(!title.aggregatable());
+    assert_eq!(None, title.indices());
+    assert_eq!(None, title.non_searchable_indices());
+}
+
+#[test]
+fn success_parse_field_caps_conflicting_types() {
+    let f = load_file("tests/samples/field_caps.json");
+    let deserialized = parse::<FieldCapsResponse>().from_reader(200, f).unwrap();
+
+    let rating = deserialized.field("rating").unwrap();
+    assert_eq!(2, rating.len());
+
+    let long = &rating["long"];
+    assert_eq!(Some(&["index1".to_owned(), "index2".to_owned()][..]), long.indices());
+    assert_eq!(Some(&["index1".to_owned()][..]), long.non_aggregatable_indices());
+    assert_eq!(None, long.non_searchable_indices());
+
+    let keyword = &rating["keyword"];
+    assert_eq!(Some(&["index4".to_owned()][..]), keyword.non_searchable_indices());
+}
+
+#[test]
+fn error_parse_field_caps_index_not_found() {
+    let f = load_file("tests/samples/error_index_not_found.json");
+    let deserialized = parse::<FieldCapsResponse>().from_reader(404, f).unwrap_err();
+
+    let valid = match deserialized {
+        ResponseError::Api(ApiError::IndexNotFound { ref index }) if index == "carrots" => true,
+        _ => false,
+    };
+
+    assert!(valid);
+}
@@ -60,3 +60,83 @@ fn error_parse_index_not_found() {
 
     assert!(valid);
 }
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Doc {
+    id: i32,
+}
+
+#[test]
+fn success_parse_found_doc_of_t() {
+    let f = load_file("tests/samples/get_found.json");
+    let deserialized = parse::<GetResponse<Doc>>().from_reader(200, f).unwrap();
+
+    assert!(deserialized.found());
+    assert_eq!(None, deserialized.routing());
+    assert_eq!(Some(Doc { id: 1 }), deserialized.into_document());
+}
+
+#[test]
+fn success_parse_not_found_doc_of_t() {
+    let f = load_file("tests/samples/get_not_found.json");
+    let deserialized = parse::<GetResponse<Doc>>().from_reader(404, f).unwrap();
+
+    assert!(!deserialized.found());
+    assert_eq!(None, deserialized.into_document());
+}
+
+#[test]
+fn success_parse_found_exists_into_source() {
+    let f = load_file("tests/samples/get_found.json");
+    let deserialized = parse::<GetResponse<Doc>>().from_reader(200, f).unwrap();
+
+    assert!(deserialized.exists());
+    assert_eq!(Some(Doc { id: 1 }), deserialized.into_source());
+}
+
+#[test]
+fn success_parse_not_found_exists_into_source() {
+    let f = load_file("tests/samples/get_not_found.json");
+    let deserialized = parse::<GetResponse<Doc>>().from_reader(404, f).unwrap();
+
+    assert!(!deserialized.exists());
+    assert_eq!(None, deserialized.into_source());
+}
+
+#[test]
+fn success_parse_untyped_default() {
+    let f = load_file("tests/samples/get_found.json");
+    let deserialized: GetResponse = parse::<GetResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!(Some(&json!({ "id": 1 })), deserialized.document());
+}
+
+#[test]
+fn success_parse_source_doc_of_t() {
+    let f = load_file("tests/samples/get_source.json");
+    let deserialized = parse::<GetSourceResponse<Doc>>().from_reader(200, f).unwrap();
+
+    assert_eq!(&Doc { id: 1 }, deserialized.document());
+    assert_eq!(Doc { id: 1 }, deserialized.into_document());
+}
+
+#[test]
+fn success_parse_source_untyped_default() {
+    let f = load_file("tests/samples/get_source.json");
+    let deserialized: GetSourceResponse = parse::<GetSourceResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!(&json!({ "id": 1 }), deserialized.document());
+}
+
+#[test]
+fn error_parse_source_not_found() {
+    let f = load_file("tests/samples/error_get_source_not_found.json");
+    let deserialized = parse::<GetSourceResponse<Doc>>().from_reader(404, f).unwrap_err();
+
+    let valid = match deserialized {
+        ResponseError::Api(ApiError::Other(ref err)) => err["type"] == "resource_not_found_exception",
+        _ => false,
+    };
+
+    assert!(valid);
+}
@@ -17,6 +17,49 @@ fn success_parse_response() {
     assert_eq!(Some(1), deserialized.version());
 }
 
+#[test]
+fn success_parse_created_result() {
+    let f = load_file("tests/samples/index_created.json");
+    let deserialized = parse::<IndexResponse>().from_reader(201, f).unwrap();
+
+    assert!(deserialized.created());
+    assert!(deserialized.was_created());
+    assert!(!deserialized.was_updated());
+    assert_eq!(Some(WriteResult::Created), deserialized.result());
+    assert_eq!("AVdepUXTLyQ-FjMslkmf", deserialized.id());
+}
+
+#[test]
+fn success_parse_updated_result() {
+    let f = load_file("tests/samples/index_updated.json");
+    let deserialized = parse::<IndexResponse>().from_reader(200, f).unwrap();
+
+    assert!(!deserialized.created());
+    assert!(!deserialized.was_created());
+    assert!(deserialized.was_updated());
+    assert_eq!(Some(WriteResult::Updated), deserialized.result());
+    assert_eq!(Some(2), deserialized.version());
+}
+
+#[test]
+fn success_parse_seq_no_primary_term() {
+    let f = load_file("tests/samples/index_seq_no.json");
+    let deserialized = parse::<IndexResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!(Some(5), deserialized.version());
+    assert_eq!(Some(10), deserialized.seq_no());
+    assert_eq!(Some(2), deserialized.primary_term());
+}
+
+#[test]
+fn success_parse_without_seq_no() {
+    let f = load_file("tests/samples/index_success.json");
+    let deserialized = parse::<IndexResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!(None, deserialized.seq_no());
+    assert_eq!(None, deserialized.primary_term());
+}
+
 #[test]
 fn error_parse_mapping() {
     let f = load_file("tests/samples/error_mapper_parsing.json");
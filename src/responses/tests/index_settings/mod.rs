@@ -0,0 +1,35 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use elastic_responses::error::*;
+use load_file;
+
+#[test]
+fn success_parse_index_settings() {
+    let f = load_file("tests/samples/index_settings.json");
+    let deserialized = parse::<IndexSettingsResponse>().from_reader(200, f).unwrap();
+
+    let settings = deserialized.index("twitter").unwrap();
+    let index = settings.index_settings().unwrap();
+
+    assert_eq!("5", index["number_of_shards"]);
+    assert_eq!("30s", index["refresh_interval"]);
+    assert_eq!("async", index["translog"]["durability"]);
+    assert_eq!("async", settings.settings()["index"]["translog"]["durability"]);
+
+    assert_eq!(1, deserialized.indices().count());
+}
+
+#[test]
+fn error_parse_index_settings_index_not_found() {
+    let f = load_file("tests/samples/error_index_not_found.json");
+    let deserialized = parse::<IndexSettingsResponse>().from_reader(404, f).unwrap_err();
+
+    let valid = match deserialized {
+        ResponseError::Api(ApiError::IndexNotFound { ref index }) if index == "carrots" => true,
+        _ => false,
+    };
+
+    assert!(valid);
+}
@@ -0,0 +1,35 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_index_template() {
+    let f = load_file("tests/samples/index_template.json");
+    let deserialized = parse::<IndexTemplateResponse>().from_reader(200, f).unwrap();
+
+    let template = deserialized.template("template_1").unwrap();
+
+    assert_eq!(0, template.order());
+    assert_eq!(&["te*".to_owned(), "bar*".to_owned()], template.index_patterns());
+    assert_eq!("1", template.settings()["index"]["number_of_shards"]);
+    assert_eq!(false, template.mappings()["type1"]["_source"]["enabled"]);
+    assert_eq!(1, deserialized.templates().count());
+}
+
+#[test]
+fn success_parse_index_template_names() {
+    let f = load_file("tests/samples/index_template_multiple.json");
+    let deserialized = parse::<IndexTemplateResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!(vec!["logs", "metrics", "traces"], deserialized.template_names());
+}
+
+#[test]
+fn success_parse_empty_index_template() {
+    let deserialized = parse::<IndexTemplateResponse>().from_slice(200, b"{}").unwrap();
+
+    assert!(deserialized.template("template_1").is_none());
+    assert!(deserialized.template_names().is_empty());
+}
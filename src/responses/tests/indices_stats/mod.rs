@@ -0,0 +1,35 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use elastic_responses::error::*;
+use load_file;
+
+#[test]
+fn success_parse_indices_stats() {
+    let f = load_file("tests/samples/indices_stats.json");
+    let deserialized = parse::<IndicesStatsResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!(150, deserialized.total_doc_count());
+    assert_eq!(150, deserialized.all().primaries().doc_count());
+    assert_eq!(2, deserialized.indices().count());
+
+    let index = deserialized.index("logs-000002").unwrap();
+
+    assert_eq!(50, index.primaries().doc_count());
+    assert_eq!(1, index.primaries().deleted_doc_count());
+    assert_eq!(Some(16070), index.total().store_size_in_bytes());
+}
+
+#[test]
+fn error_parse_indices_stats_index_not_found() {
+    let f = load_file("tests/samples/error_index_not_found.json");
+    let deserialized = parse::<IndicesStatsResponse>().from_reader(404, f).unwrap_err();
+
+    let valid = match deserialized {
+        ResponseError::Api(ApiError::IndexNotFound { .. }) => true,
+        _ => false,
+    };
+
+    assert!(valid);
+}
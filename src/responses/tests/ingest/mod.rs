@@ -0,0 +1,43 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use serde_json::Value;
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_get_pipeline() {
+    let f = load_file("tests/samples/get_pipeline.json");
+    let deserialized = parse::<GetPipelineResponse>().from_reader(200, f).unwrap();
+
+    let pipeline = deserialized.pipeline("my-pipeline-id").unwrap();
+
+    assert_eq!(Some("describe pipeline"), pipeline.description());
+    assert_eq!(1, pipeline.processors().len());
+    assert_eq!("foo", pipeline.processors()[0]["set"]["field"]);
+}
+
+#[test]
+fn success_parse_get_pipeline_not_found() {
+    let deserialized = parse::<GetPipelineResponse>().from_slice(404, b"{}").unwrap();
+
+    assert!(deserialized.pipelines().is_empty());
+}
+
+#[test]
+fn success_parse_simulate_pipeline() {
+    let f = load_file("tests/samples/simulate_pipeline.json");
+    let deserialized = parse::<SimulatePipelineResponse<Value>>().from_reader(200, f).unwrap();
+
+    let docs: Vec<_> = deserialized.docs().collect();
+    assert_eq!(2, docs.len());
+
+    let doc = docs[0].doc().unwrap();
+    assert_eq!("index", doc.index());
+    assert_eq!("bar", doc.document()["foo"]);
+    assert_eq!("2017-05-04T22:30:03.187Z", doc.ingest().unwrap().timestamp());
+    assert!(docs[0].error().is_none());
+
+    assert!(docs[1].doc().is_none());
+    assert_eq!("illegal_argument_exception", docs[1].error().unwrap()["type"]);
+}
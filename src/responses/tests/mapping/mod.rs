@@ -0,0 +1,39 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use elastic_responses::error::*;
+use load_file;
+
+#[test]
+fn success_parse_get_mapping() {
+    let f = load_file("tests/samples/get_mapping.json");
+    let deserialized = parse::<GetMappingResponse>().from_reader(200, f).unwrap();
+
+    let mapping = deserialized.index("twitter").unwrap().ty("tweet").unwrap();
+
+    let message = mapping.property("message").unwrap();
+    assert_eq!(Some("text"), message.type_());
+    assert_eq!(Some("english"), message.analyzer());
+    assert_eq!(Some("keyword"), message.fields()["raw"].type_());
+
+    let user = mapping.property("user").unwrap();
+    assert_eq!(None, user.type_());
+    assert_eq!(Some("keyword"), user.properties()["name"].type_());
+    assert_eq!(Some(false), user.properties()["name"].index());
+    assert_eq!(Some("integer"), user.properties()["age"].type_());
+    assert_eq!(None, user.properties()["age"].index());
+}
+
+#[test]
+fn error_parse_get_mapping_index_not_found() {
+    let f = load_file("tests/samples/error_index_not_found.json");
+    let deserialized = parse::<GetMappingResponse>().from_reader(404, f).unwrap_err();
+
+    let valid = match deserialized {
+        ResponseError::Api(ApiError::IndexNotFound { ref index }) if index == "carrots" => true,
+        _ => false,
+    };
+
+    assert!(valid);
+}
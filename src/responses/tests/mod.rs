@@ -16,4 +16,33 @@ pub mod get;
 pub mod search;
 pub mod bulk;
 pub mod index;
-pub mod indices_exists;
\ No newline at end of file
+pub mod indices_exists;
+pub mod explain;
+pub mod suggest;
+pub mod render_search_template;
+pub mod field_caps;
+pub mod count;
+pub mod index_template;
+pub mod mapping;
+pub mod ingest;
+pub mod snapshot;
+pub mod cat;
+pub mod rollover;
+pub mod index_settings;
+pub mod validate_query;
+pub mod termvectors;
+pub mod cluster_health;
+pub mod cluster_reroute;
+pub mod indices_stats;
+pub mod alias;
+pub mod nodes_stats;
+pub mod nodes_info;
+pub mod tasks;
+pub mod script;
+pub mod reindex;
+pub mod sql;
+pub mod eql;
+pub mod percolate;
+pub mod analyze;
+pub mod recovery;
+pub mod exists;
@@ -0,0 +1,35 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_nodes_info() {
+    let f = load_file("tests/samples/nodes_info.json");
+    let deserialized = parse::<NodesInfoResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!("testcluster", deserialized.cluster_name());
+    assert_eq!(2, deserialized.nodes().count());
+
+    let node = deserialized.node("mJw06l1gR4Gn9c3bsyWvKg").unwrap();
+
+    assert_eq!("node-1", node.name());
+    assert_eq!("10.0.0.1:9300", node.transport_address());
+    assert_eq!(Some("10.0.0.1:9200"), node.http_publish_address());
+    assert_eq!(Some("6.2.3"), node.version());
+    assert_eq!(3, node.roles().len());
+    assert!(node.has_role("data"));
+    assert_eq!(Some(&json!("node-1")), node.settings().map(|settings| &settings["node"]["name"]));
+}
+
+#[test]
+fn success_parse_nodes_info_without_http() {
+    let f = load_file("tests/samples/nodes_info.json");
+    let deserialized = parse::<NodesInfoResponse>().from_reader(200, f).unwrap();
+
+    let node = deserialized.node("Bp2MJ8bQQ269uSuoI-U04A").unwrap();
+
+    assert_eq!(None, node.http_publish_address());
+    assert!(!node.has_role("data"));
+}
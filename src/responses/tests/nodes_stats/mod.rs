@@ -0,0 +1,73 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_nodes_stats() {
+    let f = load_file("tests/samples/nodes_stats.json");
+    let deserialized = parse::<NodesStatsResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!("testcluster", deserialized.cluster_name());
+    assert_eq!(3, deserialized.nodes().count());
+
+    let node = deserialized.node("mJw06l1gR4Gn9c3bsyWvKg").unwrap();
+
+    assert_eq!("node-1", node.name());
+    assert_eq!(Some("10.0.0.1"), node.host());
+    assert_eq!(Some(268435456), node.heap_used_in_bytes());
+    assert_eq!(Some(1073741824), node.heap_max_in_bytes());
+    assert_eq!(Some(25), node.heap_used_percent());
+}
+
+#[test]
+fn success_parse_nodes_stats_os_thread_pool() {
+    let f = load_file("tests/samples/nodes_stats.json");
+    let deserialized = parse::<NodesStatsResponse>().from_reader(200, f).unwrap();
+
+    let node = deserialized.node("mJw06l1gR4Gn9c3bsyWvKg").unwrap();
+
+    assert_eq!(Some(30), node.cpu_percent());
+    assert_eq!(2, node.thread_pools().count());
+
+    let search = node.thread_pool("search").unwrap();
+
+    assert_eq!(7, search.threads());
+    assert_eq!(10, search.queue());
+    assert_eq!(2, search.active());
+    assert_eq!(5, search.rejected());
+    assert!(node.thread_pool("missing").is_none());
+}
+
+#[test]
+fn success_parse_nodes_stats_highest_heap_usage() {
+    let f = load_file("tests/samples/nodes_stats.json");
+    let deserialized = parse::<NodesStatsResponse>().from_reader(200, f).unwrap();
+
+    let node = deserialized.node_with_highest_heap_usage().unwrap();
+
+    assert_eq!("node-2", node.name());
+}
+
+#[test]
+fn success_parse_nodes_stats_empty_highest_heap_usage() {
+    let deserialized = parse::<NodesStatsResponse>()
+        .from_slice(200, br#"{ "cluster_name": "testcluster", "nodes": {} }"#.as_ref())
+        .unwrap();
+
+    assert!(deserialized.node_with_highest_heap_usage().is_none());
+}
+
+#[test]
+fn success_parse_nodes_stats_without_metrics() {
+    let deserialized = parse::<NodesStatsResponse>()
+        .from_slice(200, br#"{ "cluster_name": "testcluster", "nodes": { "abc": { "name": "node-1" } } }"#.as_ref())
+        .unwrap();
+
+    let node = deserialized.node("abc").unwrap();
+
+    assert_eq!(None, node.heap_used_in_bytes());
+    assert_eq!(None, node.cpu_percent());
+    assert_eq!(0, node.thread_pools().count());
+}
@@ -0,0 +1,14 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_percolate_query() {
+    let f = load_file("tests/samples/percolate_query.json");
+    let deserialized = parse::<PercolateQueryResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!(2, deserialized.search().total());
+    assert_eq!(vec!["1", "2"], deserialized.matched_query_ids());
+}
@@ -0,0 +1,26 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_indices_recovery() {
+    let f = load_file("tests/samples/indices_recovery.json");
+    let deserialized = parse::<IndicesRecoveryResponse>().from_reader(200, f).unwrap();
+
+    let shard = &deserialized.index("restored_index").unwrap()[0];
+    assert_eq!("SNAPSHOT", shard.ty());
+    assert_eq!("INDEX", shard.stage());
+    assert!(shard.is_primary());
+
+    assert!(!deserialized.all_complete());
+}
+
+#[test]
+fn success_parse_indices_recovery_done() {
+    let f = load_file("tests/samples/indices_recovery_done.json");
+    let deserialized = parse::<IndicesRecoveryResponse>().from_reader(200, f).unwrap();
+
+    assert!(deserialized.all_complete());
+}
@@ -0,0 +1,30 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_reindex() {
+    let f = load_file("tests/samples/reindex.json");
+    let deserialized = parse::<ReindexResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!(147, deserialized.took());
+    assert_eq!(120, deserialized.total());
+    assert_eq!(70, deserialized.created());
+    assert_eq!(20, deserialized.updated());
+    assert_eq!(30, deserialized.version_conflicts());
+
+    assert!(deserialized.had_failures());
+    assert_eq!(409, deserialized.failures()[0]["status"]);
+    assert!((deserialized.success_rate() - 0.75).abs() < 1e-9);
+}
+
+#[test]
+fn success_parse_reindex_empty() {
+    let f = load_file("tests/samples/reindex_empty.json");
+    let deserialized = parse::<ReindexResponse>().from_reader(200, f).unwrap();
+
+    assert!(!deserialized.had_failures());
+    assert_eq!(1.0, deserialized.success_rate());
+}
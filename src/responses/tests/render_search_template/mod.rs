@@ -0,0 +1,22 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_render_search_template() {
+    let f = load_file("tests/samples/render_search_template.json");
+    let deserialized = parse::<RenderSearchTemplateResponse>().from_reader(200, f).unwrap();
+
+    let expected = json!({
+        "query": {
+            "match": {
+                "title": "search for these words"
+            }
+        },
+        "size": 10
+    });
+
+    assert_eq!(&expected, deserialized.template_output());
+}
@@ -0,0 +1,35 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use elastic_responses::error::*;
+use load_file;
+
+#[test]
+fn success_parse_rollover() {
+    let f = load_file("tests/samples/rollover.json");
+    let deserialized = parse::<RolloverResponse>().from_reader(200, f).unwrap();
+
+    assert!(deserialized.acknowledged());
+    assert!(deserialized.shards_acknowledged());
+    assert_eq!("logs-000001", deserialized.old_index());
+    assert_eq!("logs-000002", deserialized.new_index());
+    assert!(deserialized.rolled_over());
+    assert!(!deserialized.dry_run());
+
+    assert_eq!(Some(&false), deserialized.conditions().get("[max_age: 7d]"));
+    assert_eq!(Some(&true), deserialized.conditions().get("[max_docs: 1000]"));
+}
+
+#[test]
+fn error_parse_rollover_alias_missing() {
+    let f = load_file("tests/samples/error_rollover_alias_missing.json");
+    let deserialized = parse::<RolloverResponse>().from_reader(400, f).unwrap_err();
+
+    let valid = match deserialized {
+        ResponseError::Api(ApiError::Other(ref err)) => err["type"] == "illegal_argument_exception",
+        _ => false,
+    };
+
+    assert!(valid);
+}
@@ -0,0 +1,29 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_get_script() {
+    let f = load_file("tests/samples/get_script.json");
+    let deserialized = parse::<GetScriptResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!("calculate-score", deserialized.id());
+    assert!(deserialized.found());
+
+    let script = deserialized.into_script().unwrap();
+
+    assert_eq!("painless", script.lang());
+    assert_eq!("Math.log(_score * 2) + params.my_modifier", script.source());
+}
+
+#[test]
+fn success_parse_get_script_not_found() {
+    let f = load_file("tests/samples/get_script_not_found.json");
+    let deserialized = parse::<GetScriptResponse>().from_reader(404, f).unwrap();
+
+    assert_eq!("missing-script", deserialized.id());
+    assert!(!deserialized.found());
+    assert!(deserialized.script().is_none());
+}
@@ -5,6 +5,7 @@ extern crate serde_json;
 use elastic_responses::*;
 use elastic_responses::error::*;
 use serde_json::Value;
+use std::time::Duration;
 use load_file;
 
 #[test]
@@ -53,6 +54,120 @@ fn success_parse_hits_simple_of_t() {
     assert_eq!(deserialized.hits().into_iter().count(), 5);
 }
 
+#[test]
+fn success_parse_hits_of_t_with_metadata() {
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Tweet {
+        user: String,
+        message: String,
+    }
+
+    let f = load_file("tests/samples/search_hits_routing.json");
+    let deserialized = parse::<SearchResponse<Tweet>>()
+        .from_reader(200, f)
+        .unwrap();
+
+    let hit = deserialized.hits().next().unwrap();
+
+    assert_eq!("twitter", hit.index());
+    assert_eq!("tweet", hit.ty());
+    assert_eq!("1", hit.id());
+    assert_eq!(Some(0.2876821), hit.score());
+    assert_eq!(Some("kimchy"), hit.routing());
+
+    let expected = Tweet {
+        user: "kimchy".to_owned(),
+        message: "trying out Elasticsearch".to_owned(),
+    };
+
+    assert_eq!(Some(&expected), hit.document());
+}
+
+#[test]
+fn success_parse_hits_of_t_without_source() {
+    #[allow(dead_code)]
+    #[derive(Deserialize, Debug)]
+    struct Tweet {
+        user: String,
+        message: String,
+    }
+
+    let f = load_file("tests/samples/search_hits_no_source.json");
+    let deserialized = parse::<SearchResponse<Tweet>>()
+        .from_reader(200, f)
+        .unwrap();
+
+    let ids: Vec<_> = deserialized.hits().map(|hit| hit.id()).collect();
+
+    assert_eq!(vec!["1", "2"], ids);
+    assert!(deserialized.hits().all(|hit| hit.document().is_none()));
+    assert_eq!(0, deserialized.documents().count());
+}
+
+#[test]
+fn success_parse_hits_untyped_default() {
+    let f = load_file("tests/samples/search_hits_routing.json");
+    let deserialized: SearchResponse = parse::<SearchResponse>().from_reader(200, f).unwrap();
+
+    let doc: &Value = deserialized.documents().next().unwrap();
+
+    assert_eq!("kimchy", doc["user"]);
+}
+
+#[test]
+fn success_parse_hits_raw() {
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Tweet {
+        user: String,
+        message: String,
+    }
+
+    let f = load_file("tests/samples/search_hits_routing.json");
+    let deserialized = parse::<SearchResponseRaw>().from_reader(200, f).unwrap();
+
+    let hit = deserialized.hits().next().unwrap();
+
+    let expected = Tweet {
+        user: "kimchy".to_owned(),
+        message: "trying out Elasticsearch".to_owned(),
+    };
+
+    assert_eq!("1", hit.id());
+    assert_eq!(Some(expected), hit.parse_source::<Tweet>().unwrap());
+    assert!(hit.parse_source::<Vec<String>>().is_err());
+}
+
+#[test]
+fn success_parse_hits_raw_without_source() {
+    let f = load_file("tests/samples/search_hits_no_source.json");
+    let deserialized = parse::<SearchResponseRaw>().from_reader(200, f).unwrap();
+
+    assert!(deserialized.hits().all(|hit| hit.parse_source::<Value>().unwrap().is_none()));
+}
+
+#[test]
+fn success_parse_hits_sorted() {
+    let f = load_file("tests/samples/search_hits_sorted.json");
+    let deserialized = parse::<SearchResponse<Value>>()
+        .from_reader(200, f)
+        .unwrap();
+
+    let hit = deserialized.hits().next().unwrap();
+    assert_eq!(Some(&[json!(1463538855000u64), json!("tweet#1")][..]), hit.sort());
+
+    assert_eq!(Some(&[json!(1463538857000u64), json!("tweet#2")][..]), deserialized.last_sort_values());
+}
+
+#[test]
+fn success_parse_hits_unsorted_has_no_last_sort_values() {
+    let f = load_file("tests/samples/search_hits_routing.json");
+    let deserialized = parse::<SearchResponse<Value>>()
+        .from_reader(200, f)
+        .unwrap();
+
+    assert_eq!(None, deserialized.last_sort_values());
+}
+
 #[test]
 fn success_parse_hits_no_score() {
     let f = load_file("tests/samples/search_null_score.json");
@@ -255,3 +370,136 @@ fn error_parse_other() {
         reason
     );
 }
+
+#[test]
+fn success_parse_profile() {
+    let f = load_file("tests/samples/search_profile.json");
+    let deserialized = parse::<SearchResponse<Value>>().from_reader(200, f).unwrap();
+
+    let shards = deserialized.profile().unwrap().shards();
+
+    assert_eq!(1, shards.len());
+    assert_eq!("[2aE02wS1R8q_QFnYu6vDVQ][twitter][0]", shards[0].id());
+
+    let search = &shards[0].searches()[0];
+
+    assert_eq!(51443, search.rewrite_time());
+
+    let query = &search.query()[0];
+
+    assert_eq!("BooleanQuery", query.ty());
+    assert_eq!("message:some message:number", query.description());
+    assert_eq!(1873811, query.time_in_nanos());
+    assert_eq!(Some(&51306), query.breakdown().get("score"));
+    assert_eq!(2, query.children().len());
+    assert_eq!("TermQuery", query.children()[0].ty());
+    assert_eq!(391943, query.children()[0].time_in_nanos());
+    assert!(query.children()[0].children().is_empty());
+
+    let collector = &search.collector()[0];
+
+    assert_eq!("CancellableCollector", collector.name());
+    assert_eq!("search_cancelled", collector.reason());
+    assert_eq!("SimpleTopScoreDocCollector", collector.children()[0].name());
+
+    let agg = &shards[0].aggregations()[0];
+
+    assert_eq!("GlobalOrdinalsStringTermsAggregator", agg.ty());
+    assert_eq!("my_scoped_agg", agg.description());
+    assert_eq!(195386, agg.time_in_nanos());
+}
+
+#[test]
+fn success_parse_no_profile() {
+    let f = load_file("tests/samples/search_hits_only.json");
+    let deserialized = parse::<SearchResponse<Value>>().from_reader(200, f).unwrap();
+
+    assert!(deserialized.profile().is_none());
+}
+
+#[test]
+fn success_parse_from_json() {
+    let json = include_str!("../samples/search_hits_routing.json");
+    let deserialized = SearchResponse::<Value>::from_json(json).unwrap();
+
+    assert_eq!(1, deserialized.total());
+    assert_eq!(Some("kimchy"), deserialized.hits().next().unwrap().routing());
+}
+
+#[test]
+fn error_parse_from_json_invalid() {
+    let deserialized = SearchResponse::<Value>::from_json(r#"{ "took": 1 }"#);
+
+    assert!(deserialized.is_err());
+}
+
+#[test]
+fn success_parse_took_duration() {
+    let deserialized = SearchResponse::<Value>::from_json(r#"{
+        "took": 1500,
+        "timed_out": false,
+        "_shards": { "total": 1, "successful": 1, "failed": 0 },
+        "hits": { "total": 0, "max_score": null, "hits": [] }
+    }"#).unwrap();
+
+    assert_eq!(1500, deserialized.took_ms());
+    assert_eq!(Duration::from_millis(1500), deserialized.took_duration());
+}
+
+#[test]
+fn error_parse_invalid_body_has_location() {
+    let body = "{\n  \"took\": \"not a number\"\n}";
+    let deserialized = parse::<SearchResponse<Value>>().from_slice(200, body.as_bytes());
+
+    match deserialized {
+        Err(ResponseError::Parse(ParseResponseError::Body { line, column, raw_body, .. })) => {
+            assert_eq!(2, line);
+            assert!(column > 0);
+            assert_eq!(Some(body.to_owned()), raw_body);
+        }
+        _ => panic!("expected a body parse error"),
+    }
+}
+
+#[test]
+fn error_parse_invalid_body_over_capture_limit() {
+    let deserialized = parse::<SearchResponse<Value>>()
+        .capture_body_on_error_limit(4)
+        .from_reader(200, &br#"{ "took": "not a number" }"#[..]);
+
+    match deserialized {
+        Err(ResponseError::Parse(ParseResponseError::Body { raw_body, .. })) => assert_eq!(None, raw_body),
+        _ => panic!("expected a body parse error"),
+    }
+}
+
+#[test]
+fn error_parse_invalid_body_from_reader_has_location() {
+    let body = "{\n  \"took\": \"not a number\"\n}";
+    let deserialized = parse::<SearchResponse<Value>>().from_reader(200, body.as_bytes());
+
+    match deserialized {
+        Err(ResponseError::Parse(ParseResponseError::Body { line, column, raw_body, .. })) => {
+            assert_eq!(2, line);
+            assert!(column > 0);
+            assert_eq!(Some(body.to_owned()), raw_body);
+        }
+        _ => panic!("expected a body parse error"),
+    }
+}
+
+#[test]
+fn error_parse_invalid_body_without_capture_has_location() {
+    let body = "{\n  \"took\": \"not a number\"\n}";
+    let deserialized = parse::<SearchResponse<Value>>()
+        .capture_body_on_error_limit(0)
+        .from_reader(200, body.as_bytes());
+
+    match deserialized {
+        Err(ResponseError::Parse(ParseResponseError::Body { line, raw_body, .. })) => {
+            assert_eq!(2, line);
+            assert_eq!(None, raw_body);
+        }
+        _ => panic!("expected a body parse error"),
+    }
+}
@@ -0,0 +1,65 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_create_snapshot() {
+    let f = load_file("tests/samples/snapshot_create.json");
+    let deserialized = parse::<SnapshotResponse>().from_reader(200, f).unwrap();
+
+    let snapshot = deserialized.snapshot().unwrap();
+
+    assert!(!deserialized.accepted());
+    assert_eq!("snapshot_1", snapshot.name());
+    assert_eq!(Some(SnapshotState::Success), snapshot.state());
+    assert_eq!(&["index_1".to_owned(), "index_2".to_owned()], snapshot.indices());
+    assert_eq!(Some(1102), snapshot.duration_in_millis());
+    assert_eq!(10, snapshot.shards().unwrap().successful());
+    assert!(snapshot.is_success());
+    assert!(!snapshot.is_partial());
+}
+
+#[test]
+fn success_parse_create_snapshot_partial() {
+    let f = load_file("tests/samples/snapshot_create_partial.json");
+    let deserialized = parse::<SnapshotResponse>().from_reader(200, f).unwrap();
+
+    let snapshot = deserialized.snapshot().unwrap();
+
+    assert_eq!(Some(SnapshotState::Partial), snapshot.state());
+    assert!(snapshot.is_partial());
+    assert!(!snapshot.is_success());
+    assert_eq!(1, snapshot.failures().len());
+}
+
+#[test]
+fn success_parse_create_snapshot_accepted() {
+    let deserialized = parse::<SnapshotResponse>().from_slice(200, br#"{"accepted":true}"#).unwrap();
+
+    assert!(deserialized.accepted());
+    assert!(deserialized.snapshot().is_none());
+}
+
+#[test]
+fn success_parse_get_snapshot() {
+    let f = load_file("tests/samples/snapshot_get.json");
+    let deserialized = parse::<GetSnapshotResponse>().from_reader(200, f).unwrap();
+
+    let states: Vec<_> = deserialized.snapshots().map(|snapshot| snapshot.state()).collect();
+
+    assert_eq!(vec![Some(SnapshotState::Success), Some(SnapshotState::InProgress)], states);
+}
+
+#[test]
+fn success_parse_restore_snapshot() {
+    let f = load_file("tests/samples/snapshot_restore.json");
+    let deserialized = parse::<RestoreSnapshotResponse>().from_reader(200, f).unwrap();
+
+    let snapshot = deserialized.snapshot().unwrap();
+
+    assert_eq!("snapshot_1", snapshot.name());
+    assert_eq!(&["index_1".to_owned()], snapshot.indices());
+    assert_eq!(5, snapshot.shards().total());
+}
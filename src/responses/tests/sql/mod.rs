@@ -0,0 +1,21 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_sql() {
+    let f = load_file("tests/samples/sql.json");
+    let deserialized = parse::<SqlQueryResponse>().from_reader(200, f).unwrap();
+
+    let columns: Vec<_> = deserialized.columns().map(|column| column.name()).collect();
+    assert_eq!(vec!["author", "page_count"], columns);
+    assert_eq!(3, deserialized.rows().count());
+    assert!(deserialized.cursor().is_some());
+
+    let records = deserialized.into_records();
+    assert_eq!(3, records.len());
+    assert_eq!(json!("Vernor Vinge"), records[1]["author"]);
+    assert_eq!(json!(604), records[1]["page_count"]);
+}
@@ -0,0 +1,62 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_term_suggest() {
+    let f = load_file("tests/samples/suggest_term.json");
+    let deserialized = parse::<SuggestResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!(vec!["my-suggest"], deserialized.names());
+    assert_eq!(5, deserialized.shards().unwrap().total());
+
+    let entries = deserialized.suggestion("my-suggest").unwrap();
+
+    assert_eq!(3, entries.len());
+    assert_eq!("tring", entries[0].text());
+    assert_eq!(0, entries[0].offset());
+    assert_eq!(5, entries[0].length());
+
+    let option = entries[0].options().next().unwrap();
+
+    assert_eq!("trying", option.text());
+    assert_eq!(Some(0.8), option.score());
+    assert_eq!(Some(1), option.freq());
+    assert!(option.document().is_none());
+}
+
+#[test]
+fn success_parse_completion_suggest() {
+    let f = load_file("tests/samples/suggest_completion.json");
+    let deserialized = parse::<SuggestResponse>().from_reader(200, f).unwrap();
+
+    let entries = deserialized.suggestion("song-suggest").unwrap();
+    let option = entries[0].options().next().unwrap();
+
+    assert_eq!("Nirvana", option.text());
+    assert_eq!(Some("music"), option.index());
+    assert_eq!(Some("song"), option.ty());
+    assert_eq!(Some("1"), option.id());
+    assert_eq!(Some(1.0), option.score());
+    assert_eq!(Some(&json!({ "suggest": ["Nevermind", "Nirvana"] })), option.document());
+}
+
+#[test]
+fn success_parse_missing_suggestion() {
+    let f = load_file("tests/samples/suggest_completion.json");
+    let deserialized = parse::<SuggestResponse>().from_reader(200, f).unwrap();
+
+    assert!(deserialized.suggestion("not-a-suggest").is_none());
+}
+
+#[test]
+fn success_parse_search_suggest() {
+    let f = load_file("tests/samples/search_suggest.json");
+    let deserialized = parse::<SearchResponse<serde_json::Value>>().from_reader(200, f).unwrap();
+
+    let entries = deserialized.suggestion("my-suggest").unwrap();
+
+    assert_eq!("trying", entries[0].options().next().unwrap().text());
+}
@@ -0,0 +1,39 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use load_file;
+
+#[test]
+fn success_parse_tasks_list() {
+    let f = load_file("tests/samples/tasks_list.json");
+    let deserialized = parse::<TasksListResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!(2, deserialized.nodes().count());
+
+    let node = deserialized.node("oTUltX4IQMOUUVeiohTt8A").unwrap();
+
+    assert_eq!("node-1", node.name());
+    assert_eq!(3, node.tasks().count());
+
+    let (id, task) = node.tasks().next().unwrap();
+
+    assert_eq!("oTUltX4IQMOUUVeiohTt8A:124", id);
+    assert_eq!("oTUltX4IQMOUUVeiohTt8A:124", task.task_id());
+    assert_eq!("indices:data/write/reindex", task.action());
+    assert!(task.cancellable());
+    assert_eq!(None, task.parent_task_id());
+    assert_eq!(Some(&json!(6154)), task.status().map(|status| &status["total"]));
+}
+
+#[test]
+fn success_parse_tasks_list_pending_tasks() {
+    let f = load_file("tests/samples/tasks_list.json");
+    let deserialized = parse::<TasksListResponse>().from_reader(200, f).unwrap();
+
+    let tasks = deserialized.pending_tasks();
+
+    assert_eq!(6, tasks.len());
+    assert_eq!(3, tasks.iter().filter(|task| task.node() == "Bp2MJ8bQQ269uSuoI-U04A").count());
+    assert_eq!(Some("Bp2MJ8bQQ269uSuoI-U04A:7"), tasks[1].parent_task_id());
+}
@@ -0,0 +1,65 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use elastic_responses::error::*;
+use load_file;
+
+#[test]
+fn success_parse_termvectors() {
+    let f = load_file("tests/samples/termvectors.json");
+    let deserialized = parse::<TermVectorsResponse>().from_reader(200, f).unwrap();
+
+    assert_eq!("twitter", deserialized.index());
+    assert_eq!("tweet", deserialized.ty());
+    assert_eq!(Some("1"), deserialized.id());
+    assert!(deserialized.found());
+
+    let text = deserialized.field("text").unwrap();
+    let stats = text.field_statistics().unwrap();
+
+    assert_eq!(4, stats.sum_doc_freq());
+    assert_eq!(2, stats.doc_count());
+    assert_eq!(6, stats.sum_ttf());
+
+    let test = text.term("test").unwrap();
+
+    assert_eq!(3, test.term_freq());
+    assert_eq!(Some(2), test.doc_freq());
+    assert_eq!(Some(4), test.ttf());
+    assert_eq!(None, test.score());
+    assert_eq!(2, test.tokens().len());
+    assert_eq!(Some(1), test.tokens()[0].position());
+    assert_eq!(Some(8), test.tokens()[0].start_offset());
+    assert_eq!(Some(12), test.tokens()[0].end_offset());
+    assert_eq!(Some("d29yZA=="), test.tokens()[0].payload());
+
+    let twitter = text.term("twitter").unwrap();
+
+    assert_eq!(1, twitter.term_freq());
+    assert_eq!(None, twitter.doc_freq());
+    assert_eq!(Some(0.5), twitter.score());
+    assert!(twitter.tokens().is_empty());
+}
+
+#[test]
+fn success_parse_termvectors_not_found() {
+    let f = load_file("tests/samples/termvectors_not_found.json");
+    let deserialized = parse::<TermVectorsResponse>().from_reader(200, f).unwrap();
+
+    assert!(!deserialized.found());
+    assert!(deserialized.term_vectors().is_empty());
+}
+
+#[test]
+fn error_parse_termvectors_index_not_found() {
+    let f = load_file("tests/samples/error_index_not_found.json");
+    let deserialized = parse::<TermVectorsResponse>().from_reader(404, f).unwrap_err();
+
+    let valid = match deserialized {
+        ResponseError::Api(ApiError::IndexNotFound { ref index }) if index == "carrots" => true,
+        _ => false,
+    };
+
+    assert!(valid);
+}
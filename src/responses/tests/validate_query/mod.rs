@@ -0,0 +1,57 @@
+extern crate elastic_responses;
+extern crate serde_json;
+
+use elastic_responses::*;
+use elastic_responses::error::*;
+use load_file;
+
+#[test]
+fn success_parse_validate_query_valid() {
+    let f = load_file("tests/samples/validate_query_valid.json");
+    let deserialized = parse::<ValidateQueryResponse>().from_reader(200, f).unwrap();
+
+    assert!(deserialized.valid());
+    assert_eq!(1, deserialized.shards().unwrap().successful());
+    assert_eq!(1, deserialized.explanations().len());
+
+    let explanation = &deserialized.explanations()[0];
+
+    assert_eq!("twitter", explanation.index());
+    assert!(explanation.valid());
+
+    match *explanation {
+        ValidationExplanation::Valid { ref explanation, .. } => assert!(explanation.starts_with("+user:kimchy")),
+        _ => panic!("expected a valid explanation"),
+    }
+}
+
+#[test]
+fn success_parse_validate_query_invalid() {
+    let f = load_file("tests/samples/validate_query_invalid.json");
+    let deserialized = parse::<ValidateQueryResponse>().from_reader(200, f).unwrap();
+
+    assert!(!deserialized.valid());
+
+    let explanation = &deserialized.explanations()[0];
+
+    assert_eq!("twitter", explanation.index());
+    assert!(!explanation.valid());
+
+    match *explanation {
+        ValidationExplanation::Invalid { ref error, .. } => assert!(error.contains("failed to parse date field")),
+        _ => panic!("expected an invalid explanation"),
+    }
+}
+
+#[test]
+fn error_parse_validate_query_index_not_found() {
+    let f = load_file("tests/samples/error_index_not_found.json");
+    let deserialized = parse::<ValidateQueryResponse>().from_reader(404, f).unwrap_err();
+
+    let valid = match deserialized {
+        ResponseError::Api(ApiError::IndexNotFound { ref index }) if index == "carrots" => true,
+        _ => false,
+    };
+
+    assert!(valid);
+}
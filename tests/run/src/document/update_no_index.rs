@@ -43,7 +43,7 @@ impl IntegrationTest for UpdateNoIndex {
     // Ensure an `DocumentMissing` error is returned
     fn assert_err(&self, err: &Error) -> bool {
         match *err {
-            Error::Api(ApiError::DocumentMissing { .. }) => true,
+            Error::Api { error: ApiError::DocumentMissing { .. }, .. } => true,
             _ => false,
         }
     }
@@ -21,7 +21,7 @@ pub trait IntegrationTest: Debug {
     /// Check an error during preparation and possibly continue.
     fn prepare_err(&self, err: &Error) -> bool {
         match *err {
-            Error::Api(ApiError::IndexNotFound { .. }) => true,
+            Error::Api { error: ApiError::IndexNotFound { .. }, .. } => true,
             _ => false,
         }
     }
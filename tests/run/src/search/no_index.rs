@@ -36,7 +36,7 @@ impl IntegrationTest for NoIndex {
     // Ensure an `IndexNotFound` error is returned
     fn assert_err(&self, err: &Error) -> bool {
         match *err {
-            Error::Api(ApiError::IndexNotFound { .. }) => true,
+            Error::Api { error: ApiError::IndexNotFound { .. }, .. } => true,
             _ => false,
         }
     }